@@ -0,0 +1,68 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// ALLOCATIONS is the number of allocations made by the program.
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+// ALLOCATED_BYTES is the number of bytes allocated by the program. Memory that is freed is not
+// subtracted.
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+// Counting is a global allocator that counts allocations before passing them on to the system
+// allocator. Reallocations count as an allocation of the new size.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+fn count(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+}
+
+// Counts holds the number of allocations and allocated bytes.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Counts {
+    pub allocations: u64,
+    pub bytes: u64,
+}
+
+// counts returns the number of allocations and bytes allocated so far.
+pub fn counts() -> Counts {
+    Counts {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::str::FromStr;
+
+// Arg is a single command-line argument.
+#[derive(Debug, PartialEq)]
+pub enum Arg {
+    // Flag is an argument starting with a dash, e.g. --day. A value given inline with an equals
+    // sign, e.g. --day=1, is returned by the next call to Parser::value.
+    Flag(String),
+    // Positional is any other argument.
+    Positional(String),
+}
+
+// UsageError is returned when the command-line arguments are invalid.
+#[derive(Debug, PartialEq)]
+pub struct UsageError(pub String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+// Parser is a minimal command-line argument parser.
+pub struct Parser {
+    args: std::vec::IntoIter<String>,
+    pending: Option<String>,
+}
+
+impl Parser {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Self {
+        Parser {
+            args: args.into_iter().collect::<Vec<_>>().into_iter(),
+            pending: None,
+        }
+    }
+
+    // next returns the next argument.
+    pub fn next(&mut self) -> Option<Arg> {
+        // An inline value that was never consumed is treated as a positional argument.
+        if let Some(v) = self.pending.take() {
+            return Some(Arg::Positional(v));
+        }
+
+        let arg = self.args.next()?;
+        if arg.starts_with('-') && arg.len() > 1 {
+            if let Some((flag, value)) = arg.split_once('=') {
+                self.pending = Some(value.to_string());
+                return Some(Arg::Flag(flag.to_string()));
+            }
+            return Some(Arg::Flag(arg));
+        }
+
+        Some(Arg::Positional(arg))
+    }
+
+    // value returns the value for the given flag.
+    pub fn value(&mut self, flag: &str) -> Result<String, UsageError> {
+        self.pending
+            .take()
+            .or_else(|| self.args.next())
+            .ok_or_else(|| UsageError(format!("{flag} requires a value")))
+    }
+
+    // parse returns the value for the given flag parsed as T.
+    pub fn parse<T: FromStr>(&mut self, flag: &str) -> Result<T, UsageError> {
+        let v = self.value(flag)?;
+        v.parse::<T>()
+            .map_err(|_| UsageError(format!("invalid value for {flag}: {v:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser(args: &[&str]) -> Parser {
+        Parser::new(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_parser() -> Result<(), UsageError> {
+        let mut p = parser(&["run", "--day", "3", "--part=2", "-"]);
+        assert_eq!(p.next(), Some(Arg::Positional("run".to_string())));
+        assert_eq!(p.next(), Some(Arg::Flag("--day".to_string())));
+        assert_eq!(p.parse::<u8>("--day")?, 3);
+        assert_eq!(p.next(), Some(Arg::Flag("--part".to_string())));
+        assert_eq!(p.value("--part")?, "2");
+        assert_eq!(p.next(), Some(Arg::Positional("-".to_string())));
+        assert_eq!(p.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parser_missing_value() {
+        let mut p = parser(&["--day"]);
+        p.next();
+        assert_eq!(
+            p.parse::<u8>("--day"),
+            Err(UsageError("--day requires a value".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parser_invalid_value() {
+        let mut p = parser(&["--day=x"]);
+        p.next();
+        assert_eq!(
+            p.parse::<u8>("--day"),
+            Err(UsageError("invalid value for --day: \"x\"".to_string()))
+        );
+    }
+}
@@ -0,0 +1,555 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path;
+use std::process;
+use std::time;
+
+use advent2024::days::{self, Day};
+use aoc_common::{json, sha256, Part};
+
+use crate::alloc;
+use crate::args::{Arg, Parser, UsageError};
+
+pub const USAGE: &str = "Usage: advent2024 bench [OPTIONS]
+
+Runs a day's parse, part 1 and part 2 stages repeatedly and reports the min,
+median and 95th percentile time and the number of allocations of each stage.
+
+Options:
+  --day N            Day to benchmark (1-23).
+  --input FILE       Read the puzzle input from FILE. Defaults to stdin.
+  --all              Benchmark every day with an input in --input-dir.
+  --input-dir DIR    Directory holding a dayN/input.txt file for each day.
+                     Used with --all.
+  --iterations N     Number of measured iterations. Defaults to 10.
+  --warmup N         Number of iterations run before measuring. Defaults to 2.
+  --output FILE      Write the results to FILE as a JSON baseline.
+  --compare FILE     Compare the results with a JSON baseline written by
+                     --output and fail if any stage regressed.
+  --threshold PCT    Percentage by which a stage's median time or allocation
+                     count may exceed the baseline before it is reported as a
+                     regression. Defaults to 10.
+";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    day: Option<u8>,
+    input: Option<path::PathBuf>,
+    all: bool,
+    input_dir: Option<path::PathBuf>,
+    iterations: usize,
+    warmup: usize,
+    output: Option<path::PathBuf>,
+    compare: Option<path::PathBuf>,
+    threshold: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            day: None,
+            input: None,
+            all: false,
+            input_dir: None,
+            iterations: 10,
+            warmup: 2,
+            output: None,
+            compare: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+fn parse_options(args: &mut Parser) -> Result<Options, UsageError> {
+    let mut opts = Options::default();
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Flag(f) if f == "--day" => opts.day = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--input" => opts.input = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--all" => opts.all = true,
+            Arg::Flag(f) if f == "--input-dir" => opts.input_dir = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--iterations" => opts.iterations = args.parse(&f)?,
+            Arg::Flag(f) if f == "--warmup" => opts.warmup = args.parse(&f)?,
+            Arg::Flag(f) if f == "--output" => opts.output = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--compare" => opts.compare = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--threshold" => opts.threshold = args.parse(&f)?,
+            Arg::Flag(f) => return Err(UsageError(format!("unknown flag: {f}"))),
+            Arg::Positional(p) => return Err(UsageError(format!("unexpected argument: {p}"))),
+        }
+    }
+
+    if opts.all == opts.day.is_some() {
+        return Err(UsageError(
+            "exactly one of --day or --all is required".to_string(),
+        ));
+    }
+    if opts.all && opts.input.is_some() {
+        return Err(UsageError("--input cannot be used with --all".to_string()));
+    }
+    if !opts.all && opts.input_dir.is_some() {
+        return Err(UsageError(
+            "--input-dir can only be used with --all".to_string(),
+        ));
+    }
+    if opts.iterations == 0 {
+        return Err(UsageError("--iterations must be at least 1".to_string()));
+    }
+    if opts.threshold.is_nan() || opts.threshold < 0.0 {
+        return Err(UsageError("--threshold must not be negative".to_string()));
+    }
+
+    Ok(opts)
+}
+
+// Stage is a stage of running a solution that is measured separately.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    const ALL: [Stage; 3] = [
+        Stage::Parse,
+        Stage::Solve(Part::One),
+        Stage::Solve(Part::Two),
+    ];
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+// Sample is a single measurement of a stage.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Sample {
+    elapsed: time::Duration,
+    allocs: alloc::Counts,
+}
+
+// measure runs f and returns its result along with the time it took and the allocations it made.
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Sample) {
+    let before = alloc::counts();
+    let start = time::Instant::now();
+    let v = f();
+    let elapsed = start.elapsed();
+    let after = alloc::counts();
+    (
+        v,
+        Sample {
+            elapsed,
+            allocs: alloc::Counts {
+                allocations: after.allocations - before.allocations,
+                bytes: after.bytes - before.bytes,
+            },
+        },
+    )
+}
+
+// Stats summarizes the samples of a stage.
+#[derive(Debug, Clone, PartialEq)]
+struct Stats {
+    stage: String,
+    min: time::Duration,
+    median: time::Duration,
+    p95: time::Duration,
+    allocs: u64,
+    alloc_bytes: u64,
+}
+
+// percentile returns the value at the given percentile of the sorted values using the nearest
+// rank method.
+fn percentile<T: Copy>(sorted: &[T], p: f64) -> T {
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl Stats {
+    // new summarizes the samples. There must be at least one sample.
+    fn new(stage: Stage, samples: &[Sample]) -> Stats {
+        let mut times: Vec<_> = samples.iter().map(|s| s.elapsed).collect();
+        times.sort();
+        let mut allocs: Vec<_> = samples.iter().map(|s| s.allocs.allocations).collect();
+        allocs.sort();
+        let mut bytes: Vec<_> = samples.iter().map(|s| s.allocs.bytes).collect();
+        bytes.sort();
+
+        Stats {
+            stage: stage.to_string(),
+            min: times[0],
+            median: percentile(&times, 50.0),
+            p95: percentile(&times, 95.0),
+            allocs: percentile(&allocs, 50.0),
+            alloc_bytes: percentile(&bytes, 50.0),
+        }
+    }
+
+    fn to_json(&self) -> json::Value {
+        json::Value::object([
+            ("stage", json::Value::from(self.stage.as_str())),
+            ("min_ns", json::Value::Int(self.min.as_nanos() as i128)),
+            (
+                "median_ns",
+                json::Value::Int(self.median.as_nanos() as i128),
+            ),
+            ("p95_ns", json::Value::Int(self.p95.as_nanos() as i128)),
+            ("allocs", json::Value::Int(self.allocs.into())),
+            ("alloc_bytes", json::Value::Int(self.alloc_bytes.into())),
+        ])
+    }
+
+    fn from_json(v: &json::Value) -> Option<Stats> {
+        let ns = |key| {
+            let n = v.get(key)?.as_int()?;
+            Some(time::Duration::from_nanos(n.try_into().ok()?))
+        };
+        let count = |key| v.get(key)?.as_int()?.try_into().ok();
+        Some(Stats {
+            stage: v.get("stage")?.as_str()?.to_string(),
+            min: ns("min_ns")?,
+            median: ns("median_ns")?,
+            p95: ns("p95_ns")?,
+            allocs: count("allocs")?,
+            alloc_bytes: count("alloc_bytes")?,
+        })
+    }
+}
+
+// DayBench holds the benchmark results for a day.
+#[derive(Debug, Clone, PartialEq)]
+struct DayBench {
+    day: u8,
+    input_sha256: String,
+    stages: Vec<Stats>,
+}
+
+impl DayBench {
+    fn to_json(&self) -> json::Value {
+        json::Value::object([
+            ("day", json::Value::Int(self.day.into())),
+            (
+                "input_sha256",
+                json::Value::from(self.input_sha256.as_str()),
+            ),
+            (
+                "stages",
+                json::Value::Array(self.stages.iter().map(Stats::to_json).collect()),
+            ),
+        ])
+    }
+
+    fn from_json(v: &json::Value) -> Option<DayBench> {
+        Some(DayBench {
+            day: v.get("day")?.as_int()?.try_into().ok()?,
+            input_sha256: v.get("input_sha256")?.as_str()?.to_string(),
+            stages: v
+                .get("stages")?
+                .as_array()?
+                .iter()
+                .map(Stats::from_json)
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+// bench_day runs the day's solution on the input warmup times and then iterations more times
+// while measuring each stage.
+fn bench_day(
+    day: &Day,
+    input: &[u8],
+    iterations: usize,
+    warmup: usize,
+) -> Result<DayBench, Box<dyn error::Error>> {
+    let solution = day.solution();
+    let mut samples: Vec<Vec<Sample>> = vec![Vec::new(); Stage::ALL.len()];
+
+    for i in 0..warmup + iterations {
+        let (prepared, parse) = measure(|| solution.prepare(&mut io::Cursor::new(input)));
+        let prepared = prepared?;
+        let mut iteration = vec![parse];
+        for part in Part::BOTH {
+            let (answer, sample) = measure(|| prepared.solve(part));
+            answer?;
+            iteration.push(sample);
+        }
+        drop(prepared);
+
+        if i >= warmup {
+            for (s, sample) in samples.iter_mut().zip(iteration) {
+                s.push(sample);
+            }
+        }
+    }
+
+    Ok(DayBench {
+        day: day.day,
+        input_sha256: sha256::hex_digest(input),
+        stages: Stage::ALL
+            .iter()
+            .zip(&samples)
+            .map(|(stage, s)| Stats::new(*stage, s))
+            .collect(),
+    })
+}
+
+// Change is the relative change of a stage's median time and allocation count from the
+// baseline.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Change {
+    time: f64,
+    allocs: f64,
+}
+
+impl Change {
+    fn new(current: &Stats, baseline: &Stats) -> Change {
+        let ratio = |a: f64, b: f64| {
+            if b == 0.0 {
+                if a == 0.0 {
+                    0.0
+                } else {
+                    f64::INFINITY
+                }
+            } else {
+                a / b - 1.0
+            }
+        };
+        Change {
+            time: ratio(current.median.as_secs_f64(), baseline.median.as_secs_f64()),
+            allocs: ratio(current.allocs as f64, baseline.allocs as f64),
+        }
+    }
+
+    // regressed returns true if the time or allocations grew by more than threshold percent.
+    fn regressed(&self, threshold: f64) -> bool {
+        self.time * 100.0 > threshold || self.allocs * 100.0 > threshold
+    }
+}
+
+// read_baseline reads a JSON baseline written by --output.
+fn read_baseline(path: &path::Path) -> Result<Vec<DayBench>, Box<dyn error::Error>> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let v = json::parse(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+    let days = v
+        .get("days")
+        .and_then(|d| d.as_array())
+        .and_then(|d| {
+            d.iter()
+                .map(DayBench::from_json)
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| format!("{}: not a benchmark baseline", path.display()))?;
+    Ok(days)
+}
+
+// print_results prints a table of the results. The change from the baseline is included for
+// stages found in it. It returns true if any stage regressed.
+fn print_results(results: &[DayBench], baseline: Option<&[DayBench]>, threshold: f64) -> bool {
+    let mut regressed = false;
+    println!(
+        "{:<5}  {:<5}  {:>10}  {:>10}  {:>10}  {:>8}  {:>12}  vs baseline",
+        "Day", "Stage", "Min", "Median", "P95", "Allocs", "Bytes"
+    );
+    for bench in results {
+        let base = baseline.and_then(|b| b.iter().find(|d| d.day == bench.day));
+        if let Some(base) = base {
+            if base.input_sha256 != bench.input_sha256 {
+                eprintln!("warning: day{}: input differs from the baseline", bench.day);
+            }
+        }
+
+        for stats in &bench.stages {
+            let change = base
+                .and_then(|b| b.stages.iter().find(|s| s.stage == stats.stage))
+                .map(|s| Change::new(stats, s));
+            let vs = match change {
+                Some(c) if c.regressed(threshold) => {
+                    regressed = true;
+                    format!(
+                        "{:+.1}% time, {:+.1}% allocs  REGRESSED",
+                        c.time * 100.0,
+                        c.allocs * 100.0
+                    )
+                }
+                Some(c) => format!(
+                    "{:+.1}% time, {:+.1}% allocs",
+                    c.time * 100.0,
+                    c.allocs * 100.0
+                ),
+                None => String::new(),
+            };
+            let line = format!(
+                "{:<5}  {:<5}  {:>10}  {:>10}  {:>10}  {:>8}  {:>12}  {}",
+                bench.day,
+                stats.stage,
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.median),
+                format!("{:.1?}", stats.p95),
+                stats.allocs,
+                stats.alloc_bytes,
+                vs,
+            );
+            println!("{}", line.trim_end());
+        }
+    }
+    regressed
+}
+
+// run implements the bench command.
+pub fn run(args: &mut Parser) -> Result<process::ExitCode, Box<dyn error::Error>> {
+    let opts = parse_options(args)?;
+    let baseline = opts.compare.as_deref().map(read_baseline).transpose()?;
+
+    let mut results = Vec::new();
+    if opts.all {
+        let dir = opts
+            .input_dir
+            .clone()
+            .unwrap_or_else(|| path::PathBuf::from("."));
+        for day in days::DAYS {
+            let path = dir.join(format!("day{}", day.day)).join("input.txt");
+            let input = match fs::read(&path) {
+                Ok(input) => input,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    eprintln!("skipping day{}: {} not found", day.day, path.display());
+                    continue;
+                }
+                Err(e) => return Err(format!("{}: {e}", path.display()).into()),
+            };
+            results.push(
+                bench_day(day, &input, opts.iterations, opts.warmup)
+                    .map_err(|e| format!("day{}: {e}", day.day))?,
+            );
+        }
+    } else {
+        let day_num = opts.day.unwrap_or_default();
+        let day =
+            days::get(day_num).ok_or_else(|| UsageError(format!("unknown day: {day_num}")))?;
+        let input = match &opts.input {
+            Some(path) => fs::read(path)?,
+            None => {
+                let mut input = Vec::new();
+                io::stdin().lock().read_to_end(&mut input)?;
+                input
+            }
+        };
+        results.push(bench_day(day, &input, opts.iterations, opts.warmup)?);
+    }
+
+    let regressed = print_results(&results, baseline.as_deref(), opts.threshold);
+
+    if let Some(path) = &opts.output {
+        let v = json::Value::object([
+            ("iterations", json::Value::Int(opts.iterations as i128)),
+            (
+                "days",
+                json::Value::Array(results.iter().map(DayBench::to_json).collect()),
+            ),
+        ]);
+        fs::write(path, format!("{v}\n")).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+
+    if regressed {
+        return Ok(process::ExitCode::FAILURE);
+    }
+    Ok(process::ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, UsageError> {
+        parse_options(&mut Parser::new(args.iter().map(|a| a.to_string())))
+    }
+
+    #[test]
+    fn test_parse_options() -> Result<(), UsageError> {
+        let opts = parse(&["--day", "5", "--iterations", "3", "--compare", "b.json"])?;
+        assert_eq!(
+            opts,
+            Options {
+                day: Some(5),
+                iterations: 3,
+                compare: Some("b.json".into()),
+                ..Default::default()
+            }
+        );
+
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--day", "5", "--iterations", "0"]).is_err());
+        assert!(parse(&["--day", "5", "--threshold", "-1"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values: Vec<_> = (1..=20).collect();
+        assert_eq!(percentile(&values, 50.0), 10);
+        assert_eq!(percentile(&values, 95.0), 19);
+        assert_eq!(percentile(&values, 100.0), 20);
+        assert_eq!(percentile(&[7], 95.0), 7);
+    }
+
+    #[test]
+    fn test_bench_day() -> Result<(), Box<dyn error::Error>> {
+        let day = days::get(1).unwrap();
+        let bench = bench_day(day, b"3   4\n4   3\n", 3, 1)?;
+        assert_eq!(
+            bench
+                .stages
+                .iter()
+                .map(|s| s.stage.as_str())
+                .collect::<Vec<_>>(),
+            ["parse", "part1", "part2"]
+        );
+        for s in &bench.stages {
+            assert!(s.min <= s.median && s.median <= s.p95);
+        }
+
+        // Results survive a round trip through JSON.
+        let v = json::parse(&bench.to_json().to_string())?;
+        assert_eq!(DayBench::from_json(&v), Some(bench));
+        Ok(())
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = |median_ms, allocs| Stats {
+            stage: "parse".to_string(),
+            min: time::Duration::from_millis(median_ms),
+            median: time::Duration::from_millis(median_ms),
+            p95: time::Duration::from_millis(median_ms),
+            allocs,
+            alloc_bytes: 0,
+        };
+        let c = Change::new(&stats(105, 10), &stats(100, 10));
+        assert!(!c.regressed(10.0));
+        let c = Change::new(&stats(120, 10), &stats(100, 10));
+        assert!(c.regressed(10.0));
+        let c = Change::new(&stats(100, 12), &stats(100, 10));
+        assert!(c.regressed(10.0));
+        let c = Change::new(&stats(100, 1), &stats(100, 0));
+        assert!(c.regressed(10.0));
+    }
+}
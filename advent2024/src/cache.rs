@@ -0,0 +1,90 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::path;
+use std::process;
+
+use aoc_common::{cache, Cache};
+
+use advent2024::days;
+
+use crate::args::{Arg, Parser, UsageError};
+
+pub const USAGE: &str = "Usage: advent2024 cache <clear|prune|dir> [OPTIONS]
+
+Manages the cache of answers written by advent2024 run and the days' binaries.
+Answers are keyed by the SHA-256 digest of the input, the day's parameters and
+the version of the day's code, which changes whenever its sources do.
+
+Commands:
+  clear      Remove every cached answer, or only day N's with --day.
+  prune      Remove answers cached by other versions of the days' code.
+  dir        Print the cache directory.
+
+Options:
+  --day N          Only clear day N's answers.
+  --cache-dir DIR  The cache directory. Defaults to the AOC_CACHE_DIR
+                   environment variable or advent2024 in $XDG_CACHE_HOME
+                   or ~/.cache.
+";
+
+// run implements the cache command.
+pub fn run(args: &mut Parser) -> Result<process::ExitCode, Box<dyn error::Error>> {
+    let mut command: Option<String> = None;
+    let mut dir: Option<path::PathBuf> = None;
+    let mut day: Option<u8> = None;
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Flag(f) if f == "--cache-dir" => dir = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--day" => day = Some(args.parse(&f)?),
+            Arg::Flag(f) => return Err(UsageError(format!("unknown flag: {f}")).into()),
+            Arg::Positional(p) if command.is_none() => command = Some(p),
+            Arg::Positional(p) => {
+                return Err(UsageError(format!("unexpected argument: {p}")).into())
+            }
+        }
+    }
+
+    let dir = dir.or_else(Cache::default_dir).ok_or_else(|| {
+        UsageError(format!(
+            "no cache directory: set --cache-dir or {}",
+            cache::ENV_VAR
+        ))
+    })?;
+    let cache = Cache::new(dir);
+    if let Some(n) = day {
+        if days::get(n).is_none() {
+            return Err(UsageError(format!("unknown day: {n}")).into());
+        }
+    }
+
+    match command.as_deref() {
+        Some("clear") => {
+            let removed = cache.clear(day)?;
+            println!("removed {removed} entries from {}", cache.dir().display());
+        }
+        Some("prune") if day.is_none() => {
+            let removed = cache.prune(|n| days::get(n).map(|d| d.version))?;
+            println!("removed {removed} entries from {}", cache.dir().display());
+        }
+        Some("dir") if day.is_none() => println!("{}", cache.dir().display()),
+        Some("prune" | "dir") => {
+            return Err(UsageError("--day can only be used with clear".to_string()).into())
+        }
+        Some(c) => return Err(UsageError(format!("unknown cache command: {c}")).into()),
+        None => return Err(UsageError("a cache command is required".to_string()).into()),
+    }
+    Ok(process::ExitCode::SUCCESS)
+}
@@ -0,0 +1,79 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::path;
+use std::process;
+
+use advent2024::solutions;
+
+use crate::args::{Arg, Parser, UsageError};
+
+pub const USAGE: &str = "Usage: advent2024 check [OPTIONS]
+
+Runs every day on its input in a solutions repository and compares the answers
+with the expected ones. The repository holds a 2024/dayN/input.txt and
+2024/dayN/solution.txt file for each day where solution.txt has the answer to
+each part on its own line. Days without both files are skipped.
+
+Options:
+  --solutions DIR  Path to the solutions repository. Defaults to the
+                   SOLUTIONS_REPO environment variable or
+                   ../advent-of-code-solutions.
+  --day N          Only check day N.
+";
+
+// run implements the check command.
+pub fn run(args: &mut Parser) -> Result<process::ExitCode, Box<dyn error::Error>> {
+    let mut dir: Option<path::PathBuf> = None;
+    let mut day: Option<u8> = None;
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Flag(f) if f == "--solutions" => dir = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--day" => day = Some(args.parse(&f)?),
+            Arg::Flag(f) => return Err(UsageError(format!("unknown flag: {f}")).into()),
+            Arg::Positional(p) => {
+                return Err(UsageError(format!("unexpected argument: {p}")).into())
+            }
+        }
+    }
+    let dir = dir.unwrap_or_else(solutions::solutions_dir);
+
+    let checks = match day {
+        Some(n) => {
+            let day =
+                advent2024::days::get(n).ok_or_else(|| UsageError(format!("unknown day: {n}")))?;
+            vec![solutions::check_day(day, &dir)]
+        }
+        None => solutions::check_all(&dir),
+    };
+
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut skipped = 0;
+    for check in &checks {
+        print!("{check}");
+        match &check.outcome {
+            solutions::Outcome::Skipped(_) => skipped += 1,
+            _ if check.failed() => failed += 1,
+            _ => passed += 1,
+        }
+    }
+    println!("{passed} passed, {failed} failed, {skipped} skipped");
+
+    if failed > 0 {
+        return Ok(process::ExitCode::FAILURE);
+    }
+    Ok(process::ExitCode::SUCCESS)
+}
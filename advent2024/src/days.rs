@@ -0,0 +1,333 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Error, Generated, Param, Params, Rng, Runner};
+
+// Day is an entry in the registry of solutions.
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    // version identifies the code of the day's solution. It is part of the key of cached answers.
+    pub version: &'static str,
+    // params declares the day's tunable parameters and their defaults.
+    pub params: &'static [Param],
+    // build returns the day's solution with the given parameters overriding its defaults.
+    pub build: fn(&Params) -> Result<Box<dyn Runner>, Error>,
+    // reference returns the day's slow but simple reference solution with the given parameters.
+    // It is used to check the solution in differential tests.
+    pub reference: fn(&Params) -> Result<Box<dyn Runner>, Error>,
+    // generate returns a random input of the given size along with any answers known from the
+    // way it was constructed. What the size measures differs between days.
+    pub generate: fn(&mut Rng, usize) -> Generated,
+    // default_size is the size of generated inputs that are similar to the real puzzle input.
+    pub default_size: usize,
+}
+
+impl Day {
+    // solution returns the day's solution with its default parameters.
+    pub fn solution(&self) -> Box<dyn Runner> {
+        match (self.build)(&Params::new()) {
+            Ok(s) => s,
+            Err(e) => unreachable!("day{}: default parameters are invalid: {e}", self.day),
+        }
+    }
+
+    // with_params returns the day's solution with the given parameters overriding its defaults.
+    pub fn with_params(&self, params: &Params) -> Result<Box<dyn Runner>, Error> {
+        (self.build)(params)
+    }
+}
+
+// plain returns the solution for a day that has no parameters. An error is returned if any
+// parameters are given.
+fn plain(params: &Params, solution: impl Runner + 'static) -> Result<Box<dyn Runner>, Error> {
+    params.check_known(&[])?;
+    Ok(Box::new(solution))
+}
+
+// DAYS is the registry of all solutions in order. Solutions are built with the same default
+// parameters as each day's binary unless others are given.
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        title: "Historian Hysteria",
+        version: day1::VERSION,
+        params: day1::PARAMS,
+        build: |p| Ok(Box::new(day1::Day1::from_params(p)?)),
+        reference: |p| {
+            // The memory budget doesn't change the answers so the reference ignores it.
+            p.check(day1::PARAMS)?;
+            Ok(Box::new(day1::reference::Reference))
+        },
+        generate: day1::generator::generate,
+        default_size: day1::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 2,
+        title: "Red-Nosed Reports",
+        version: day2::VERSION,
+        params: day2::PARAMS,
+        build: |p| Ok(Box::new(day2::Day2::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day2::reference::Reference(
+                day2::Day2::from_params(p)?,
+            )))
+        },
+        generate: day2::generator::generate,
+        default_size: day2::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 3,
+        title: "Mull It Over",
+        version: day3::VERSION,
+        params: &[],
+        build: |p| plain(p, day3::Day3),
+        reference: |p| plain(p, day3::reference::Reference),
+        generate: day3::generator::generate,
+        default_size: day3::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 4,
+        title: "Ceres Search",
+        version: day4::VERSION,
+        params: &[],
+        build: |p| plain(p, day4::Day4),
+        reference: |p| plain(p, day4::reference::Reference),
+        generate: day4::generator::generate,
+        default_size: day4::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 5,
+        title: "Print Queue",
+        version: day5::VERSION,
+        params: &[],
+        build: |p| plain(p, day5::Day5),
+        reference: |p| plain(p, day5::reference::Reference),
+        generate: day5::generator::generate,
+        default_size: day5::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 6,
+        title: "Guard Gallivant",
+        version: day6::VERSION,
+        params: &[],
+        build: |p| plain(p, day6::Day6),
+        reference: |p| plain(p, day6::reference::Reference),
+        generate: day6::generator::generate,
+        default_size: day6::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 7,
+        title: "Bridge Repair",
+        version: day7::VERSION,
+        params: &[],
+        build: |p| plain(p, day7::Day7),
+        reference: |p| plain(p, day7::reference::Reference),
+        generate: day7::generator::generate,
+        default_size: day7::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 8,
+        title: "Resonant Collinearity",
+        version: day8::VERSION,
+        params: &[],
+        build: |p| plain(p, day8::Day8),
+        reference: |p| plain(p, day8::reference::Reference),
+        generate: day8::generator::generate,
+        default_size: day8::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 9,
+        title: "Disk Fragmenter",
+        version: day9::VERSION,
+        params: &[],
+        build: |p| plain(p, day9::Day9),
+        reference: |p| plain(p, day9::reference::Reference),
+        generate: day9::generator::generate,
+        default_size: day9::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 10,
+        title: "Hoof It",
+        version: day10::VERSION,
+        params: &[],
+        build: |p| plain(p, day10::Day10),
+        reference: |p| plain(p, day10::reference::Reference),
+        generate: day10::generator::generate,
+        default_size: day10::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 11,
+        title: "Plutonian Pebbles",
+        version: day11::VERSION,
+        params: day11::PARAMS,
+        build: |p| Ok(Box::new(day11::Day11::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day11::reference::Reference(
+                day11::Day11::from_params(p)?,
+            )))
+        },
+        generate: day11::generator::generate,
+        default_size: day11::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 12,
+        title: "Garden Groups",
+        version: day12::VERSION,
+        params: &[],
+        build: |p| plain(p, day12::Day12),
+        reference: |p| plain(p, day12::reference::Reference),
+        generate: day12::generator::generate,
+        default_size: day12::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 13,
+        title: "Claw Contraption",
+        version: day13::VERSION,
+        params: day13::PARAMS,
+        build: |p| Ok(Box::new(day13::Day13::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day13::reference::Reference(
+                day13::Day13::from_params(p)?,
+            )))
+        },
+        generate: day13::generator::generate,
+        default_size: day13::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 14,
+        title: "Restroom Redoubt",
+        version: day14::VERSION,
+        params: day14::PARAMS,
+        build: |p| Ok(Box::new(day14::Day14::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day14::reference::Reference(
+                day14::Day14::from_params(p)?,
+            )))
+        },
+        generate: day14::generator::generate,
+        default_size: day14::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 15,
+        title: "Warehouse Woes",
+        version: day15::VERSION,
+        params: &[],
+        build: |p| plain(p, day15::Day15),
+        reference: |p| plain(p, day15::reference::Reference),
+        generate: day15::generator::generate,
+        default_size: day15::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 16,
+        title: "Reindeer Maze",
+        version: day16::VERSION,
+        params: &[],
+        build: |p| plain(p, day16::Day16),
+        reference: |p| plain(p, day16::reference::Reference),
+        generate: day16::generator::generate,
+        default_size: day16::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 17,
+        title: "Chronospatial Computer",
+        version: day17::VERSION,
+        params: &[],
+        build: |p| plain(p, day17::Day17),
+        reference: |p| plain(p, day17::reference::Reference),
+        generate: day17::generator::generate,
+        default_size: day17::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 18,
+        title: "RAM Run",
+        version: day18::VERSION,
+        params: day18::PARAMS,
+        build: |p| Ok(Box::new(day18::Day18::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day18::reference::Reference(
+                day18::Day18::from_params(p)?,
+            )))
+        },
+        generate: day18::generator::generate,
+        default_size: day18::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 19,
+        title: "Linen Layout",
+        version: day19::VERSION,
+        params: &[],
+        build: |p| plain(p, day19::Day19),
+        reference: |p| plain(p, day19::reference::Reference),
+        generate: day19::generator::generate,
+        default_size: day19::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 20,
+        title: "Race Condition",
+        version: day20::VERSION,
+        params: day20::PARAMS,
+        build: |p| Ok(Box::new(day20::Day20::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day20::reference::Reference(
+                day20::Day20::from_params(p)?,
+            )))
+        },
+        generate: day20::generator::generate,
+        default_size: day20::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 21,
+        title: "Keypad Conundrum",
+        version: day21::VERSION,
+        params: day21::PARAMS,
+        build: |p| Ok(Box::new(day21::Day21::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day21::reference::Reference(
+                day21::Day21::from_params(p)?,
+            )))
+        },
+        generate: day21::generator::generate,
+        default_size: day21::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 22,
+        title: "Monkey Market",
+        version: day22::VERSION,
+        params: day22::PARAMS,
+        build: |p| Ok(Box::new(day22::Day22::from_params(p)?)),
+        reference: |p| {
+            Ok(Box::new(day22::reference::Reference(
+                day22::Day22::from_params(p)?,
+            )))
+        },
+        generate: day22::generator::generate,
+        default_size: day22::generator::DEFAULT_SIZE,
+    },
+    Day {
+        day: 23,
+        title: "LAN Party",
+        version: day23::VERSION,
+        params: &[],
+        build: |p| plain(p, day23::Day23),
+        reference: |p| plain(p, day23::reference::Reference),
+        generate: day23::generator::generate,
+        default_size: day23::generator::DEFAULT_SIZE,
+    },
+];
+
+// get returns the registry entry for the given day.
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
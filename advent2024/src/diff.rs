@@ -0,0 +1,80 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::process;
+
+use advent2024::days::{self, Day};
+use advent2024::differential;
+
+use crate::args::{Arg, Parser, UsageError};
+
+pub const USAGE: &str = "Usage: advent2024 diff [OPTIONS]
+
+Compares each day's solution with its slow but simple reference solution on
+randomly generated inputs. Inputs for which they disagree are shrunk to a small
+reproducer and printed along with their seed.
+
+Options:
+  --day N    Only check day N.
+  --seeds N  Number of inputs to generate for each day. Defaults to 100.
+  --seed N   First seed. Seeds are consecutive from it. Defaults to 0.
+  --size N   Size of the inputs. Defaults to a tenth of the size of a real
+             puzzle input.
+";
+
+// DEFAULT_SEEDS is the number of inputs generated for each day if --seeds is not given.
+const DEFAULT_SEEDS: u64 = 100;
+
+// run implements the diff command.
+pub fn run(args: &mut Parser) -> Result<process::ExitCode, Box<dyn error::Error>> {
+    let mut day: Option<u8> = None;
+    let mut seeds = DEFAULT_SEEDS;
+    let mut first: u64 = 0;
+    let mut size: Option<usize> = None;
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Flag(f) if f == "--day" => day = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--seeds" => seeds = args.parse(&f)?,
+            Arg::Flag(f) if f == "--seed" => first = args.parse(&f)?,
+            Arg::Flag(f) if f == "--size" => size = Some(args.parse(&f)?),
+            Arg::Flag(f) => return Err(UsageError(format!("unknown flag: {f}")).into()),
+            Arg::Positional(p) => {
+                return Err(UsageError(format!("unexpected argument: {p}")).into())
+            }
+        }
+    }
+
+    let selected: Vec<&Day> = match day {
+        Some(n) => vec![days::get(n).ok_or_else(|| UsageError(format!("unknown day: {n}")))?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut failed = 0;
+    for day in selected {
+        let size = size.unwrap_or_else(|| differential::test_size(day));
+        for seed in first..first.saturating_add(seeds) {
+            if let Some(failure) = differential::check_seed(day, seed, size)? {
+                println!("{failure}");
+                failed += 1;
+            }
+        }
+    }
+    println!("{failed} mismatches");
+
+    if failed > 0 {
+        return Ok(process::ExitCode::FAILURE);
+    }
+    Ok(process::ExitCode::SUCCESS)
+}
@@ -0,0 +1,310 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io;
+use std::panic;
+
+use aoc_common::{Error, Params, Part, Rng, Runner};
+
+use crate::days::Day;
+
+/// Mismatch is a part for which a day's solution disagrees with its reference solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    pub part: Part,
+
+    /// solution is the solution's answer or a description of its error.
+    pub solution: Result<String, String>,
+
+    /// reference is the reference solution's answer.
+    pub reference: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.solution {
+            Ok(answer) => write!(f, "part {}: solution answered {answer}", self.part)?,
+            Err(e) => write!(f, "part {}: solution failed: {e}", self.part)?,
+        }
+        write!(f, ", reference answered {}", self.reference)
+    }
+}
+
+// solve returns the runner's answer to each part of the puzzle or a description of its error. A
+// panic is returned as an error.
+fn solve(runner: &dyn Runner, input: &[u8]) -> Vec<Result<String, String>> {
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let prepared = runner.prepare(&mut io::Cursor::new(input))?;
+        Ok::<_, Error>(
+            Part::BOTH
+                .iter()
+                .map(|part| {
+                    prepared
+                        .solve(*part)
+                        .map(|a| a.value)
+                        .map_err(|e| e.to_string())
+                })
+                .collect(),
+        )
+    }));
+    match result {
+        Ok(Ok(answers)) => answers,
+        Ok(Err(e)) => vec![Err(e.message()); Part::BOTH.len()],
+        Err(panic) => {
+            let msg = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            vec![Err(format!("panicked: {msg}")); Part::BOTH.len()]
+        }
+    }
+}
+
+/// compare runs the day's solution and its reference solution on the input and returns the first
+/// part for which they disagree. A part is only compared if the reference solution answers it, so
+/// reference solutions can fail for inputs that they cannot decide or that are too large for
+/// them. An error is returned if either solution cannot be built with the parameters.
+pub fn compare(day: &Day, params: &Params, input: &[u8]) -> Result<Option<Mismatch>, Error> {
+    let solution = day.with_params(params)?;
+    let reference = (day.reference)(params)?;
+
+    let expected = solve(reference.as_ref(), input);
+    if expected.iter().all(|e| e.is_err()) {
+        return Ok(None);
+    }
+    let actual = solve(solution.as_ref(), input);
+
+    for ((part, expected), actual) in Part::BOTH.iter().zip(expected).zip(actual) {
+        match expected {
+            Ok(reference) if actual.as_ref() != Ok(&reference) => {
+                return Ok(Some(Mismatch {
+                    part: *part,
+                    solution: actual,
+                    reference,
+                }))
+            }
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+// join joins the lines into an input with a newline after every line.
+fn join(lines: &[Vec<u8>]) -> Vec<u8> {
+    lines
+        .iter()
+        .flat_map(|line| line.iter().chain(b"\n"))
+        .copied()
+        .collect()
+}
+
+/// shrink returns the smallest input it can find for which the day's solution still disagrees
+/// with its reference solution on the same part as in the mismatch. Whole lines are removed
+/// first, then columns so that grids stay rectangular, then single characters, until none of
+/// them can be removed.
+pub fn shrink(day: &Day, params: &Params, input: &[u8], mismatch: &Mismatch) -> Vec<u8> {
+    let fails = |lines: &[Vec<u8>]| {
+        matches!(
+            compare(day, params, &join(lines)),
+            Ok(Some(m)) if m.part == mismatch.part
+        )
+    };
+
+    let mut lines: Vec<Vec<u8>> = input.split(|b| *b == b'\n').map(|l| l.to_vec()).collect();
+    if input.ends_with(b"\n") {
+        lines.pop();
+    }
+    if !fails(&lines) {
+        // The input can't be split into lines without losing the mismatch.
+        return input.to_vec();
+    }
+
+    loop {
+        let before = lines.clone();
+
+        // Remove runs of lines, halving the length of the runs each time.
+        let mut run = lines.len() / 2;
+        while run > 0 {
+            let mut i = 0;
+            while i < lines.len() {
+                let mut candidate = lines.clone();
+                candidate.drain(i..(i + run).min(lines.len()));
+                if fails(&candidate) {
+                    lines = candidate;
+                } else {
+                    i += run;
+                }
+            }
+            run /= 2;
+        }
+
+        // Remove columns.
+        let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        for col in (0..width).rev() {
+            let mut candidate = lines.clone();
+            for line in candidate.iter_mut().filter(|l| l.len() > col) {
+                line.remove(col);
+            }
+            if fails(&candidate) {
+                lines = candidate;
+            }
+        }
+
+        // Remove single characters.
+        for i in 0..lines.len() {
+            for col in (0..lines[i].len()).rev() {
+                let mut candidate = lines.clone();
+                candidate[i].remove(col);
+                if fails(&candidate) {
+                    lines = candidate;
+                }
+            }
+        }
+
+        if lines == before {
+            return join(&lines);
+        }
+    }
+}
+
+/// test_size returns the size of the inputs generated for differential tests. They are a tenth of
+/// the size of a real input so that the reference solutions are fast enough.
+pub fn test_size(day: &Day) -> usize {
+    (day.default_size / 10).max(5)
+}
+
+/// Failure is a generated input for which a day's solution disagrees with its reference
+/// solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub day: u8,
+    pub seed: u64,
+    pub params: Params,
+
+    /// input is the generated input after shrinking.
+    pub input: Vec<u8>,
+
+    /// mismatch is the disagreement on the shrunk input.
+    pub mismatch: Mismatch,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "day {} seed {}: {}", self.day, self.seed, self.mismatch)?;
+        if !self.params.is_empty() {
+            write!(f, "params:\n{}", self.params)?;
+        }
+        write!(f, "input:\n{}", String::from_utf8_lossy(&self.input))
+    }
+}
+
+/// check_seed generates an input of the given size for the day from the seed and compares the
+/// day's solution with its reference solution on it. A Failure holding the shrunk input is
+/// returned if they disagree.
+pub fn check_seed(day: &Day, seed: u64, size: usize) -> Result<Option<Failure>, Error> {
+    let generated = (day.generate)(&mut Rng::new(seed), size);
+    let input = generated.input.as_bytes();
+    let mismatch = match compare(day, &generated.params, input)? {
+        Some(mismatch) => mismatch,
+        None => return Ok(None),
+    };
+
+    let input = shrink(day, &generated.params, input, &mismatch);
+    let mismatch = compare(day, &generated.params, &input)?.unwrap_or(mismatch);
+    Ok(Some(Failure {
+        day: day.day,
+        seed,
+        params: generated.params,
+        input,
+        mismatch,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Generated, Lines, Solution};
+    use std::error;
+    use std::io::BufRead;
+
+    // Sum sums the numbers on each line. The solution gets numbers over 100 wrong and the
+    // reference does not.
+    struct Sum {
+        buggy: bool,
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+        type Part1 = i64;
+        type Part2 = i64;
+
+        fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+            let mut nums = Vec::new();
+            for line in Lines::new(r) {
+                nums.push(line?.token().parse("integer")?);
+            }
+            Ok(nums)
+        }
+
+        fn part1(&self, nums: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+            Ok(nums.iter().sum())
+        }
+
+        fn part2(&self, nums: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+            if self.buggy && nums.iter().any(|n| *n > 100) {
+                return Err("too large".into());
+            }
+            Ok(nums.iter().sum())
+        }
+    }
+
+    const DAY: Day = Day {
+        day: 0,
+        title: "Sum",
+        version: "0.0.0",
+        params: &[],
+        build: |_| Ok(Box::new(Sum { buggy: true })),
+        reference: |_| Ok(Box::new(Sum { buggy: false })),
+        generate: |_, _| Generated::default(),
+        default_size: 0,
+    };
+
+    #[test]
+    fn test_compare() -> Result<(), Error> {
+        let params = Params::new();
+        assert_eq!(compare(&DAY, &params, b"1\n2\n")?, None);
+
+        // Inputs the reference can't parse are not compared.
+        assert_eq!(compare(&DAY, &params, b"x\n")?, None);
+
+        let mismatch = compare(&DAY, &params, b"1\n200\n")?.unwrap();
+        assert_eq!(mismatch.part, Part::Two);
+        assert_eq!(
+            mismatch.to_string(),
+            "part 2: solution failed: too large, reference answered 201"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_shrink() -> Result<(), Error> {
+        let params = Params::new();
+        let input = b"1\n2\n30\n4\n5678\n6\n7\n";
+        let mismatch = compare(&DAY, &params, input)?.unwrap();
+        assert_eq!(shrink(&DAY, &params, input, &mismatch), b"567\n");
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::fs;
+use std::io::{self, Write};
+use std::path;
+use std::process;
+use std::time;
+
+use aoc_common::Rng;
+
+use crate::args::{Arg, Parser, UsageError};
+
+pub const USAGE: &str = "Usage: advent2024 generate --day N [OPTIONS]
+
+Generates a random input for a day and writes it to stdout. Answers that are
+known from the way the input was built are written with it when --output-dir
+is given.
+
+Options:
+  --day N           Day to generate an input for.
+  --seed N          Seed for the random number generator. Defaults to one based
+                    on the current time, which is printed to stderr.
+  --size N          Size of the input. What it measures depends on the day.
+                    Defaults to about the size of a real puzzle input.
+  --output-dir DIR  Write NAME.in.txt, NAME.out.txt and NAME.params.txt to DIR
+                    instead of writing the input to stdout. Unknown answers are
+                    left as empty lines in NAME.out.txt.
+  --name NAME       Base name of the files in --output-dir. Defaults to
+                    generated-SEED.
+";
+
+// run implements the generate command.
+pub fn run(args: &mut Parser) -> Result<process::ExitCode, Box<dyn error::Error>> {
+    let mut day: Option<u8> = None;
+    let mut seed: Option<u64> = None;
+    let mut size: Option<usize> = None;
+    let mut dir: Option<path::PathBuf> = None;
+    let mut name: Option<String> = None;
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Flag(f) if f == "--day" => day = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--seed" => seed = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--size" => size = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--output-dir" => dir = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--name" => name = Some(args.value(&f)?),
+            Arg::Flag(f) => return Err(UsageError(format!("unknown flag: {f}")).into()),
+            Arg::Positional(p) => {
+                return Err(UsageError(format!("unexpected argument: {p}")).into())
+            }
+        }
+    }
+
+    let n = day.ok_or_else(|| UsageError("--day is required".to_string()))?;
+    let day = advent2024::days::get(n).ok_or_else(|| UsageError(format!("unknown day: {n}")))?;
+    let seed = match seed {
+        Some(seed) => seed,
+        None => {
+            let seed = time::SystemTime::now()
+                .duration_since(time::UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default();
+            eprintln!("seed: {seed}");
+            seed
+        }
+    };
+
+    let generated = (day.generate)(&mut Rng::new(seed), size.unwrap_or(day.default_size));
+
+    match dir {
+        Some(dir) => {
+            let name = name.unwrap_or_else(|| format!("generated-{seed}"));
+            fs::create_dir_all(&dir)?;
+            fs::write(dir.join(format!("{name}.in.txt")), &generated.input)?;
+            fs::write(dir.join(format!("{name}.out.txt")), generated.expected())?;
+            fs::write(
+                dir.join(format!("{name}.params.txt")),
+                generated.params.to_string(),
+            )?;
+        }
+        None => io::stdout().lock().write_all(generated.input.as_bytes())?,
+    }
+
+    Ok(process::ExitCode::SUCCESS)
+}
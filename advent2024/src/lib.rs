@@ -0,0 +1,21 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Library advent2024 holds the registry of every day's solution and the checks against the
+//! solutions repository so that they can be shared by the advent2024 binary and the tests.
+
+pub mod days;
+pub mod differential;
+pub mod pool;
+pub mod solutions;
@@ -0,0 +1,93 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Program advent2024 runs the solution for any day of Advent of Code 2024.
+
+use std::env;
+use std::process;
+
+use aoc_common::cli;
+
+mod alloc;
+mod args;
+mod bench;
+mod cache;
+mod check;
+mod diff;
+mod generate;
+mod run;
+
+use args::{Arg, Parser};
+
+// ALLOC counts allocations so that they can be reported by the bench command.
+#[global_allocator]
+static ALLOC: alloc::Counting = alloc::Counting;
+
+const USAGE: &str = "Usage: advent2024 <COMMAND> [OPTIONS]
+
+Commands:
+  run       Run a day's solution, or every day with --all.
+  check     Check every day's answers against a solutions repository.
+  bench     Measure the time and allocations of a day's solution.
+  generate  Generate a random input for a day.
+  diff      Compare solutions with reference solutions on random inputs.
+  cache     Clear or prune the cache of answers.
+  help      Print help for a command.
+";
+
+fn help(args: &mut Parser) -> process::ExitCode {
+    match args.next() {
+        Some(Arg::Positional(cmd)) if cmd == "run" => print!("{}", run::USAGE),
+        Some(Arg::Positional(cmd)) if cmd == "check" => print!("{}", check::USAGE),
+        Some(Arg::Positional(cmd)) if cmd == "bench" => print!("{}", bench::USAGE),
+        Some(Arg::Positional(cmd)) if cmd == "generate" => print!("{}", generate::USAGE),
+        Some(Arg::Positional(cmd)) if cmd == "diff" => print!("{}", diff::USAGE),
+        Some(Arg::Positional(cmd)) if cmd == "cache" => print!("{}", cache::USAGE),
+        _ => print!("{}", USAGE),
+    }
+    process::ExitCode::SUCCESS
+}
+
+fn main() -> process::ExitCode {
+    let mut args = Parser::new(env::args().skip(1));
+
+    let result = match args.next() {
+        Some(Arg::Positional(cmd)) if cmd == "run" => run::run(&mut args),
+        Some(Arg::Positional(cmd)) if cmd == "check" => check::run(&mut args),
+        Some(Arg::Positional(cmd)) if cmd == "bench" => bench::run(&mut args),
+        Some(Arg::Positional(cmd)) if cmd == "generate" => generate::run(&mut args),
+        Some(Arg::Positional(cmd)) if cmd == "diff" => diff::run(&mut args),
+        Some(Arg::Positional(cmd)) if cmd == "cache" => cache::run(&mut args),
+        Some(Arg::Positional(cmd)) if cmd == "help" => Ok(help(&mut args)),
+        Some(Arg::Flag(f)) if f == "--help" || f == "-h" => Ok(help(&mut args)),
+        Some(Arg::Positional(cmd)) | Some(Arg::Flag(cmd)) => {
+            eprintln!("unknown command: {cmd}");
+            eprint!("{}", USAGE);
+            return process::ExitCode::from(cli::EXIT_USAGE);
+        }
+        None => {
+            eprint!("{}", USAGE);
+            return process::ExitCode::from(cli::EXIT_USAGE);
+        }
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) if e.is::<args::UsageError>() => {
+            eprintln!("error: {e}");
+            process::ExitCode::from(cli::EXIT_USAGE)
+        }
+        Err(e) => cli::report_error(e.as_ref()),
+    }
+}
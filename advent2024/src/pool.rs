@@ -0,0 +1,233 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any;
+use std::io::{self, Read};
+use std::panic;
+use std::process;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time;
+
+/// map calls f on each item using up to jobs threads and returns the results in the order of
+/// the items. A panic in f is caught and returned as an error holding the panic message so that
+/// it doesn't stop the other items.
+pub fn map<T, R>(items: Vec<T>, jobs: usize, f: impl Fn(T) -> R + Sync) -> Vec<Result<R, String>>
+where
+    T: Send,
+    R: Send,
+{
+    let n = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, n.max(1)) {
+            let tx = tx.clone();
+            let (queue, f) = (&queue, &f);
+            s.spawn(move || loop {
+                // The lock is released before f is called so that the workers run concurrently.
+                let next = queue.lock().ok().and_then(|mut q| q.next());
+                let Some((i, item)) = next else {
+                    break;
+                };
+                let result = panic::catch_unwind(panic::AssertUnwindSafe(|| f(item)))
+                    .map_err(|e| panic_message(e.as_ref()));
+                if tx.send((i, result)).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(tx);
+
+    let mut results: Vec<_> = rx.into_iter().collect();
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+// panic_message returns the message of a caught panic.
+fn panic_message(payload: &(dyn any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s.clone();
+    }
+    "panic".to_string()
+}
+
+/// Limits holds the limits placed on a child process. Limits that are None are not enforced.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Limits {
+    /// timeout is the time after which the process is killed.
+    pub timeout: Option<time::Duration>,
+
+    /// memory is the most virtual memory in bytes the process may use. Allocations beyond it
+    /// fail. It is only supported on Unix systems where it is set with the shell's ulimit.
+    pub memory: Option<u64>,
+}
+
+/// Exit describes how a child process ended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exit {
+    /// status is the exit status of the process, or None if it was killed for running longer
+    /// than the timeout.
+    pub status: Option<process::ExitStatus>,
+
+    /// stdout and stderr hold everything the process wrote.
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+
+    /// elapsed is the time the process ran for.
+    pub elapsed: time::Duration,
+}
+
+impl Exit {
+    /// timed_out returns true if the process was killed for running longer than the timeout.
+    pub fn timed_out(&self) -> bool {
+        self.status.is_none()
+    }
+
+    /// out_of_memory returns true if the process failed to allocate memory.
+    pub fn out_of_memory(&self) -> bool {
+        String::from_utf8_lossy(&self.stderr).contains("memory allocation of")
+    }
+
+    /// panic_message returns the message of the panic that ended the process, if any.
+    pub fn panic_message(&self) -> Option<String> {
+        let stderr = String::from_utf8_lossy(&self.stderr);
+        // The message is on the line after the location of the panic.
+        let mut lines = stderr.lines().skip_while(|l| !l.contains("panicked at"));
+        lines.next()?;
+        Some(lines.next().unwrap_or_default().to_string())
+    }
+}
+
+// POLL is how often a running child process is checked for having exited.
+const POLL: time::Duration = time::Duration::from_millis(10);
+
+// reader reads everything from r on a new thread so that a child process can't block on a full
+// pipe while it is being waited for.
+fn reader(r: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut r) = r {
+            // A read error only loses output, which is reported as it is.
+            let _ = r.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+/// run_limited runs the program with the arguments under the limits and returns how it ended.
+/// stdin is closed and stdout and stderr are captured.
+pub fn run_limited(program: &str, args: &[String], limits: Limits) -> io::Result<Exit> {
+    let mut cmd = match limits.memory {
+        Some(bytes) if cfg!(unix) => {
+            let mut cmd = process::Command::new("sh");
+            cmd.arg("-c")
+                .arg(format!("ulimit -v {} && exec \"$0\" \"$@\"", bytes / 1024))
+                .arg(program);
+            cmd
+        }
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "memory limits are only supported on Unix",
+            ))
+        }
+        None => process::Command::new(program),
+    };
+    cmd.args(args)
+        .stdin(process::Stdio::null())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped());
+
+    let start = time::Instant::now();
+    let mut child = cmd.spawn()?;
+    let stdout = reader(child.stdout.take());
+    let stderr = reader(child.stderr.take());
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if limits.timeout.is_some_and(|t| start.elapsed() >= t) {
+            child.kill()?;
+            child.wait()?;
+            break None;
+        }
+        thread::sleep(POLL);
+    };
+    let elapsed = start.elapsed();
+
+    let join = |h: thread::JoinHandle<Vec<u8>>| h.join().unwrap_or_default();
+    Ok(Exit {
+        status,
+        stdout: join(stdout),
+        stderr: join(stderr),
+        elapsed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let results = map((0..20).collect(), 4, |n: u32| {
+            if n == 7 {
+                panic!("seven");
+            }
+            n * 2
+        });
+        assert_eq!(results.len(), 20);
+        assert_eq!(results[3], Ok(6));
+        assert_eq!(results[7], Err("seven".to_string()));
+        assert_eq!(results[19], Ok(38));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_limited() -> io::Result<()> {
+        let args = |s: &str| vec!["-c".to_string(), s.to_string()];
+
+        let exit = run_limited("sh", &args("echo out; echo err >&2"), Limits::default())?;
+        assert!(exit.status.is_some_and(|s| s.success()));
+        assert_eq!(exit.stdout, b"out\n");
+        assert_eq!(exit.stderr, b"err\n");
+
+        let limits = Limits {
+            timeout: Some(time::Duration::from_millis(50)),
+            memory: Some(512 << 20),
+        };
+        let exit = run_limited("sh", &args("exec sleep 5"), limits)?;
+        assert!(exit.timed_out());
+        assert!(exit.elapsed < time::Duration::from_secs(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let exit = Exit {
+            status: None,
+            stdout: Vec::new(),
+            stderr: b"\nthread 'main' panicked at day6/src/lib.rs:10:5:\nindex out of bounds\nnote: run with RUST_BACKTRACE=1\n".to_vec(),
+            elapsed: time::Duration::ZERO,
+        };
+        assert_eq!(exit.panic_message().as_deref(), Some("index out of bounds"));
+        assert!(!exit.out_of_memory());
+    }
+}
@@ -0,0 +1,690 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::error;
+use std::fs;
+use std::io;
+use std::path;
+use std::process;
+use std::thread;
+use std::time;
+
+use aoc_common::cache::{self, Spool};
+use aoc_common::cli::{self, Format};
+use aoc_common::{json, log, Cache, Key, Params, Part, Report};
+
+use crate::args::{Arg, Parser, UsageError};
+use advent2024::days::{self, Day};
+use advent2024::pool;
+
+pub const USAGE: &str = "Usage: advent2024 run [OPTIONS]
+
+Runs a day's solution and prints the answer to each part on its own line.
+
+Options:
+  --day N          Day to run (1-23).
+  --part P         Only run part P (1 or 2). Both parts are run by default.
+  --input FILE     Read the puzzle input from FILE. Defaults to stdin.
+  --all            Run every day concurrently and print a summary table.
+  --input-dir DIR  Directory holding a dayN/input.txt file for each day.
+                   Used with --all.
+  --jobs N         Run up to N days at once with --all. Defaults to the
+                   number of CPUs.
+  --timeout SECS   Stop a day that runs for longer than SECS seconds with
+                   --all.
+  --memory-limit MB
+                   Stop a day that uses more than MB megabytes of virtual
+                   memory with --all. Only supported on Unix.
+  --format FORMAT  Output format: text (default) or json. The JSON output
+                   includes answer types, timings and the input's SHA-256.
+  --param N=V      Set the day's parameter N to V, e.g. --param width=11 for
+                   day 14. May be repeated. Cannot be used with --all.
+  --params FILE    Read the day's parameters from FILE, which holds
+                   NAME = VALUE lines. Cannot be used with --all.
+  -v, -vv, -vvv    Print info, debug or trace events of the solutions to
+                   stderr.
+  --log SPEC       Set the level of events printed for each module, e.g.
+                   info,day16=trace. May be repeated.
+  --no-cache       Solve the input again without reading or writing cached
+                   answers.
+  --cache-dir DIR  Directory holding cached answers. Defaults to the
+                   AOC_CACHE_DIR environment variable or advent2024 in
+                   $XDG_CACHE_HOME or ~/.cache.
+
+Days with tunable parameters, such as grid sizes, also read them from
+AOC_DAYN_NAME environment variables, e.g. AOC_DAY14_WIDTH=11. The environment
+takes precedence over --params and --param over both. Run a day's own binary
+with --help to list its parameters.
+
+With --all, each day runs in its own process so that a day that panics, times
+out or runs out of memory doesn't stop the others. Answers are compared with
+the lines of dayN/solution.txt next to the input when it exists, and the exit
+code is 1 if any day failed or gave a wrong answer.
+
+Events are also filtered by the AOC_LOG environment variable, which takes the
+same form as --log. -v and --log take precedence.
+
+Answers are cached keyed by the input's SHA-256 digest, the day's parameters
+and the version of the day's code, so an unchanged input is only solved once.
+Cached answers are marked in the JSON output and the --all table. The input is
+hashed before it is parsed, with stdin copied to a temporary file first, so
+cached answers are returned without parsing it and solutions don't print
+events. Use advent2024 cache to clear the cache.
+";
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<path::PathBuf>,
+    all: bool,
+    input_dir: Option<path::PathBuf>,
+    format: Format,
+    params: Params,
+    params_file: Option<path::PathBuf>,
+    verbosity: usize,
+    log: Vec<String>,
+    jobs: Option<usize>,
+    timeout: Option<time::Duration>,
+    memory_limit: Option<u64>,
+    no_cache: bool,
+    cache_dir: Option<path::PathBuf>,
+}
+
+fn parse_options(args: &mut Parser) -> Result<Options, UsageError> {
+    let mut opts = Options::default();
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Flag(f) if f == "--day" => opts.day = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--part" => opts.part = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--input" => opts.input = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--all" => opts.all = true,
+            Arg::Flag(f) if f == "--input-dir" => opts.input_dir = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--format" => opts.format = args.parse(&f)?,
+            Arg::Flag(f) if f == "--param" => {
+                let v = args.value(&f)?;
+                let (name, value) = v.split_once('=').ok_or_else(|| {
+                    UsageError(format!("invalid value for {f}: {v:?}: expected NAME=VALUE"))
+                })?;
+                opts.params.set(name.trim(), value.trim());
+            }
+            Arg::Flag(f) if f == "--params" => opts.params_file = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "--jobs" => {
+                let jobs = args.parse(&f)?;
+                if jobs == 0 {
+                    return Err(UsageError(format!("{f} must be at least 1")));
+                }
+                opts.jobs = Some(jobs);
+            }
+            Arg::Flag(f) if f == "--timeout" => {
+                let secs: f64 = args.parse(&f)?;
+                let timeout = time::Duration::try_from_secs_f64(secs)
+                    .map_err(|_| UsageError(format!("invalid value for {f}: {secs}")))?;
+                opts.timeout = Some(timeout);
+            }
+            Arg::Flag(f) if f == "--memory-limit" => opts.memory_limit = Some(args.parse(&f)?),
+            Arg::Flag(f) if f == "--no-cache" => opts.no_cache = true,
+            Arg::Flag(f) if f == "--cache-dir" => opts.cache_dir = Some(args.value(&f)?.into()),
+            Arg::Flag(f) if f == "-v" || f == "-vv" || f == "-vvv" => opts.verbosity += f.len() - 1,
+            Arg::Flag(f) if f == "--log" => {
+                let spec = args.value(&f)?;
+                log::Filter::default()
+                    .apply(&spec)
+                    .map_err(|e| UsageError(format!("invalid value for {f}: {e}")))?;
+                opts.log.push(spec);
+            }
+            Arg::Flag(f) => return Err(UsageError(format!("unknown flag: {f}"))),
+            Arg::Positional(p) => return Err(UsageError(format!("unexpected argument: {p}"))),
+        }
+    }
+
+    if opts.all == opts.day.is_some() {
+        return Err(UsageError(
+            "exactly one of --day or --all is required".to_string(),
+        ));
+    }
+    if opts.all && opts.input.is_some() {
+        return Err(UsageError("--input cannot be used with --all".to_string()));
+    }
+    if opts.all && (!opts.params.is_empty() || opts.params_file.is_some()) {
+        return Err(UsageError(
+            "--param and --params cannot be used with --all".to_string(),
+        ));
+    }
+    if !opts.all && opts.input_dir.is_some() {
+        return Err(UsageError(
+            "--input-dir can only be used with --all".to_string(),
+        ));
+    }
+    if opts.no_cache && opts.cache_dir.is_some() {
+        return Err(UsageError(
+            "--no-cache and --cache-dir cannot be used together".to_string(),
+        ));
+    }
+    if !opts.all && (opts.jobs.is_some() || opts.timeout.is_some() || opts.memory_limit.is_some()) {
+        return Err(UsageError(
+            "--jobs, --timeout and --memory-limit can only be used with --all".to_string(),
+        ));
+    }
+
+    Ok(opts)
+}
+
+// parts returns the parts to run for the given --part option.
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(p) => vec![p],
+        None => Part::BOTH.to_vec(),
+    }
+}
+
+// run implements the run command.
+pub fn run(args: &mut Parser) -> Result<process::ExitCode, Box<dyn error::Error>> {
+    let opts = parse_options(args)?;
+    log::configure(opts.verbosity, &opts.log).map_err(UsageError)?;
+
+    if opts.all {
+        return run_all(&opts);
+    }
+
+    let day_num = opts.day.unwrap_or_default();
+    let day = days::get(day_num).ok_or_else(|| UsageError(format!("unknown day: {day_num}")))?;
+    // Errors reading the params file keep their exit code, such as 3 for a missing file. Only
+    // unknown parameters and invalid values are usage errors.
+    let params = Params::resolve(
+        day.day,
+        day.params,
+        opts.params_file.as_deref(),
+        &opts.params,
+    )?;
+    let solution = day
+        .with_params(&params)
+        .map_err(|e| UsageError(e.message()))?;
+
+    let open = |path: &path::Path| {
+        fs::File::open(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+    };
+    let key =
+        |input_sha256: &str| Key::new(day.day, day.version, day.params, &params, input_sha256);
+    let parts = parts(opts.part);
+    let solution = solution.as_ref();
+    // With a cache, the input is hashed before it is parsed so that cached answers are returned
+    // without parsing it. stdin is copied to a temporary file first since it can only be read
+    // once. Without a cache, the input is parsed as it is read.
+    let (report, input_sha256) = match (cache_dir(&opts).map(Cache::new), &opts.input) {
+        (Some(cache), Some(path)) => cache.solve(key, solution, open(path)?, &parts)?,
+        (Some(cache), None) => {
+            let input = Spool::new(io::stdin().lock())?;
+            cache.solve(key, solution, input, &parts)?
+        }
+        (None, Some(path)) => cache::solve_uncached(solution, open(path)?, &parts)?,
+        (None, None) => cache::solve_uncached(solution, io::stdin().lock(), &parts)?,
+    };
+    match opts.format {
+        Format::Text => {
+            for answer in &report.answers {
+                println!("{}", answer.value);
+            }
+        }
+        Format::Json => println!("{}", day_json(day, Ok((&report, &input_sha256)))),
+    }
+
+    Ok(process::ExitCode::SUCCESS)
+}
+
+// cache_dir returns the directory of the cache, or None if caching is turned off.
+fn cache_dir(opts: &Options) -> Option<path::PathBuf> {
+    if opts.no_cache {
+        return None;
+    }
+    opts.cache_dir.clone().or_else(Cache::default_dir)
+}
+
+// day_json returns a JSON object describing the result of running the day's solution on the
+// input.
+fn day_json(day: &Day, result: Result<(&Report, &str), &dyn error::Error>) -> json::Value {
+    let mut fields = vec![
+        ("day".to_string(), json::Value::Int(day.day.into())),
+        ("title".to_string(), json::Value::from(day.title)),
+    ];
+    match result {
+        Ok((report, input_sha256)) => fields.extend(cli::report_fields(report, input_sha256)),
+        Err(e) => fields.push(("error".to_string(), json::Value::from(e.to_string()))),
+    }
+    json::Value::Object(fields)
+}
+
+// Row is a row in the summary table printed by run_all.
+struct Row {
+    day: String,
+    title: String,
+    part1: String,
+    part2: String,
+    status: String,
+    time: String,
+}
+
+// Status is the result of running a day with --all.
+#[derive(Debug, Clone, PartialEq)]
+enum Status {
+    // Ok is returned when every answer matches the expected one.
+    Ok,
+    // Wrong is returned when an answer doesn't match the expected one.
+    Wrong,
+    // Unchecked is returned when the day ran but there are no expected answers.
+    Unchecked,
+    // Error is returned when the day's input couldn't be read or the solution returned an
+    // error.
+    Error(String),
+    // Panicked is returned when the solution panicked.
+    Panicked(String),
+    // TimedOut is returned when the solution ran for longer than --timeout.
+    TimedOut,
+    // OutOfMemory is returned when the solution used more memory than --memory-limit.
+    OutOfMemory,
+}
+
+impl Status {
+    // failed returns true if the day counts as a failure in the exit code.
+    fn failed(&self) -> bool {
+        !matches!(self, Status::Ok | Status::Unchecked)
+    }
+
+    // name returns the name of the status shown in the summary table and JSON output.
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Wrong => "wrong",
+            Status::Unchecked => "unchecked",
+            Status::Error(_) => "error",
+            Status::Panicked(_) => "panic",
+            Status::TimedOut => "timeout",
+            Status::OutOfMemory => "out of memory",
+        }
+    }
+
+    // message returns a description of the failure, if any.
+    fn message(&self) -> Option<String> {
+        match self {
+            Status::Error(e) | Status::Panicked(e) => Some(e.clone()),
+            Status::TimedOut => Some("timed out".to_string()),
+            Status::OutOfMemory => Some("out of memory".to_string()),
+            Status::Ok | Status::Unchecked | Status::Wrong => None,
+        }
+    }
+}
+
+// Outcome is the result of running a day's solution in its own process.
+struct Outcome {
+    day: &'static Day,
+    status: Status,
+    // report is the JSON object printed by the day's run command if it succeeded.
+    report: Option<json::Value>,
+    // answers and expected hold the answer to each part and the expected answer, if any.
+    answers: Vec<(Part, String)>,
+    expected: Vec<String>,
+    // elapsed is the time taken to solve the puzzle, or the time the process ran for if it
+    // failed. It is None if the process didn't run.
+    elapsed: Option<time::Duration>,
+    // cached is true if the answers were read from the cache. elapsed is then the time taken
+    // when they were solved.
+    cached: bool,
+}
+
+impl Outcome {
+    // failed returns an outcome for a day that failed before printing a report.
+    fn failed(day: &'static Day, status: Status, elapsed: Option<time::Duration>) -> Self {
+        Outcome {
+            day,
+            status,
+            report: None,
+            answers: Vec::new(),
+            expected: Vec::new(),
+            elapsed,
+            cached: false,
+        }
+    }
+
+    // expected_answer returns the expected answer to the part, if any.
+    fn expected_answer(&self, part: Part) -> Option<&str> {
+        self.expected
+            .get(part as usize)
+            .map(|e| e.as_str())
+            .filter(|e| !e.is_empty())
+    }
+
+    // row returns the summary table row for the outcome.
+    fn row(&self) -> Row {
+        let mut row = Row {
+            day: self.day.day.to_string(),
+            title: self.day.title.to_string(),
+            part1: String::new(),
+            part2: String::new(),
+            status: self.status.name().to_string(),
+            time: self.elapsed.map(|t| format!("{t:.1?}")).unwrap_or_default(),
+        };
+        if self.cached {
+            row.time += " (cached)";
+        }
+        match &self.status {
+            Status::Error(e) => row.part1 = format!("error: {e}"),
+            Status::Panicked(e) => row.part1 = format!("panic: {e}"),
+            status => row.part1 = status.message().unwrap_or_default(),
+        }
+        if self.status.message().is_some() {
+            return row;
+        }
+        for (part, answer) in &self.answers {
+            let cell = match self.expected_answer(*part) {
+                Some(e) if e != answer => format!("{answer} (expected {e})"),
+                _ => answer.clone(),
+            };
+            match part {
+                Part::One => row.part1 = cell,
+                Part::Two => row.part2 = cell,
+            }
+        }
+        row
+    }
+
+    // json returns the JSON object describing the outcome. It is the object printed by the
+    // day's run command, or the day and the error if it failed, along with the status.
+    fn json(&self) -> json::Value {
+        let mut fields = match &self.report {
+            Some(json::Value::Object(fields)) => fields.clone(),
+            _ => vec![
+                ("day".to_string(), json::Value::Int(self.day.day.into())),
+                ("title".to_string(), json::Value::from(self.day.title)),
+            ],
+        };
+        if let Some(msg) = self.status.message() {
+            fields.push(("error".to_string(), json::Value::from(msg)));
+        }
+        fields.push(("status".to_string(), json::Value::from(self.status.name())));
+        json::Value::Object(fields)
+    }
+}
+
+// read_expected reads the expected answers from the solution.txt file next to the input, one
+// line per part. No answers are expected if the file doesn't exist.
+fn read_expected(path: &path::Path) -> Vec<String> {
+    fs::read_to_string(path)
+        .map(|s| s.lines().map(|l| l.trim_end().to_string()).collect())
+        .unwrap_or_default()
+}
+
+// answers returns the answer to each part in a report printed by the run command.
+fn answers(report: &json::Value) -> Result<Vec<(Part, String)>, String> {
+    let parts = report
+        .get("parts")
+        .and_then(|p| p.as_array())
+        .ok_or("report has no parts")?;
+    parts
+        .iter()
+        .map(|p| {
+            let part = match p.get("part").and_then(|n| n.as_int()) {
+                Some(1) => Part::One,
+                Some(2) => Part::Two,
+                _ => return Err("report has an invalid part".to_string()),
+            };
+            let answer = match p.get("answer") {
+                Some(json::Value::Int(n)) => n.to_string(),
+                Some(json::Value::String(s)) => s.clone(),
+                _ => return Err("report has an invalid answer".to_string()),
+            };
+            Ok((part, answer))
+        })
+        .collect()
+}
+
+// run_day runs the day's solution on its input in dir in a child process running exe, the
+// advent2024 binary, under the limits. The answers are compared with the expected answers in
+// solution.txt next to the input if it exists.
+fn run_day(
+    exe: &path::Path,
+    day: &'static Day,
+    dir: &path::Path,
+    opts: &Options,
+    limits: pool::Limits,
+) -> Outcome {
+    let day_dir = dir.join(format!("day{}", day.day));
+    let path = day_dir.join("input.txt");
+    if let Err(e) = fs::metadata(&path) {
+        let err = Status::Error(format!("{}: {e}", path.display()));
+        return Outcome::failed(day, err, None);
+    }
+
+    let mut args = vec![
+        "run".to_string(),
+        "--day".to_string(),
+        day.day.to_string(),
+        "--input".to_string(),
+        path.display().to_string(),
+        "--format".to_string(),
+        "json".to_string(),
+    ];
+    if let Some(p) = opts.part {
+        args.extend(["--part".to_string(), p.to_string()]);
+    }
+    match cache_dir(opts) {
+        Some(dir) => args.extend(["--cache-dir".to_string(), dir.display().to_string()]),
+        None => args.push("--no-cache".to_string()),
+    }
+    let exit = match pool::run_limited(&exe.to_string_lossy(), &args, limits) {
+        Ok(exit) => exit,
+        Err(e) => return Outcome::failed(day, Status::Error(e.to_string()), None),
+    };
+
+    let elapsed = Some(exit.elapsed);
+    let status = match exit.status {
+        None => return Outcome::failed(day, Status::TimedOut, elapsed),
+        Some(status) => status,
+    };
+    if exit.out_of_memory() {
+        return Outcome::failed(day, Status::OutOfMemory, elapsed);
+    }
+    if let Some(msg) = exit.panic_message() {
+        return Outcome::failed(day, Status::Panicked(msg), elapsed);
+    }
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&exit.stderr);
+        let msg = match stderr.lines().next() {
+            Some(first) => first.strip_prefix("error: ").unwrap_or(first).to_string(),
+            None => status.to_string(),
+        };
+        return Outcome::failed(day, Status::Error(msg), elapsed);
+    }
+
+    let parsed = json::parse(String::from_utf8_lossy(&exit.stdout).trim())
+        .and_then(|report| Ok((answers(&report)?, report)));
+    let (answers, report) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let err = Status::Error(format!("invalid report: {e}"));
+            return Outcome::failed(day, err, elapsed);
+        }
+    };
+
+    let mut outcome = Outcome {
+        day,
+        status: Status::Unchecked,
+        report: None,
+        answers,
+        expected: read_expected(&day_dir.join("solution.txt")),
+        elapsed: solve_time(&report).or(elapsed),
+        cached: report.get("cached") == Some(&json::Value::Bool(true)),
+    };
+    outcome.report = Some(report);
+    let checked: Vec<_> = outcome
+        .answers
+        .iter()
+        .filter_map(|(p, a)| outcome.expected_answer(*p).map(|e| e == a))
+        .collect();
+    if checked.contains(&false) {
+        outcome.status = Status::Wrong;
+    } else if !checked.is_empty() {
+        outcome.status = Status::Ok;
+    }
+    outcome
+}
+
+// solve_time returns the time taken to parse the input and solve all parts in a report printed
+// by the run command.
+fn solve_time(report: &json::Value) -> Option<time::Duration> {
+    let mut ns = report.get("parse_ns")?.as_int()?;
+    for part in report.get("parts")?.as_array()? {
+        ns += part.get("solve_ns")?.as_int()?;
+    }
+    Some(time::Duration::from_nanos(ns.try_into().ok()?))
+}
+
+fn run_all(opts: &Options) -> Result<process::ExitCode, Box<dyn error::Error>> {
+    let dir = opts
+        .input_dir
+        .clone()
+        .unwrap_or_else(|| path::PathBuf::from("."));
+    let exe = env::current_exe()?;
+    let limits = pool::Limits {
+        timeout: opts.timeout,
+        memory: opts.memory_limit.map(|mb| mb << 20),
+    };
+    let jobs = opts
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+
+    // Each day runs in its own process, so a panic is reported by the process. A panic caught
+    // here is a bug in running the process.
+    let outcomes: Vec<_> = pool::map(days::DAYS.iter().collect(), jobs, |day| {
+        run_day(&exe, day, &dir, opts, limits)
+    })
+    .into_iter()
+    .zip(days::DAYS.iter())
+    .map(|(outcome, day)| {
+        outcome.unwrap_or_else(|e| Outcome::failed(day, Status::Panicked(e), None))
+    })
+    .collect();
+
+    match opts.format {
+        Format::Text => {
+            let mut rows = vec![Row {
+                day: "Day".to_string(),
+                title: "Title".to_string(),
+                part1: "Part 1".to_string(),
+                part2: "Part 2".to_string(),
+                status: "Status".to_string(),
+                time: "Time".to_string(),
+            }];
+            rows.extend(outcomes.iter().map(Outcome::row));
+            print_table(&rows);
+        }
+        Format::Json => {
+            let days = outcomes.iter().map(Outcome::json).collect();
+            println!("{}", json::Value::Array(days));
+        }
+    }
+
+    if outcomes.iter().any(|o| o.status.failed()) {
+        return Ok(process::ExitCode::FAILURE);
+    }
+    Ok(process::ExitCode::SUCCESS)
+}
+
+fn print_table(rows: &[Row]) {
+    let width = |f: fn(&Row) -> &String| rows.iter().map(|r| f(r).len()).max().unwrap_or(0);
+    let day_w = width(|r| &r.day);
+    let title_w = width(|r| &r.title);
+    let part1_w = width(|r| &r.part1);
+    let part2_w = width(|r| &r.part2);
+    let status_w = width(|r| &r.status);
+
+    for r in rows {
+        let line = format!(
+            "{:<day_w$}  {:<title_w$}  {:<part1_w$}  {:<part2_w$}  {:<status_w$}  {}",
+            r.day, r.title, r.part1, r.part2, r.status, r.time,
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, UsageError> {
+        parse_options(&mut Parser::new(args.iter().map(|a| a.to_string())))
+    }
+
+    #[test]
+    fn test_parse_options() -> Result<(), UsageError> {
+        let opts = parse(&["--day", "11", "--part", "1", "--input", "input.txt"])?;
+        assert_eq!(
+            opts,
+            Options {
+                day: Some(11),
+                part: Some(Part::One),
+                input: Some("input.txt".into()),
+                ..Default::default()
+            }
+        );
+
+        let opts = parse(&["--all", "--format", "json"])?;
+        assert_eq!(opts.format, Format::Json);
+
+        let opts = parse(&[
+            "--all",
+            "--jobs=4",
+            "--timeout",
+            "2.5",
+            "--memory-limit",
+            "512",
+        ])?;
+        assert_eq!(opts.jobs, Some(4));
+        assert_eq!(opts.timeout, Some(time::Duration::from_millis(2500)));
+        assert_eq!(opts.memory_limit, Some(512));
+
+        let opts = parse(&["--day", "16", "-vv", "-v", "--log=day16=trace"])?;
+        assert_eq!(opts.verbosity, 3);
+        assert_eq!(opts.log, vec!["day16=trace".to_string()]);
+
+        let opts = parse(&["--all", "--cache-dir", "/tmp/cache"])?;
+        assert_eq!(opts.cache_dir, Some("/tmp/cache".into()));
+        assert!(parse(&["--day", "1", "--no-cache"])?.no_cache);
+
+        let opts = parse(&["--day", "14", "--param", "width=11", "--param=height = 7"])?;
+        assert_eq!(opts.params.to_string(), "height = 7\nwidth = 11\n");
+        let opts = parse(&["--day", "2", "--params", "policy.txt"])?;
+        assert_eq!(opts.params_file, Some("policy.txt".into()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_options_invalid() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--day", "1", "--all"]).is_err());
+        assert!(parse(&["--all", "--input", "input.txt"]).is_err());
+        assert!(parse(&["--day", "1", "--part", "3"]).is_err());
+        assert!(parse(&["--day", "1", "--input-dir", "."]).is_err());
+        assert!(parse(&["--day", "1", "--format", "yaml"]).is_err());
+        assert!(parse(&["--day", "14", "--param", "width"]).is_err());
+        assert!(parse(&["--all", "--param", "width=11"]).is_err());
+        assert!(parse(&["--all", "--params", "policy.txt"]).is_err());
+        assert!(parse(&["--day", "1", "--log", "day1=loud"]).is_err());
+        assert!(parse(&["--day", "1", "--timeout", "10"]).is_err());
+        assert!(parse(&["--all", "--timeout", "-1"]).is_err());
+        assert!(parse(&["--all", "--jobs", "0"]).is_err());
+        assert!(parse(&["--day", "1", "--no-cache", "--cache-dir", "."]).is_err());
+    }
+}
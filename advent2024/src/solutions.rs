@@ -0,0 +1,257 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path;
+use std::time;
+
+use aoc_common::{Part, Runner};
+
+use crate::days::{self, Day};
+
+/// DEFAULT_SOLUTIONS is the default path to the solutions repository. It can be overridden
+/// with the SOLUTIONS_REPO environment variable.
+pub const DEFAULT_SOLUTIONS: &str = "../advent-of-code-solutions";
+
+/// solutions_dir returns the path to the solutions repository given by the SOLUTIONS_REPO
+/// environment variable, or DEFAULT_SOLUTIONS if it is not set.
+pub fn solutions_dir() -> path::PathBuf {
+    std::env::var_os("SOLUTIONS_REPO")
+        .map(path::PathBuf::from)
+        .unwrap_or_else(|| path::PathBuf::from(DEFAULT_SOLUTIONS))
+}
+
+/// PartCheck is the result of checking the answer to a single part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartCheck {
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+    pub elapsed: time::Duration,
+}
+
+impl PartCheck {
+    /// passed returns true if the answer matches the expected answer.
+    pub fn passed(&self) -> bool {
+        self.actual == self.expected
+    }
+}
+
+/// Outcome is the result of checking a day.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// Skipped is returned when the day's input or solution file is missing.
+    Skipped(String),
+
+    /// Error is returned when the solution returned an error.
+    Error(String),
+
+    /// Checked holds the result of checking each part with an expected answer.
+    Checked {
+        parse_time: time::Duration,
+        parts: Vec<PartCheck>,
+    },
+}
+
+/// DayCheck is the result of checking a day's solution against the solutions repository.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayCheck {
+    pub day: u8,
+    pub outcome: Outcome,
+}
+
+impl DayCheck {
+    /// failed returns true if the solution returned an error or any part's answer was wrong.
+    pub fn failed(&self) -> bool {
+        match &self.outcome {
+            Outcome::Skipped(_) => false,
+            Outcome::Error(_) => true,
+            Outcome::Checked { parts, .. } => parts.iter().any(|p| !p.passed()),
+        }
+    }
+}
+
+impl fmt::Display for DayCheck {
+    // fmt writes a line per part with its status and time. The expected and actual answers are
+    // written below parts that failed.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = format!("day{}", self.day);
+        match &self.outcome {
+            Outcome::Skipped(reason) => writeln!(f, "{name:<6} skipped: {reason}"),
+            Outcome::Error(e) => writeln!(f, "{name:<6} FAIL    error: {e}"),
+            Outcome::Checked { parse_time, parts } => {
+                writeln!(f, "{name:<6} parse   {parse_time:.1?}")?;
+                for p in parts {
+                    let status = if p.passed() { "ok" } else { "FAIL" };
+                    writeln!(
+                        f,
+                        "{name:<6} part {}  {status:<4}  {:.1?}",
+                        p.part, p.elapsed
+                    )?;
+                    if !p.passed() {
+                        writeln!(f, "    - {}", p.expected)?;
+                        writeln!(f, "    + {}", p.actual)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// read_optional reads the file at path. None is returned if it does not exist.
+fn read_optional(path: &path::Path) -> Result<Option<Vec<u8>>, String> {
+    match fs::read(path) {
+        Ok(b) => Ok(Some(b)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+/// check_day runs the day's solution on 2024/dayN/input.txt in the solutions repository at
+/// dir and compares the answers with the lines of 2024/dayN/solution.txt. Only parts with an
+/// expected answer are checked.
+pub fn check_day(day: &Day, dir: &path::Path) -> DayCheck {
+    let day_dir = dir.join("2024").join(format!("day{}", day.day));
+    let outcome = match (
+        read_optional(&day_dir.join("input.txt")),
+        read_optional(&day_dir.join("solution.txt")),
+    ) {
+        (Err(e), _) | (_, Err(e)) => Outcome::Error(e),
+        (Ok(None), _) => Outcome::Skipped("no input.txt".to_string()),
+        (_, Ok(None)) => Outcome::Skipped("no solution.txt".to_string()),
+        (Ok(Some(input)), Ok(Some(solution))) => check(day.solution().as_ref(), &input, &solution),
+    };
+
+    DayCheck {
+        day: day.day,
+        outcome,
+    }
+}
+
+/// check runs the solution on the input and compares the answers with the lines of expected.
+/// Only parts with a non-empty line in expected are run.
+pub fn check(solution: &dyn Runner, input: &[u8], expected: &[u8]) -> Outcome {
+    let expected: Vec<_> = String::from_utf8_lossy(expected)
+        .lines()
+        .map(|l| l.trim_end().to_string())
+        .collect();
+    let parts: Vec<_> = Part::BOTH
+        .into_iter()
+        .zip(&expected)
+        .filter(|(_, e)| !e.is_empty())
+        .map(|(p, _)| p)
+        .collect();
+
+    let report = match solution.run(&mut io::Cursor::new(input), &parts) {
+        Ok(report) => report,
+        Err(e) => return Outcome::Error(e.to_string()),
+    };
+
+    Outcome::Checked {
+        parse_time: report.parse_time,
+        parts: report
+            .answers
+            .into_iter()
+            .zip(parts.iter().map(|p| &expected[*p as usize]))
+            .map(|(a, e)| PartCheck {
+                part: a.part,
+                expected: e.clone(),
+                actual: a.value,
+                elapsed: a.elapsed,
+            })
+            .collect(),
+    }
+}
+
+/// check_all checks every day against the solutions repository at dir.
+pub fn check_all(dir: &path::Path) -> Vec<DayCheck> {
+    days::DAYS.iter().map(|day| check_day(day, dir)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // solutions creates a solutions repository in a temporary directory with the given files.
+    fn solutions(name: &str, files: &[(&str, &str)]) -> path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("advent2024-check-{name}-{}", std::process::id()));
+        for (file, content) in files {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_check_day() {
+        let dir = solutions(
+            "day",
+            &[
+                ("2024/day1/input.txt", "3   4\n4   3\n2   5\n"),
+                ("2024/day1/solution.txt", "3\n8\n"),
+                ("2024/day2/input.txt", "1 2 3\n"),
+            ],
+        );
+        let day1 = check_day(days::get(1).unwrap(), &dir);
+        let day2 = check_day(days::get(2).unwrap(), &dir);
+        let day3 = check_day(days::get(3).unwrap(), &dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(day1.failed());
+        match &day1.outcome {
+            Outcome::Checked { parts, .. } => {
+                assert_eq!(parts.len(), 2);
+                assert!(parts[0].passed());
+                assert_eq!(
+                    (parts[1].expected.as_str(), parts[1].actual.as_str()),
+                    ("8", "7")
+                );
+            }
+            outcome => panic!("unexpected outcome: {outcome:?}"),
+        }
+        assert!(day1.to_string().contains("    - 8\n    + 7\n"));
+
+        assert_eq!(
+            day2.outcome,
+            Outcome::Skipped("no solution.txt".to_string())
+        );
+        assert_eq!(day3.outcome, Outcome::Skipped("no input.txt".to_string()));
+        assert!(!day3.failed());
+    }
+
+    #[test]
+    fn test_check_day_one_part() {
+        // Only the first part is checked when the solution has one line.
+        let dir = solutions(
+            "part",
+            &[
+                ("2024/day1/input.txt", "3   4\n4   3\n2   5\n"),
+                ("2024/day1/solution.txt", "3\n"),
+            ],
+        );
+        let day1 = check_day(days::get(1).unwrap(), &dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!day1.failed());
+        match &day1.outcome {
+            Outcome::Checked { parts, .. } => assert_eq!(parts.len(), 1),
+            outcome => panic!("unexpected outcome: {outcome:?}"),
+        }
+    }
+}
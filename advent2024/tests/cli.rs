@@ -0,0 +1,263 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests that run the advent2024 binary and check that answers and errors are written to the
+// right streams with the right exit codes.
+
+use std::io::Write;
+use std::process;
+
+// run runs the advent2024 binary with the given arguments and input on stdin.
+fn run(args: &[&str], input: &str) -> process::Output {
+    run_env(args, &[], input)
+}
+
+// run_env runs the advent2024 binary like run with the given environment variables set.
+// Answers are cached in a temporary directory rather than the user's cache.
+fn run_env(args: &[&str], vars: &[(&str, &str)], input: &str) -> process::Output {
+    let cache = std::env::temp_dir().join(format!("advent2024-cache-{}", process::id()));
+    let mut child = process::Command::new(env!("CARGO_BIN_EXE_advent2024"))
+        .args(args)
+        .env("AOC_CACHE_DIR", cache)
+        .envs(vars.iter().copied())
+        .stdin(process::Stdio::piped())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped())
+        .spawn()
+        .expect("failed to run advent2024");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_answers() {
+    let out = run(&["run", "--day", "1"], "3   4\n4   3\n2   5\n");
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "3\n7\n");
+    assert_eq!(String::from_utf8_lossy(&out.stderr), "");
+}
+
+#[test]
+fn test_exit_codes() {
+    // Usage error.
+    let out = run(&["run", "--day", "99"], "");
+    assert_eq!(out.status.code(), Some(2));
+
+    // I/O error.
+    let out = run(&["run", "--day", "1", "--input", "does-not-exist.txt"], "");
+    assert_eq!(out.status.code(), Some(3));
+
+    // Parse error.
+    let out = run(&["run", "--day", "1"], "3   x\n");
+    assert_eq!(out.status.code(), Some(4));
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "");
+    assert!(String::from_utf8_lossy(&out.stderr).contains("1:5"));
+
+    // No solution. The end of the maze is walled off.
+    let out = run(&["run", "--day", "16"], "#####\n#S#E#\n#####\n");
+    assert_eq!(out.status.code(), Some(5));
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "");
+    assert!(String::from_utf8_lossy(&out.stderr).starts_with("error: no solution found"));
+}
+
+#[test]
+fn test_json() {
+    let out = run(
+        &["run", "--day", "23", "--format", "json"],
+        "kh-tc\nqp-kh\ntc-qp\n",
+    );
+    assert_eq!(out.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with(r#"{"day":23,"title":"LAN Party","input_sha256":"#));
+    assert!(stdout.contains(r#"{"part":1,"answer":1,"type":"usize","solve_ns":"#));
+    assert!(stdout.contains(r#"{"part":2,"answer":"kh,qp,tc","type":"String","solve_ns":"#));
+}
+
+#[test]
+fn test_params() {
+    let args = ["run", "--day", "11", "--part", "1"];
+    let out = run(&[&args[..], &["--param", "blinks=6"]].concat(), "125 17\n");
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "22\n");
+
+    let vars = [("AOC_DAY11_BLINKS", "6")];
+    let out = run_env(&args, &vars, "125 17\n");
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "22\n");
+
+    // --param takes precedence over the environment.
+    let out = run_env(
+        &[&args[..], &["--param", "blinks=1"]].concat(),
+        &vars,
+        "125 17\n",
+    );
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "3\n");
+
+    // Unknown parameters and invalid values are usage errors.
+    let out = run(&["run", "--day", "1", "--param", "blinks=6"], "");
+    assert_eq!(out.status.code(), Some(2));
+    let out = run(&[&args[..], &["--param", "blinks=x"]].concat(), "");
+    assert_eq!(out.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("invalid value for parameter blinks"));
+}
+
+#[test]
+fn test_generate() {
+    let dir = std::env::temp_dir().join(format!("advent2024-generate-{}", process::id()));
+    let out = run(
+        &[
+            "generate",
+            "--day",
+            "1",
+            "--seed",
+            "7",
+            "--size",
+            "20",
+            "--output-dir",
+            dir.to_str().unwrap(),
+            "--name",
+            "day1",
+        ],
+        "",
+    );
+    assert_eq!(out.status.code(), Some(0));
+
+    let input = std::fs::read_to_string(dir.join("day1.in.txt")).unwrap();
+    let expected = std::fs::read_to_string(dir.join("day1.out.txt")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(input.lines().count(), 20);
+
+    let out = run(&["run", "--day", "1"], &input);
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&out.stdout), expected);
+
+    // The same seed gives the same input on stdout.
+    let out = run(
+        &["generate", "--day", "1", "--seed", "7", "--size", "20"],
+        "",
+    );
+    assert_eq!(String::from_utf8_lossy(&out.stdout), input);
+}
+
+#[test]
+fn test_diff() {
+    let out = run(&["diff", "--day", "8", "--seeds", "3"], "");
+    assert_eq!(out.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "0 mismatches\n");
+}
+
+#[test]
+fn test_run_all() {
+    let dir = std::env::temp_dir().join(format!("advent2024-all-{}", process::id()));
+    let files = [
+        ("day1/input.txt", "3   4\n4   3\n2   5\n"),
+        ("day1/solution.txt", "3\n7\n"),
+        ("day2/input.txt", "1 2 3\n"),
+        ("day2/solution.txt", "0\n"),
+        ("day3/input.txt", "mul(2,3)\n"),
+    ];
+    for (file, content) in files {
+        let path = dir.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    let out = run(
+        &[
+            "run",
+            "--all",
+            "--input-dir",
+            dir.to_str().unwrap(),
+            "--jobs",
+            "4",
+            "--timeout",
+            "60",
+        ],
+        "",
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // Days without an input fail.
+    assert_eq!(out.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let status = |day: &str| {
+        let line = stdout
+            .lines()
+            .find(|l| l.split_whitespace().next() == Some(day))
+            .unwrap_or_else(|| panic!("no row for day {day}: {stdout}"));
+        line.split("  ")
+            .filter(|c| !c.is_empty())
+            .map(|c| c.trim().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(status("1")[2..5], ["3", "7", "ok"]);
+    assert_eq!(status("2")[2..5], ["1 (expected 0)", "1", "wrong"]);
+    assert_eq!(status("3")[2..5], ["6", "6", "unchecked"]);
+    assert!(status("4")[2].starts_with("error: "));
+    assert_eq!(status("4")[3], "error");
+}
+
+#[test]
+fn test_cache() {
+    let dir = std::env::temp_dir().join(format!("advent2024-test-cache-{}", process::id()));
+    let cache_dir = dir.to_str().unwrap();
+    let input = "3   4\n4   3\n2   5\n";
+    let args = [
+        "run",
+        "--day",
+        "1",
+        "--format",
+        "json",
+        "--cache-dir",
+        cache_dir,
+    ];
+    let cached = |out: &process::Output| {
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert_eq!(out.status.code(), Some(0), "{stdout}");
+        stdout.contains(r#""cached":true"#)
+    };
+
+    assert!(!cached(&run(&args, input)));
+    let out = run(&args, input);
+    assert!(cached(&out));
+    assert!(String::from_utf8_lossy(&out.stdout)
+        .contains(r#"{"part":1,"answer":3,"type":"i64","solve_ns":"#));
+    let out = run(
+        &["run", "--day", "1", "--format", "json", "--no-cache"],
+        input,
+    );
+    assert!(!cached(&out));
+
+    // A different input is solved.
+    let out = run(&args, "3   4\n");
+    assert!(!cached(&out));
+
+    let out = run(&["cache", "prune", "--cache-dir", cache_dir], "");
+    assert_eq!(out.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&out.stdout).starts_with("removed 0 entries"));
+    let out = run(
+        &["cache", "clear", "--day", "1", "--cache-dir", cache_dir],
+        "",
+    );
+    assert_eq!(out.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&out.stdout).starts_with("removed 2 entries"));
+    assert!(!cached(&run(&args, input)));
+
+    let out = run(&["cache", "prune", "--day", "1"], "");
+    assert_eq!(out.status.code(), Some(2));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
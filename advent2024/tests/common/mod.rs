@@ -0,0 +1,56 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Helpers shared by the tests that run the example inputs of every day.
+
+use std::fs;
+use std::io;
+use std::path;
+
+use advent2024::days::Day;
+use aoc_common::Params;
+
+// examples returns the paths to the day's example inputs, dayN/NAME.in.txt, in sorted order.
+pub fn examples(day: &Day) -> Vec<path::PathBuf> {
+    let dir = path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day.day));
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("{}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|p| p.to_string_lossy().ends_with(".in.txt"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+// sidecar returns the path to the file next to the example input with the given suffix in place
+// of ".in.txt".
+pub fn sidecar(input: &path::Path, suffix: &str) -> path::PathBuf {
+    let name = input.file_name().unwrap().to_string_lossy();
+    input.with_file_name(format!("{}{suffix}", name.trim_end_matches(".in.txt")))
+}
+
+// params returns the parameters for the example input read from its NAME.params.txt sidecar, or
+// no parameters if it has none.
+pub fn params(input: &path::Path) -> Result<Params, String> {
+    let path = sidecar(input, ".params.txt");
+    match fs::File::open(&path) {
+        Ok(f) => {
+            Params::read(io::BufReader::new(f)).map_err(|e| format!("{}: {e}", path.display()))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Params::new()),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests that compare every day's solution with its reference solution on randomly generated
+// inputs.
+
+use std::thread;
+
+use advent2024::days::{self, Day};
+use advent2024::differential;
+
+// SEEDS is the number of inputs generated for each day. More seeds can be checked with the diff
+// command.
+const SEEDS: u64 = 3;
+
+// check_day compares the day's solution with its reference on SEEDS generated inputs and returns
+// a description of each failure.
+fn check_day(day: &Day) -> Vec<String> {
+    let mut failed = Vec::new();
+    for seed in 0..SEEDS {
+        match differential::check_seed(day, seed, differential::test_size(day)) {
+            Ok(None) => {}
+            Ok(Some(failure)) => failed.push(failure.to_string()),
+            Err(e) => failed.push(format!("day {} seed {seed}: {e}", day.day)),
+        }
+    }
+    failed
+}
+
+#[test]
+fn test_differential() {
+    // Days are checked concurrently since some reference solutions take a few seconds per input
+    // in debug builds.
+    let failed: Vec<String> = thread::scope(|s| {
+        let handles: Vec<_> = days::DAYS
+            .iter()
+            .map(|day| s.spawn(move || check_day(day)))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("differential check panicked"))
+            .collect()
+    });
+    assert!(failed.is_empty(), "{}", failed.join("\n"));
+}
@@ -0,0 +1,63 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Golden tests that run every day's solution on each of its NAME.in.txt example inputs and
+// compare the answers with NAME.out.txt. Days with parameters read the parameters for the
+// example from NAME.params.txt.
+
+mod common;
+
+use std::fs;
+use std::path;
+
+use advent2024::days::{self, Day};
+use advent2024::solutions::{self, Outcome};
+
+// check_example runs the day's solution on the example input and returns a description of the
+// failure if the answers do not match.
+fn check_example(day: &Day, input_path: &path::Path) -> Result<(), String> {
+    let read = |p: &path::Path| fs::read(p).map_err(|e| format!("{}: {e}", p.display()));
+
+    let input = read(input_path)?;
+    let expected = read(&common::sidecar(input_path, ".out.txt"))?;
+    let params = common::params(input_path)?;
+    let solution = day.with_params(&params).map_err(|e| e.to_string())?;
+
+    let check = solutions::DayCheck {
+        day: day.day,
+        outcome: solutions::check(solution.as_ref(), &input, &expected),
+    };
+    match &check.outcome {
+        Outcome::Checked { parts, .. } if parts.is_empty() => Err("no expected answers".into()),
+        _ if check.failed() => Err(check.to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn test_examples() {
+    let mut failed = Vec::new();
+    for day in days::DAYS {
+        let inputs = common::examples(day);
+        if inputs.is_empty() {
+            failed.push(format!("day{}: no example inputs", day.day));
+        }
+        for input in inputs {
+            if let Err(e) = check_example(day, &input) {
+                failed.push(format!("{}:\n{e}", input.display()));
+            }
+        }
+    }
+    assert!(failed.is_empty(), "{}", failed.join("\n"));
+}
@@ -0,0 +1,89 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests that run every day's solution on randomly generated inputs and compare the answers with
+// those known from the way the inputs were constructed.
+
+use advent2024::days::{self, Day};
+use advent2024::differential;
+use advent2024::solutions::{self, DayCheck};
+use aoc_common::Rng;
+
+// SEEDS is the number of inputs generated for each day.
+const SEEDS: u64 = 5;
+
+// check_generated generates an input for the day and checks that the solution parses it and
+// finds the known answers.
+fn check_generated(day: &Day, seed: u64, size: usize) -> Result<(), String> {
+    let generated = (day.generate)(&mut Rng::new(seed), size);
+    let solution = day
+        .with_params(&generated.params)
+        .map_err(|e| e.to_string())?;
+    let check = DayCheck {
+        day: day.day,
+        outcome: solutions::check(
+            solution.as_ref(),
+            generated.input.as_bytes(),
+            generated.expected().as_bytes(),
+        ),
+    };
+    if check.failed() {
+        return Err(format!("seed {seed}, size {size}:\n{check}"));
+    }
+    Ok(())
+}
+
+// check_all checks SEEDS generated inputs for every day with the size returned by f.
+fn check_all(f: impl Fn(&Day) -> usize) {
+    let mut failed = Vec::new();
+    for day in days::DAYS {
+        for seed in 0..SEEDS {
+            if let Err(e) = check_generated(day, seed, f(day)) {
+                failed.push(e);
+            }
+        }
+    }
+    assert!(failed.is_empty(), "{}", failed.join("\n"));
+}
+
+#[test]
+fn test_generated() {
+    check_all(differential::test_size);
+}
+
+// test_generated_full_size checks inputs as large as the real puzzle inputs. It is slow unless
+// built in release mode:
+//
+//   cargo test --release --test generated -- --ignored
+#[test]
+#[ignore]
+fn test_generated_full_size() {
+    check_all(|day| day.default_size);
+}
+
+#[test]
+fn test_seeds() {
+    for day in days::DAYS {
+        let size = differential::test_size(day);
+        let a = (day.generate)(&mut Rng::new(1), size);
+        let b = (day.generate)(&mut Rng::new(1), size);
+        let c = (day.generate)(&mut Rng::new(2), size);
+        assert_eq!(a, b, "day{}: same seed generated different inputs", day.day);
+        assert_ne!(
+            a, c,
+            "day{}: different seeds generated the same input",
+            day.day
+        );
+    }
+}
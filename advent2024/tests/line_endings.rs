@@ -0,0 +1,76 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests that every day gives the same answers for its example inputs when they are saved with
+// CRLF line endings, a UTF-8 byte order mark, trailing whitespace or extra blank lines.
+
+mod common;
+
+use std::fs;
+use std::path;
+
+use advent2024::days::{self, Day};
+use advent2024::solutions;
+
+// Save returns the input saved in a different way.
+type Save = fn(&str) -> String;
+
+// VARIANTS holds the name of each way of saving an input and the function that saves it.
+const VARIANTS: &[(&str, Save)] = &[
+    ("crlf", |s| s.replace('\n', "\r\n")),
+    ("bom", |s| format!("\u{feff}{s}")),
+    ("trailing whitespace", |s| s.replace('\n', " \t\n")),
+    ("trailing blank lines", |s| format!("{s}\n\n  \n")),
+    ("no final newline", |s| s.trim_end_matches('\n').to_string()),
+    ("all", |s| {
+        format!("\u{feff}{}\r\n \r\n", s.replace('\n', "  \r\n"))
+    }),
+];
+
+// check_variants runs the day's solution on each variant of the example input and returns a
+// description of each variant whose answers don't match the expected ones.
+fn check_variants(day: &Day, input_path: &path::Path) -> Vec<String> {
+    let input = fs::read_to_string(input_path).unwrap();
+    let expected = fs::read_to_string(common::sidecar(input_path, ".out.txt")).unwrap();
+    let params = common::params(input_path).unwrap();
+    let solution = day.with_params(&params).unwrap();
+
+    VARIANTS
+        .iter()
+        .filter_map(|(variant, f)| {
+            let check = solutions::DayCheck {
+                day: day.day,
+                outcome: solutions::check(
+                    solution.as_ref(),
+                    f(&input).as_bytes(),
+                    expected.as_bytes(),
+                ),
+            };
+            check
+                .failed()
+                .then(|| format!("{} ({variant}):\n{check}", input_path.display()))
+        })
+        .collect()
+}
+
+#[test]
+fn test_variants() {
+    let failed: Vec<_> = days::DAYS
+        .iter()
+        .flat_map(|day| common::examples(day).into_iter().map(move |p| (day, p)))
+        .filter(|(_, p)| common::sidecar(p, ".out.txt").exists())
+        .flat_map(|(day, p)| check_variants(day, &p))
+        .collect();
+    assert!(failed.is_empty(), "{}", failed.join("\n"));
+}
@@ -0,0 +1,225 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests that feed truncated, empty and garbage inputs to every day and check that the solutions
+// return errors rather than panicking, and that malformed params files exit with the code of
+// their failure.
+
+use std::cell;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path;
+use std::process;
+use std::sync;
+
+use advent2024::days::{self, Day};
+use aoc_common::Part;
+
+// example returns the example input for the given day.
+fn example(day: &Day) -> Vec<u8> {
+    let dir = path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day.day));
+    fs::read(dir.join("example.in.txt"))
+        .unwrap_or_else(|e| panic!("day{}: no example input: {e}", day.day))
+}
+
+// Rand is a small xorshift random number generator so that garbage inputs are reproducible.
+struct Rand(u64);
+
+impl Rand {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+// truncated returns copies of the input cut at every line boundary and in the middle of every
+// line.
+fn truncated(input: &[u8]) -> Vec<Vec<u8>> {
+    let mut cases = Vec::new();
+    let mut start = 0;
+    for (i, b) in input.iter().enumerate() {
+        if *b == b'\n' {
+            cases.push(input[..(start + i) / 2].to_vec());
+            cases.push(input[..i].to_vec());
+            cases.push(input[..i + 1].to_vec());
+            start = i + 1;
+        }
+    }
+    cases
+}
+
+// garbage returns random inputs and copies of the input with a few bytes changed or a number
+// replaced with a very large one.
+fn garbage(input: &[u8], rand: &mut Rand) -> Vec<Vec<u8>> {
+    const ALPHABET: &[u8] = b"0123456789-+,:|=.#@^v<>ABEOSXYapv \n\t\r\xff";
+    const LARGE: &[u8] = b"4611686018427387903";
+
+    let mut cases = vec![
+        b"\n".to_vec(),
+        b"\n\n\n".to_vec(),
+        b"hello world\n".to_vec(),
+        b"\xff\xfe\x00\x01".to_vec(),
+        "\u{1F384}\u{1F385}\n".as_bytes().to_vec(),
+        b"99999999999999999999999999\n".to_vec(),
+        b"-1\n".to_vec(),
+    ];
+
+    for _ in 0..20 {
+        let len = rand.below(64);
+        cases.push(
+            (0..len)
+                .map(|_| ALPHABET[rand.below(ALPHABET.len())])
+                .collect(),
+        );
+    }
+
+    for _ in 0..100 {
+        let mut case = input.to_vec();
+        for _ in 0..=rand.below(3) {
+            if case.is_empty() {
+                break;
+            }
+            let i = rand.below(case.len());
+            match rand.below(4) {
+                0 => case[i] = ALPHABET[rand.below(ALPHABET.len())],
+                1 => {
+                    case.remove(i);
+                }
+                2 => case.insert(i, ALPHABET[rand.below(ALPHABET.len())]),
+                _ => {
+                    // Replace the number, if any, that starts at or after i.
+                    let start = match case[i..].iter().position(|b| b.is_ascii_digit()) {
+                        Some(j) => i + j,
+                        None => continue,
+                    };
+                    let len = case[start..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                    case.splice(start..start + len, LARGE.iter().copied());
+                }
+            }
+        }
+        cases.push(case);
+    }
+
+    cases
+}
+
+thread_local! {
+    // SILENT is set while running a case so that panic messages are recorded in LAST_PANIC rather
+    // than printed.
+    static SILENT: cell::Cell<bool> = const { cell::Cell::new(false) };
+    static LAST_PANIC: cell::RefCell<String> = const { cell::RefCell::new(String::new()) };
+}
+
+// check runs every case through the day's solution and returns a description of each case that
+// panicked.
+fn check(day: &Day, cases: &[Vec<u8>]) -> Vec<String> {
+    let mut panicked = Vec::new();
+    for case in cases {
+        SILENT.set(true);
+        let result = panic::catch_unwind(|| {
+            let mut r = io::Cursor::new(case);
+            // Errors are expected. Only panics are failures.
+            let _ = day.solution().run(&mut r, &Part::BOTH);
+        });
+        SILENT.set(false);
+        if result.is_err() {
+            panicked.push(format!(
+                "day{} panicked on input {:?}: {}",
+                day.day,
+                String::from_utf8_lossy(case),
+                LAST_PANIC.with_borrow(|p| p.clone()),
+            ));
+        }
+    }
+    panicked
+}
+
+// run_all runs the cases returned by f for every day and fails if any of them panicked.
+fn run_all(f: impl Fn(&[u8]) -> Vec<Vec<u8>>) {
+    // Silence the panic messages of cases since they are reported by the assertion below.
+    static HOOK: sync::Once = sync::Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if SILENT.get() {
+                LAST_PANIC.set(info.to_string());
+            } else {
+                default(info);
+            }
+        }));
+    });
+
+    let panicked: Vec<_> = days::DAYS
+        .iter()
+        .flat_map(|day| check(day, &f(&example(day))))
+        .collect();
+
+    assert!(panicked.is_empty(), "{}", panicked.join("\n"));
+}
+
+#[test]
+fn test_empty() {
+    run_all(|_| vec![Vec::new()]);
+}
+
+#[test]
+fn test_truncated() {
+    run_all(truncated);
+}
+
+#[test]
+fn test_garbage() {
+    run_all(|input| garbage(input, &mut Rand(0x2024_1201)));
+}
+
+// run_params runs day 2 with the advent2024 binary and the given params file and returns its exit
+// code.
+fn run_params(path: &path::Path) -> Option<i32> {
+    process::Command::new(env!("CARGO_BIN_EXE_advent2024"))
+        .args(["run", "--day", "2", "--no-cache", "--params"])
+        .arg(path)
+        .stdin(process::Stdio::null())
+        .stdout(process::Stdio::null())
+        .stderr(process::Stdio::null())
+        .status()
+        .expect("failed to run advent2024")
+        .code()
+}
+
+#[test]
+fn test_params_file() {
+    // A missing params file is an I/O error rather than a usage error.
+    assert_eq!(run_params(path::Path::new("/nonexistent")), Some(3));
+
+    let path = std::env::temp_dir().join(format!("advent2024-params-{}", process::id()));
+    fs::write(&path, "= 5\n").unwrap();
+    let malformed = run_params(&path);
+    fs::write(&path, "max_stp = 5\n").unwrap();
+    let unknown = run_params(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(malformed, Some(4));
+    assert_eq!(unknown, Some(2));
+}
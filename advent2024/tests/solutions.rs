@@ -0,0 +1,49 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Checks every day against the solutions repository given by the SOLUTIONS_REPO environment
+// variable. The test passes without checking anything if the repository is not found.
+
+use std::env;
+use std::path;
+
+use advent2024::solutions;
+
+#[test]
+fn test_solutions() {
+    // NOTE: Tests run in the package directory so the default path is relative to the workspace
+    //       root like it is for the Makefile.
+    let dir = env::var_os("SOLUTIONS_REPO")
+        .map(path::PathBuf::from)
+        .unwrap_or_else(|| {
+            path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(solutions::DEFAULT_SOLUTIONS)
+        });
+    if !dir.is_dir() {
+        eprintln!("skipping: solutions repository {} not found", dir.display());
+        return;
+    }
+
+    let checks = solutions::check_all(&dir);
+    let failed: Vec<_> = checks.iter().filter(|c| c.failed()).collect();
+    for check in &checks {
+        eprint!("{check}");
+    }
+    assert!(
+        failed.is_empty(),
+        "{}",
+        failed.iter().map(|c| c.to_string()).collect::<String>()
+    );
+}
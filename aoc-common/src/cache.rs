@@ -0,0 +1,583 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::error;
+use std::fs;
+use std::io::{self, BufReader, Read, Seek};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time;
+
+use crate::event;
+use crate::json;
+use crate::log::Level;
+use crate::params::{Param, Params};
+use crate::sha256::{self, Tee};
+use crate::solution::{Answer, Part, Report, Runner};
+
+/// ENV_VAR is the environment variable that sets the cache directory.
+pub const ENV_VAR: &str = "AOC_CACHE_DIR";
+
+/// Key identifies the answers of a version of a day's solution with the given parameters to an
+/// input.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Key {
+    pub day: u8,
+
+    /// version identifies the code of the day's solution. It changes whenever the code does.
+    pub version: String,
+
+    /// params holds the value of each of the day's parameters, including defaults, as
+    /// "name = value" lines so that setting a parameter to its default doesn't change the key.
+    pub params: String,
+
+    /// input_sha256 is the SHA-256 digest of the input as a hex string.
+    pub input_sha256: String,
+}
+
+impl Key {
+    /// new returns the key of the answers of the day's solution to the input whose SHA-256
+    /// digest is input_sha256. specs declares the day's parameters and params holds the values
+    /// that are set.
+    pub fn new(
+        day: u8,
+        version: &str,
+        specs: &[Param],
+        params: &Params,
+        input_sha256: &str,
+    ) -> Self {
+        let mut resolved = Params::new();
+        for spec in specs {
+            resolved.set(spec.name, spec.default);
+        }
+        resolved.merge(params);
+        Key {
+            day,
+            version: version.to_string(),
+            params: resolved.to_string(),
+            input_sha256: input_sha256.to_string(),
+        }
+    }
+
+    /// digest returns the SHA-256 digest of the key as a hex string. Entries are stored in files
+    /// named after it.
+    pub fn digest(&self) -> String {
+        sha256::hex_digest(
+            format!(
+                "{}\n{}\n{}\n{}",
+                self.day, self.version, self.input_sha256, self.params
+            )
+            .as_bytes(),
+        )
+    }
+}
+
+/// Cache stores the answers to puzzle inputs on disk so that they are not solved again. Each
+/// entry is a JSON file in a directory per day, DIR/dayN/DIGEST.json, holding the key, the
+/// answers and the times taken to find them.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// new returns a cache stored in dir. The directory is created when the first entry is
+    /// written.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// default_dir returns the directory given by AOC_CACHE_DIR, or the advent2024 directory in
+    /// $XDG_CACHE_HOME or ~/.cache. None is returned if none of the variables are set.
+    pub fn default_dir() -> Option<PathBuf> {
+        let var = |name| {
+            env::var_os(name)
+                .filter(|v| !v.is_empty())
+                .map(PathBuf::from)
+        };
+        if let Some(dir) = var(ENV_VAR) {
+            return Some(dir);
+        }
+        let base = var("XDG_CACHE_HOME").or_else(|| var("HOME").map(|h| h.join(".cache")))?;
+        Some(base.join("advent2024"))
+    }
+
+    /// dir returns the directory holding the cache.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    // day_dir returns the directory holding the entries for the day.
+    fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day}"))
+    }
+
+    // path returns the path of the entry for the key.
+    fn path(&self, key: &Key) -> PathBuf {
+        self.day_dir(key.day).join(format!("{}.json", key.digest()))
+    }
+
+    /// get returns a report holding the cached answers to the parts in order, or None if any of
+    /// them is not cached. Entries that can't be read are treated as missing.
+    pub fn get(&self, key: &Key, parts: &[Part]) -> Option<Report> {
+        let entry = read_entry(&self.path(key))?;
+        if entry.key != *key {
+            return None;
+        }
+        let answers = parts
+            .iter()
+            .map(|p| entry.answers.iter().find(|a| a.part == *p).cloned())
+            .collect::<Option<_>>()?;
+        Some(Report {
+            parse_time: entry.parse_time,
+            answers,
+            cached: true,
+        })
+    }
+
+    /// put stores the answers in the report. Answers to other parts that are already cached
+    /// for the key are kept.
+    pub fn put(&self, key: &Key, report: &Report) -> io::Result<()> {
+        let path = self.path(key);
+        let mut answers = report.answers.clone();
+        if let Some(old) = read_entry(&path).filter(|e| e.key == *key) {
+            answers.extend(
+                old.answers
+                    .into_iter()
+                    .filter(|a| report.answer(a.part).is_none()),
+            );
+        }
+        answers.sort_by_key(|a| a.part);
+        let entry = Entry {
+            key: key.clone(),
+            parse_time: report.parse_time,
+            answers,
+        };
+
+        // Write to a temporary file first so that readers never see a partial entry.
+        fs::create_dir_all(self.day_dir(key.day))?;
+        let tmp = path.with_extension(format!("tmp.{}", process::id()));
+        fs::write(&tmp, format!("{}\n", entry.json()))?;
+        fs::rename(&tmp, &path)
+    }
+
+    /// solve returns the cached answers to the parts if they are all cached. Otherwise the input
+    /// read from r is parsed, the parts are solved and the answers are cached. The input is
+    /// hashed before it is parsed, then r is rewound, so that cached answers are returned without
+    /// parsing the input. key returns the key of the answers given the SHA-256 digest of the
+    /// input. It returns the report and the digest as a hex string. Failing to write the cache
+    /// is logged but is not an error.
+    pub fn solve(
+        &self,
+        key: impl FnOnce(&str) -> Key,
+        solution: &dyn Runner,
+        mut r: impl Read + Seek,
+        parts: &[Part],
+    ) -> Result<(Report, String), Box<dyn error::Error>> {
+        let input_sha256 = Tee::new(&mut r).finish()?;
+        let key = key(&input_sha256);
+        if let Some(report) = self.get(&key, parts) {
+            event!(Level::Debug, "hit", day = key.day, digest = key.digest());
+            return Ok((report, input_sha256));
+        }
+        r.rewind()?;
+        let report = solution.run(&mut BufReader::new(r), parts)?;
+        if let Err(e) = self.put(&key, &report) {
+            event!(
+                Level::Warn,
+                "write",
+                path = self.path(&key).display(),
+                error = e
+            );
+        }
+        Ok((report, input_sha256))
+    }
+
+    // entries returns the paths of the entries of the given days.
+    fn entries(&self, days: impl Iterator<Item = u8>) -> io::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for day in days {
+            let dir = match fs::read_dir(self.day_dir(day)) {
+                Ok(dir) => dir,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            for entry in dir {
+                let path = entry?.path();
+                if path.extension().is_some_and(|e| e == "json") {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// clear removes the entries of the given day, or of every day if day is None, and returns
+    /// the number of entries removed. Only entry files are removed, so a directory that holds
+    /// other files is left alone.
+    pub fn clear(&self, day: Option<u8>) -> io::Result<usize> {
+        let days = match day {
+            Some(d) => d..=d,
+            None => 0..=u8::MAX,
+        };
+        let paths = self.entries(days.clone())?;
+        for path in &paths {
+            fs::remove_file(path)?;
+        }
+        for day in days {
+            // Empty directories are removed. Others hold files that aren't entries.
+            let _ = fs::remove_dir(self.day_dir(day));
+        }
+        Ok(paths.len())
+    }
+
+    /// prune removes the entries that are stale, those written by a version of a day's code
+    /// other than the one returned by current, and entries that can't be read. It returns the
+    /// number of entries removed. current returns None for days that no longer exist.
+    pub fn prune(&self, current: impl Fn(u8) -> Option<&'static str>) -> io::Result<usize> {
+        let mut removed = 0;
+        for path in self.entries(0..=u8::MAX)? {
+            let fresh = read_entry(&path).is_some_and(|e| {
+                current(e.key.day) == Some(e.key.version.as_str())
+                    && path.parent() == Some(self.day_dir(e.key.day).as_path())
+            });
+            if !fresh {
+                fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// solve_uncached parses the input read from r with the solution and solves the parts without
+/// a cache. The input is parsed as it is read and its SHA-256 digest is computed along the way,
+/// so it is read once and never held in memory whole. It returns the report and the digest as a
+/// hex string.
+pub fn solve_uncached(
+    solution: &dyn Runner,
+    r: impl Read,
+    parts: &[Part],
+) -> Result<(Report, String), Box<dyn error::Error>> {
+    let mut tee = Tee::new(r);
+    let report = solution.run(&mut BufReader::new(&mut tee), parts)?;
+    Ok((report, tee.finish()?))
+}
+
+// NEXT_SPOOL numbers the spool files written by the process so that their names are unique.
+static NEXT_SPOOL: AtomicUsize = AtomicUsize::new(0);
+
+/// Spool is a copy of an input that can only be read once, such as stdin, in a temporary file so
+/// that it can be hashed and then parsed by Cache::solve. The file is removed when the Spool is
+/// dropped.
+#[derive(Debug)]
+pub struct Spool {
+    file: fs::File,
+    path: PathBuf,
+}
+
+impl Spool {
+    /// new copies the input read from r into a new temporary file and returns the copy positioned
+    /// at its start.
+    pub fn new(mut r: impl Read) -> io::Result<Self> {
+        let n = NEXT_SPOOL.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("aoc-input-{}-{n}", process::id()));
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        // The spool is created before copying so that the file is removed if copying fails.
+        let mut spool = Spool { file, path };
+        io::copy(&mut r, &mut spool.file)?;
+        spool.file.rewind()?;
+        Ok(spool)
+    }
+}
+
+impl Read for Spool {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.file.read(buf)
+    }
+}
+
+impl Seek for Spool {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        self.file.seek(pos)
+    }
+}
+
+impl Drop for Spool {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// Entry is a cached entry along with its key.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    key: Key,
+    parse_time: time::Duration,
+    answers: Vec<Answer>,
+}
+
+impl Entry {
+    // json returns the entry as a JSON object. Answers are always stored as strings.
+    fn json(&self) -> json::Value {
+        let parts = self.answers.iter().map(|a| {
+            json::Value::object([
+                ("part", json::Value::Int(a.part as i128 + 1)),
+                ("answer", json::Value::from(a.value.as_str())),
+                ("type", json::Value::from(a.type_name.as_str())),
+                ("solve_ns", json::Value::Int(a.elapsed.as_nanos() as i128)),
+            ])
+        });
+        json::Value::object([
+            ("day", json::Value::Int(self.key.day.into())),
+            ("version", json::Value::from(self.key.version.as_str())),
+            ("params", json::Value::from(self.key.params.as_str())),
+            (
+                "input_sha256",
+                json::Value::from(self.key.input_sha256.as_str()),
+            ),
+            (
+                "parse_ns",
+                json::Value::Int(self.parse_time.as_nanos() as i128),
+            ),
+            ("parts", json::Value::Array(parts.collect())),
+        ])
+    }
+
+    // from_json returns the entry held by the JSON object, or None if it is not a valid entry.
+    fn from_json(v: &json::Value) -> Option<Entry> {
+        let string = |v: &json::Value, name| v.get(name)?.as_str().map(str::to_string);
+        let nanos = |v: &json::Value, name| {
+            let ns = v.get(name)?.as_int()?;
+            Some(time::Duration::from_nanos(ns.try_into().ok()?))
+        };
+        let answers = v
+            .get("parts")?
+            .as_array()?
+            .iter()
+            .map(|p| {
+                Some(Answer {
+                    part: match p.get("part")?.as_int()? {
+                        1 => Part::One,
+                        2 => Part::Two,
+                        _ => return None,
+                    },
+                    value: string(p, "answer")?,
+                    type_name: string(p, "type")?,
+                    elapsed: nanos(p, "solve_ns")?,
+                })
+            })
+            .collect::<Option<_>>()?;
+        Some(Entry {
+            key: Key {
+                day: v.get("day")?.as_int()?.try_into().ok()?,
+                version: string(v, "version")?,
+                params: string(v, "params")?,
+                input_sha256: string(v, "input_sha256")?,
+            },
+            parse_time: nanos(v, "parse_ns")?,
+            answers,
+        })
+    }
+}
+
+// read_entry reads the entry at path. None is returned if it doesn't exist or is invalid.
+fn read_entry(path: &Path) -> Option<Entry> {
+    let text = fs::read_to_string(path).ok()?;
+    let entry = json::parse(text.trim())
+        .ok()
+        .and_then(|v| Entry::from_json(&v));
+    if entry.is_none() {
+        event!(Level::Warn, "invalid", path = path.display());
+    }
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::Error;
+    use crate::solution::Solution;
+    use std::cell::Cell;
+    use std::io::{BufRead, Cursor};
+
+    // Sum sums the numbers on each line and counts the number of times it parsed an input.
+    #[derive(Default)]
+    struct Sum {
+        parsed: Cell<usize>,
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        type Part1 = u64;
+        type Part2 = usize;
+
+        fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+            self.parsed.set(self.parsed.get() + 1);
+            crate::lines::Lines::new(r)
+                .map(|line| line?.token().parse("number"))
+                .collect()
+        }
+
+        fn part1(&self, nums: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+            Ok(nums.iter().sum())
+        }
+
+        fn part2(&self, nums: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+            Ok(nums.len())
+        }
+    }
+
+    // temp_cache returns a cache in a new temporary directory.
+    fn temp_cache(name: &str) -> Cache {
+        let dir = env::temp_dir().join(format!("aoc-common-cache-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    const SPECS: &[Param] = &[Param {
+        name: "width",
+        default: "101",
+        help: "width",
+    }];
+
+    #[test]
+    fn test_key() {
+        let mut params = Params::new();
+        let key = Key::new(14, "0.1.0", SPECS, &params, "a1");
+        assert_eq!(key.params, "width = 101\n");
+
+        // Setting a parameter to its default doesn't change the key.
+        params.set("width", "101");
+        assert_eq!(Key::new(14, "0.1.0", SPECS, &params, "a1"), key);
+
+        params.set("width", "11");
+        let other = Key::new(14, "0.1.0", SPECS, &params, "a1");
+        assert_ne!(other.digest(), key.digest());
+        let other = Key::new(14, "0.2.0", SPECS, &Params::new(), "a1");
+        assert_ne!(other.digest(), key.digest());
+        let other = Key::new(14, "0.1.0", SPECS, &Params::new(), "b2");
+        assert_ne!(other.digest(), key.digest());
+    }
+
+    // key returns the key of day 1's answers to the input with the given digest.
+    fn key(input_sha256: &str) -> Key {
+        Key::new(1, "0.1.0", &[], &Params::new(), input_sha256)
+    }
+
+    #[test]
+    fn test_solve() -> Result<(), Box<dyn error::Error>> {
+        let cache = temp_cache("solve");
+        let sum = Sum::default();
+        let input = || Cursor::new(b"1\n2\n");
+
+        let (report, digest) = cache.solve(key, &sum, input(), &[Part::Two])?;
+        assert!(!report.cached);
+        assert_eq!(report.answers[0].value, "2");
+        assert_eq!(digest, sha256::hex_digest(b"1\n2\n"));
+        assert_eq!(sum.parsed.get(), 1);
+
+        // Part one is not cached yet, so the input is solved again and both parts are kept.
+        let (report, _) = cache.solve(key, &sum, input(), &Part::BOTH)?;
+        assert!(!report.cached);
+        assert_eq!(sum.parsed.get(), 2);
+
+        // The cached answers are returned without parsing the input.
+        let (report, _) = cache.solve(key, &sum, input(), &Part::BOTH)?;
+        assert!(report.cached);
+        assert_eq!(sum.parsed.get(), 2);
+        let answers: Vec<_> = report.answers.iter().map(|a| a.value.as_str()).collect();
+        assert_eq!(answers, ["3", "2"]);
+        assert_eq!(report.answers[0].type_name, "u64");
+
+        // Errors are not cached.
+        assert!(cache
+            .solve(key, &sum, Cursor::new(b"x\n"), &Part::BOTH)
+            .is_err());
+        assert!(cache
+            .get(&key(&sha256::hex_digest(b"x\n")), &Part::BOTH)
+            .is_none());
+
+        fs::remove_dir_all(cache.dir())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_uncached() -> Result<(), Box<dyn error::Error>> {
+        let sum = Sum::default();
+        let (report, digest) = solve_uncached(&sum, &b"1\n2\n"[..], &Part::BOTH)?;
+        assert!(!report.cached);
+        assert_eq!(report.answers[0].value, "3");
+        assert_eq!(digest, sha256::hex_digest(b"1\n2\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_spool() -> Result<(), Box<dyn error::Error>> {
+        let cache = temp_cache("spool");
+        let sum = Sum::default();
+        let spool = Spool::new(&b"1\n2\n"[..])?;
+        let path = spool.path.clone();
+        let (report, _) = cache.solve(key, &sum, spool, &Part::BOTH)?;
+        assert_eq!(report.answers[0].value, "3");
+        assert!(!path.exists());
+        fs::remove_dir_all(cache.dir())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_entry() -> Result<(), Box<dyn error::Error>> {
+        let cache = temp_cache("invalid");
+        let key = Key::new(2, "0.1.0", &[], &Params::new(), "");
+        fs::create_dir_all(cache.day_dir(2))?;
+        fs::write(cache.path(&key), "{\"day\":2")?;
+        assert!(cache.get(&key, &[Part::One]).is_none());
+        assert_eq!(cache.prune(|_| Some("0.1.0"))?, 1);
+        fs::remove_dir_all(cache.dir())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear_and_prune() -> Result<(), Box<dyn error::Error>> {
+        let cache = temp_cache("clear");
+        let sum = Sum::default();
+        for (day, version) in [(1, "0.1.0"), (1, "0.2.0"), (2, "0.1.0"), (3, "0.1.0")] {
+            let key = |digest: &str| Key::new(day, version, &[], &Params::new(), digest);
+            cache.solve(key, &sum, Cursor::new(b"1\n"), &Part::BOTH)?;
+        }
+
+        // Day 1's current version is 0.2.0 and day 3 no longer exists.
+        let current = |day| match day {
+            1 => Some("0.2.0"),
+            2 => Some("0.1.0"),
+            _ => None,
+        };
+        assert_eq!(cache.prune(current)?, 2);
+        assert_eq!(cache.clear(Some(2))?, 1);
+        assert!(!cache.day_dir(2).exists());
+        assert_eq!(cache.clear(None)?, 1);
+        assert_eq!(cache.clear(None)?, 0);
+
+        fs::remove_dir_all(cache.dir())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,895 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Read};
+use std::path;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time;
+
+use crate::cache::{self, Cache, Key, Spool};
+use crate::error::Error;
+use crate::explain::{Explain, Explanation, TableFormat};
+use crate::image::Images;
+use crate::json;
+use crate::log;
+use crate::params::{Param, Params};
+use crate::solution::{Answer, Part, Report, Runner};
+use crate::visualize::{Playback, Player, Visualize};
+
+/// EXIT_USAGE is the exit code used when a program is run with invalid arguments.
+pub const EXIT_USAGE: u8 = 2;
+
+/// Failure classifies why running a solution failed. Each class has its own exit code so that
+/// scripts can tell them apart.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Failure {
+    /// Io is an error reading the input.
+    Io,
+
+    /// Parse is an error in the input.
+    Parse,
+
+    /// NoSolution is returned when the puzzle has no answer for the input.
+    NoSolution,
+
+    /// Other is any other error found while solving the puzzle.
+    Other,
+}
+
+impl Failure {
+    /// of classifies an error.
+    pub fn of(err: &(dyn error::Error + 'static)) -> Failure {
+        if let Some(e) = err.downcast_ref::<Error>() {
+            return match e {
+                Error::Io(_) => Failure::Io,
+                Error::NoSolution { .. } => Failure::NoSolution,
+                _ => Failure::Parse,
+            };
+        }
+        if err.is::<io::Error>() {
+            return Failure::Io;
+        }
+        Failure::Other
+    }
+
+    /// code returns the process exit code for the failure.
+    pub fn code(&self) -> u8 {
+        match *self {
+            Failure::Other => 1,
+            Failure::Io => 3,
+            Failure::Parse => 4,
+            Failure::NoSolution => 5,
+        }
+    }
+}
+
+/// Format is the format used to print answers.
+#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Format {
+    /// Text prints the answer to each part on its own line.
+    #[default]
+    Text,
+
+    /// Json prints a single JSON object holding the answers, their types, timings and the
+    /// SHA-256 digest of the input.
+    Json,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format: {s:?}")),
+        }
+    }
+}
+
+// INTEGER_TYPES holds the answer types that are printed as JSON numbers.
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// answer_json returns the answer as a JSON object. Integer answers are JSON numbers and all
+/// other answers are strings.
+pub fn answer_json(answer: &Answer) -> json::Value {
+    let value = match answer.value.parse::<i128>() {
+        Ok(n) if INTEGER_TYPES.contains(&answer.type_name.as_str()) => json::Value::Int(n),
+        _ => json::Value::from(answer.value.as_str()),
+    };
+    json::Value::object([
+        ("part", json::Value::Int(answer.part as i128 + 1)),
+        ("answer", value),
+        ("type", json::Value::from(answer.type_name.as_str())),
+        (
+            "solve_ns",
+            json::Value::Int(answer.elapsed.as_nanos() as i128),
+        ),
+    ])
+}
+
+/// report_fields returns the fields of the JSON object describing a report for the input whose
+/// SHA-256 digest is input_sha256. They are returned as a list so that callers can add their own
+/// fields.
+pub fn report_fields(report: &Report, input_sha256: &str) -> Vec<(String, json::Value)> {
+    vec![
+        ("input_sha256".to_string(), json::Value::from(input_sha256)),
+        (
+            "parse_ns".to_string(),
+            json::Value::Int(report.parse_time.as_nanos() as i128),
+        ),
+        (
+            "parts".to_string(),
+            json::Value::Array(report.answers.iter().map(answer_json).collect()),
+        ),
+        ("cached".to_string(), json::Value::Bool(report.cached)),
+    ]
+}
+
+/// report_error prints the error to stderr and returns the exit code for it.
+pub fn report_error(err: &(dyn error::Error + 'static)) -> ExitCode {
+    eprintln!("error: {err}");
+    ExitCode::from(Failure::of(err).code())
+}
+
+// DEFAULT_DELAY is the time each frame is shown for with --visualize unless --delay is given.
+const DEFAULT_DELAY: time::Duration = time::Duration::from_millis(100);
+
+// DEFAULT_SCALE is the number of pixels per cell of images written with --export unless --scale
+// is given. It is kept small since --export can write thousands of frames.
+const DEFAULT_SCALE: usize = 1;
+
+// Args holds the parsed command line arguments of a day's binary.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    format: Format,
+    params: Params,
+    params_file: Option<path::PathBuf>,
+    help: bool,
+    visualize: bool,
+    step: bool,
+    delay: Option<time::Duration>,
+    export: Option<path::PathBuf>,
+    scale: Option<usize>,
+    verbosity: usize,
+    log: Vec<String>,
+    no_cache: bool,
+    cache_dir: Option<path::PathBuf>,
+    explain: Option<String>,
+    explain_format: Option<TableFormat>,
+}
+
+impl Args {
+    // playback returns how frames are played with --visualize, or None if it was not given.
+    fn playback(&self) -> Option<Playback> {
+        match (self.visualize, self.step) {
+            (false, _) => None,
+            (true, true) => Some(Playback::Step),
+            (true, false) => Some(Playback::Timed(self.delay.unwrap_or(DEFAULT_DELAY))),
+        }
+    }
+}
+
+// Command describes the command line of a day's binary.
+struct Command<'a> {
+    day: u8,
+    // version identifies the code of the day's solution. It is part of the key of cached answers.
+    version: &'a str,
+    // specs declares the day's parameters. A flag is accepted for each of them.
+    specs: &'a [Param],
+    // visual is true if the day can visualize its solution and accepts --visualize.
+    visual: bool,
+    // explanations declares the reports the day can print with --explain.
+    explanations: &'a [Explanation],
+}
+
+impl Command<'_> {
+    // parse_args parses the command line arguments, not including the program name.
+    fn parse_args(&self, mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => {
+                    parsed.help = true;
+                    continue;
+                }
+                "-v" | "-vv" | "-vvv" => {
+                    parsed.verbosity += arg.len() - 1;
+                    continue;
+                }
+                "--no-cache" => {
+                    parsed.no_cache = true;
+                    continue;
+                }
+                "--visualize" if self.visual => {
+                    parsed.visualize = true;
+                    continue;
+                }
+                "--step" if self.visual => {
+                    parsed.step = true;
+                    continue;
+                }
+                _ => {}
+            }
+            let (flag, value) = match arg.split_once('=') {
+                Some((f, v)) => (f.to_string(), Some(v.to_string())),
+                None => (arg, None),
+            };
+            let spec = self.specs.iter().find(|s| s.flag() == flag);
+            let known = ["--format", "--log", "--cache-dir"].contains(&flag.as_str())
+                || (!self.specs.is_empty() && flag == "--params")
+                || (self.visual && ["--delay", "--export", "--scale"].contains(&flag.as_str()))
+                || (!self.explanations.is_empty()
+                    && ["--explain", "--explain-format"].contains(&flag.as_str()));
+            if !known && spec.is_none() {
+                return Err(format!("unexpected argument: {flag}"));
+            }
+            let value = match value {
+                Some(v) => v,
+                None => args.next().ok_or(format!("{flag} requires a value"))?,
+            };
+            match spec {
+                Some(spec) => parsed.params.set(spec.name, &value),
+                None if flag == "--delay" => {
+                    let ms = value
+                        .parse()
+                        .map_err(|_| format!("invalid value for --delay: {value:?}"))?;
+                    parsed.delay = Some(time::Duration::from_millis(ms));
+                }
+                None if flag == "--log" => {
+                    log::Filter::default().apply(&value)?;
+                    parsed.log.push(value);
+                }
+                None if flag == "--cache-dir" => parsed.cache_dir = Some(value.into()),
+                None if flag == "--params" => parsed.params_file = Some(value.into()),
+                None if flag == "--explain" => {
+                    if !self.explanations.iter().any(|e| e.name == value) {
+                        return Err(format!("unknown explanation: {value:?}"));
+                    }
+                    parsed.explain = Some(value);
+                }
+                None if flag == "--explain-format" => parsed.explain_format = Some(value.parse()?),
+                None if flag == "--export" => parsed.export = Some(value.into()),
+                None if flag == "--scale" => {
+                    let scale = value
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or(format!("invalid value for --scale: {value:?}"))?;
+                    parsed.scale = Some(scale);
+                }
+                None => parsed.format = value.parse()?,
+            }
+        }
+        if (parsed.step || parsed.delay.is_some()) && !parsed.visualize {
+            return Err("--step and --delay can only be used with --visualize".to_string());
+        }
+        if parsed.scale.is_some() && parsed.export.is_none() {
+            return Err("--scale can only be used with --export".to_string());
+        }
+        if parsed.explain_format.is_some() && parsed.explain.is_none() {
+            return Err("--explain-format can only be used with --explain".to_string());
+        }
+        if parsed.no_cache && parsed.cache_dir.is_some() {
+            return Err("--no-cache and --cache-dir cannot be used together".to_string());
+        }
+        Ok(parsed)
+    }
+
+    // usage returns the usage message describing the flags and parameters.
+    fn usage(&self, name: &str) -> String {
+        let mut s = format!(
+            "Usage: {name} [-v|-vv|-vvv] [--log SPEC] [--format text|json] [--no-cache | --cache-dir DIR]"
+        );
+        if !self.specs.is_empty() {
+            s += " [--params FILE]";
+        }
+        for spec in self.specs {
+            s += &format!(" [{} N]", spec.flag());
+        }
+        if self.visual {
+            s += " [--visualize [--step | --delay MS]] [--export DIR [--scale N]]";
+        }
+        if !self.explanations.is_empty() {
+            s += " [--explain NAME [--explain-format text|csv]]";
+        }
+        s += " < input.txt\n";
+        s += &format!(
+            "\n-v, -vv and -vvv print info, debug and trace events of the solution to stderr.
+--log and {} take a filter such as info,day16=trace that sets the level of
+each module.
+
+Answers are cached in --cache-dir, {} or ~/.cache/advent2024 keyed
+by the input, the parameters and the version of the solution. --no-cache solves
+the input again without reading or writing the cache.\n",
+            log::ENV_VAR,
+            cache::ENV_VAR
+        );
+        if !self.specs.is_empty() {
+            s += "\nParameters are also read from --params FILE, which holds name = value lines.
+Environment variables take precedence over the file and flags over both.
+
+Parameters:\n";
+            for spec in self.specs {
+                s += &format!(
+                    "  {}: {} (default {}, env {})\n",
+                    spec.flag(),
+                    spec.help,
+                    spec.default,
+                    spec.env_var(self.day)
+                );
+            }
+        }
+        if !self.explanations.is_empty() {
+            s += "\n--explain prints tables explaining the answers instead of the answers, as
+aligned text or as CSV with --explain-format:\n";
+            for e in self.explanations {
+                s += &format!("  {}: {}\n", e.name, e.help);
+            }
+        }
+        if self.visual {
+            s += &format!(
+                "\n--visualize shows the solution working on stderr before printing the answers.
+Each frame is shown for --delay milliseconds ({} by default), or until Enter is
+pressed with --step. Set NO_COLOR to turn off colours.
+
+--export writes the frames to DIR as numbered PPM images, frame-0000.ppm and so
+on, along with any still images of the solution the day draws. Each cell is
+--scale pixels wide ({} by default).\n",
+                DEFAULT_DELAY.as_millis(),
+                DEFAULT_SCALE
+            );
+        }
+        s
+    }
+
+    // parse parses the command line of the process. Usage errors and --help are handled here
+    // and the exit code to return is given as the error.
+    fn parse(&self) -> Result<Args, ExitCode> {
+        let mut args = env::args();
+        let name = args
+            .next()
+            .and_then(|a| {
+                path::Path::new(&a)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+            })
+            .unwrap_or_default();
+        match self.parse_args(args) {
+            Ok(args) if args.help => {
+                print!("{}", self.usage(&name));
+                Err(ExitCode::SUCCESS)
+            }
+            Ok(args) => match log::configure(args.verbosity, &args.log) {
+                Ok(()) => Ok(args),
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    Err(ExitCode::from(EXIT_USAGE))
+                }
+            },
+            Err(e) => {
+                eprintln!("error: {e}");
+                eprint!("{}", self.usage(&name));
+                Err(ExitCode::from(EXIT_USAGE))
+            }
+        }
+    }
+
+    // build parses the command line and builds the solution from the parameters set by flags,
+    // the --params file and environment variables. The params of the returned arguments hold
+    // all of them.
+    fn build<R>(&self, build: impl Fn(&Params) -> Result<R, Error>) -> Result<(R, Args), ExitCode> {
+        let mut args = self.parse()?;
+        args.params = match Params::resolve(
+            self.day,
+            self.specs,
+            args.params_file.as_deref(),
+            &args.params,
+        ) {
+            Ok(params) => params,
+            // Errors reading the params file keep their exit code, such as 3 for a missing file.
+            Err(e) => return Err(report_error(&e)),
+        };
+        match build(&args.params) {
+            Ok(solution) => Ok((solution, args)),
+            Err(e) => {
+                eprintln!("error: {e}");
+                Err(ExitCode::from(EXIT_USAGE))
+            }
+        }
+    }
+
+    // cache returns the cache to use, or None if caching is turned off by --no-cache or there is
+    // no cache directory.
+    fn cache(&self, args: &Args) -> Option<Cache> {
+        if args.no_cache {
+            return None;
+        }
+        args.cache_dir
+            .clone()
+            .or_else(Cache::default_dir)
+            .map(Cache::new)
+    }
+
+    // key returns the key of the answers to the input whose SHA-256 digest is input_sha256.
+    fn key(&self, args: &Args, input_sha256: &str) -> Key {
+        Key::new(
+            self.day,
+            self.version,
+            self.specs,
+            &args.params,
+            input_sha256,
+        )
+    }
+}
+
+// read_input reads the whole input. It is only used when the input is parsed more than once,
+// such as to visualize the solution before solving it. Otherwise the input is parsed as it is
+// read.
+fn read_input(mut r: impl BufRead) -> Result<Vec<u8>, ExitCode> {
+    let mut input = Vec::new();
+    match r.read_to_end(&mut input) {
+        Ok(_) => Ok(input),
+        Err(e) => Err(report_error(&Error::from(e))),
+    }
+}
+
+// solve solves the puzzle input read from r, or reads the answers from the cache unless
+// --no-cache is given, and prints the answers in the format given by --format. With a cache, the
+// input is copied to a temporary file so that it can be hashed before it is parsed.
+fn solve(command: &Command, args: &Args, solution: &dyn Runner, r: impl Read) -> ExitCode {
+    let key = |input_sha256: &str| command.key(args, input_sha256);
+    let solved = match command.cache(args) {
+        Some(cache) => Spool::new(r)
+            .map_err(Box::from)
+            .and_then(|input| cache.solve(key, solution, input, &Part::BOTH)),
+        None => cache::solve_uncached(solution, r, &Part::BOTH),
+    };
+    let (report, input_sha256) = match solved {
+        Ok(solved) => solved,
+        Err(e) => return report_error(e.as_ref()),
+    };
+
+    match args.format {
+        Format::Text => {
+            for answer in &report.answers {
+                println!("{}", answer.value);
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            json::Value::Object(report_fields(&report, &input_sha256))
+        ),
+    }
+    ExitCode::SUCCESS
+}
+
+// visualize parses the input and plays the solution's frames on stderr. Step playback reads
+// from the terminal since stdin holds the puzzle input.
+fn visualize<S: Visualize>(
+    solution: &S,
+    playback: Playback,
+    input: &[u8],
+) -> Result<(), Box<dyn error::Error>> {
+    let parsed = solution.parse(input)?;
+    let stderr = io::stderr();
+    let ansi = stderr.is_terminal() && env::var_os("NO_COLOR").is_none();
+    let mut player = Player::new(stderr.lock(), playback, ansi);
+    if playback == Playback::Step {
+        let tty =
+            fs::File::open("/dev/tty").map_err(|e| format!("--step needs a terminal: {e}"))?;
+        player = player.with_keys(io::BufReader::new(tty));
+    }
+    solution.visualize(&parsed, &mut player)
+}
+
+// export parses the input and writes the solution's frames and still images into dir.
+fn export<S: Visualize>(
+    solution: &S,
+    dir: &path::Path,
+    scale: usize,
+    input: &[u8],
+) -> Result<(), Box<dyn error::Error>> {
+    let parsed = solution.parse(input)?;
+    let mut images = Images::new(dir, scale)?;
+    let mut player = Player::to_images(images.sequence("frame"));
+    solution.visualize(&parsed, &mut player)?;
+    drop(player);
+    solution.export(&parsed, &mut images)?;
+    eprintln!(
+        "wrote {} images to {}",
+        images.written().len(),
+        dir.display()
+    );
+    Ok(())
+}
+
+/// run is the main function of the binary of a day without parameters. It solves the puzzle
+/// input read from r and prints the answers to stdout in the format given by the --format flag.
+/// Answers are cached on disk, keyed by the input and version of the day's code, unless
+/// --no-cache is given. Errors are printed to stderr and the returned exit code depends
+/// on the kind of failure.
+pub fn run<R: Runner>(day: u8, version: &str, solution: &R, r: impl BufRead) -> ExitCode {
+    let command = Command {
+        day,
+        version,
+        specs: &[],
+        visual: false,
+        explanations: &[],
+    };
+    let args = match command.parse() {
+        Ok(args) => args,
+        Err(code) => return code,
+    };
+    solve(&command, &args, solution, r)
+}
+
+/// run_with_params is the main function of the binary of a day with the parameters declared in
+/// specs. It works like run but the solution is built from the parameters. Each parameter is
+/// set by its flag, such as --width 11, or by its environment variable, such as
+/// AOC_DAY14_WIDTH=11, and the flag takes precedence. Parameters that are not set keep their
+/// defaults.
+pub fn run_with_params<R: Runner>(
+    day: u8,
+    version: &str,
+    specs: &[Param],
+    build: impl Fn(&Params) -> Result<R, Error>,
+    r: impl BufRead,
+) -> ExitCode {
+    let command = Command {
+        day,
+        version,
+        specs,
+        visual: false,
+        explanations: &[],
+    };
+    let (solution, args) = match command.build(build) {
+        Ok(built) => built,
+        Err(code) => return code,
+    };
+    solve(&command, &args, &solution, r)
+}
+
+/// run_visual is the main function of the binary of a day that can visualize its solution. It
+/// works like run_with_params but also accepts --visualize, which plays frames of the solution
+/// working on stderr before the answers are printed, and --export, which writes the frames and
+/// the day's still images into a directory.
+pub fn run_visual<S: Visualize>(
+    day: u8,
+    version: &str,
+    specs: &[Param],
+    build: impl Fn(&Params) -> Result<S, Error>,
+    r: impl BufRead,
+) -> ExitCode {
+    let command = Command {
+        day,
+        version,
+        specs,
+        visual: true,
+        explanations: &[],
+    };
+    let (solution, args) = match command.build(build) {
+        Ok(built) => built,
+        Err(code) => return code,
+    };
+    if args.playback().is_none() && args.export.is_none() {
+        return solve(&command, &args, &solution, r);
+    }
+    let input = match read_input(r) {
+        Ok(input) => input,
+        Err(code) => return code,
+    };
+    if let Some(playback) = args.playback() {
+        if let Err(e) = visualize(&solution, playback, &input) {
+            return report_error(e.as_ref());
+        }
+    }
+    if let Some(dir) = &args.export {
+        let scale = args.scale.unwrap_or(DEFAULT_SCALE);
+        if let Err(e) = export(&solution, dir, scale, &input) {
+            return report_error(e.as_ref());
+        }
+    }
+    solve(&command, &args, &solution, input.as_slice())
+}
+
+/// run_explained is the main function of the binary of a day that can explain its answers. It
+/// works like run_with_params but also accepts --explain NAME, which prints the tables of the
+/// named explanation in the format given by --explain-format instead of the answers.
+pub fn run_explained<S: Explain>(
+    day: u8,
+    version: &str,
+    specs: &[Param],
+    build: impl Fn(&Params) -> Result<S, Error>,
+    r: impl BufRead,
+) -> ExitCode {
+    let command = Command {
+        day,
+        version,
+        specs,
+        visual: false,
+        explanations: S::EXPLANATIONS,
+    };
+    let (solution, args) = match command.build(build) {
+        Ok(built) => built,
+        Err(code) => return code,
+    };
+    let name = match &args.explain {
+        Some(name) => name,
+        None => return solve(&command, &args, &solution, r),
+    };
+    let format = args.explain_format.unwrap_or_default();
+    let explained = solution
+        .parse(r)
+        .map_err(|e| e.into())
+        .and_then(|parsed| solution.explain(&parsed, name))
+        .and_then(|tables| Ok(format.write(&mut io::stdout().lock(), &tables)?));
+    match explained {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => report_error(e.as_ref()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha256;
+
+    const SPECS: &[Param] = &[Param {
+        name: "max_len",
+        default: "2",
+        help: "maximum length",
+    }];
+
+    // PLAIN is the command line of a day without parameters.
+    const PLAIN: Command = Command {
+        day: 1,
+        version: "0.1.0",
+        specs: &[],
+        visual: false,
+        explanations: &[],
+    };
+
+    // args returns the arguments as an iterator like env::args.
+    fn args(a: &[&str]) -> impl Iterator<Item = String> {
+        a.iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_parse_args() -> Result<(), String> {
+        assert_eq!(PLAIN.parse_args(args(&[]))?, Args::default());
+        assert_eq!(
+            PLAIN.parse_args(args(&["--format", "json"]))?.format,
+            Format::Json
+        );
+        assert_eq!(
+            PLAIN.parse_args(args(&["--format=text"]))?.format,
+            Format::Text
+        );
+        assert!(PLAIN.parse_args(args(&["--help"]))?.help);
+        assert!(PLAIN.parse_args(args(&["--format"])).is_err());
+        assert!(PLAIN.parse_args(args(&["--format", "yaml"])).is_err());
+        assert!(PLAIN.parse_args(args(&["input.txt"])).is_err());
+        assert!(PLAIN.parse_args(args(&["--max-len", "3"])).is_err());
+        assert!(PLAIN.parse_args(args(&["--visualize"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_log() -> Result<(), String> {
+        let parsed = PLAIN.parse_args(args(&["-vv", "-v", "--log", "day16=trace"]))?;
+        assert_eq!(parsed.verbosity, 3);
+        assert_eq!(parsed.log, vec!["day16=trace".to_string()]);
+        assert!(PLAIN.parse_args(args(&["--log=day16=loud"])).is_err());
+        assert!(PLAIN.parse_args(args(&["-vvvv"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_cache() -> Result<(), String> {
+        assert!(PLAIN.parse_args(args(&["--no-cache"]))?.no_cache);
+        let parsed = PLAIN.parse_args(args(&["--cache-dir", "/tmp/cache"]))?;
+        assert_eq!(parsed.cache_dir, Some("/tmp/cache".into()));
+        assert!(PLAIN
+            .parse_args(args(&["--no-cache", "--cache-dir=/tmp/cache"]))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_params() -> Result<(), String> {
+        let command = Command {
+            day: 20,
+            version: "0.1.0",
+            specs: SPECS,
+            visual: false,
+            explanations: &[],
+        };
+        let parsed = command.parse_args(args(&["--max-len", "3", "--format=json"]))?;
+        assert_eq!(parsed.format, Format::Json);
+        assert_eq!(parsed.params.to_string(), "max_len = 3\n");
+        let parsed = command.parse_args(args(&["--max-len=4"]))?;
+        assert_eq!(parsed.params.to_string(), "max_len = 4\n");
+        let parsed = command.parse_args(args(&["--params", "day20.txt"]))?;
+        assert_eq!(parsed.params_file, Some("day20.txt".into()));
+        assert!(PLAIN.parse_args(args(&["--params", "day1.txt"])).is_err());
+        assert!(command.parse_args(args(&["--max_len", "3"])).is_err());
+        assert!(command.parse_args(args(&["--max-len"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_visualize() -> Result<(), String> {
+        let command = Command {
+            day: 6,
+            version: "0.1.0",
+            specs: &[],
+            visual: true,
+            explanations: &[],
+        };
+        assert_eq!(command.parse_args(args(&[]))?.playback(), None);
+        assert_eq!(
+            command.parse_args(args(&["--visualize"]))?.playback(),
+            Some(Playback::Timed(DEFAULT_DELAY))
+        );
+        assert_eq!(
+            command
+                .parse_args(args(&["--visualize", "--delay=5"]))?
+                .playback(),
+            Some(Playback::Timed(time::Duration::from_millis(5)))
+        );
+        assert_eq!(
+            command
+                .parse_args(args(&["--step", "--visualize"]))?
+                .playback(),
+            Some(Playback::Step)
+        );
+        assert!(command.parse_args(args(&["--step"])).is_err());
+        assert!(command
+            .parse_args(args(&["--visualize", "--delay", "x"]))
+            .is_err());
+
+        let parsed = command.parse_args(args(&["--export", "out", "--scale=3"]))?;
+        assert_eq!(parsed.playback(), None);
+        assert_eq!(parsed.export, Some(path::PathBuf::from("out")));
+        assert_eq!(parsed.scale, Some(3));
+        assert!(command.parse_args(args(&["--scale", "3"])).is_err());
+        assert!(command
+            .parse_args(args(&["--export", "out", "--scale", "0"]))
+            .is_err());
+        assert!(PLAIN.parse_args(args(&["--export", "out"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_explain() -> Result<(), String> {
+        let command = Command {
+            day: 1,
+            version: "0.1.0",
+            specs: &[],
+            visual: false,
+            explanations: &[Explanation {
+                name: "matrix",
+                help: "every pair of lists",
+            }],
+        };
+        let parsed = command.parse_args(args(&["--explain", "matrix", "--explain-format=csv"]))?;
+        assert_eq!(parsed.explain, Some("matrix".to_string()));
+        assert_eq!(parsed.explain_format, Some(TableFormat::Csv));
+        assert!(command.parse_args(args(&["--explain", "pairs"])).is_err());
+        assert!(command
+            .parse_args(args(&["--explain-format", "csv"]))
+            .is_err());
+        assert!(PLAIN.parse_args(args(&["--explain", "matrix"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_usage() {
+        let command = Command {
+            day: 20,
+            version: "0.1.0",
+            specs: SPECS,
+            visual: false,
+            explanations: &[],
+        };
+        assert_eq!(
+            command.usage("day20"),
+            "Usage: day20 [-v|-vv|-vvv] [--log SPEC] [--format text|json] [--no-cache | --cache-dir DIR] [--params FILE] [--max-len N] < input.txt
+
+-v, -vv and -vvv print info, debug and trace events of the solution to stderr.
+--log and AOC_LOG take a filter such as info,day16=trace that sets the level of
+each module.
+
+Answers are cached in --cache-dir, AOC_CACHE_DIR or ~/.cache/advent2024 keyed
+by the input, the parameters and the version of the solution. --no-cache solves
+the input again without reading or writing the cache.
+
+Parameters are also read from --params FILE, which holds name = value lines.
+Environment variables take precedence over the file and flags over both.
+
+Parameters:
+  --max-len: maximum length (default 2, env AOC_DAY20_MAX_LEN)
+"
+        );
+    }
+
+    #[test]
+    fn test_report_fields() {
+        let report = Report {
+            parse_time: time::Duration::from_nanos(1500),
+            answers: vec![
+                Answer {
+                    part: Part::One,
+                    value: "42".to_string(),
+                    type_name: "usize".to_string(),
+                    elapsed: time::Duration::from_nanos(20),
+                },
+                Answer {
+                    part: Part::Two,
+                    value: "6,1".to_string(),
+                    type_name: "Point".to_string(),
+                    elapsed: time::Duration::from_nanos(30),
+                },
+            ],
+            cached: false,
+        };
+        assert_eq!(
+            json::Value::Object(report_fields(&report, &sha256::hex_digest(b"abc"))).to_string(),
+            concat!(
+                r#"{"input_sha256":"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad","#,
+                r#""parse_ns":1500,"parts":["#,
+                r#"{"part":1,"answer":42,"type":"usize","solve_ns":20},"#,
+                r#"{"part":2,"answer":"6,1","type":"Point","solve_ns":30}],"cached":false}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_failure() {
+        let err: Box<dyn error::Error> = Box::new(Error::semantic("bad input"));
+        assert_eq!(Failure::of(err.as_ref()), Failure::Parse);
+
+        let err: Box<dyn error::Error> = Box::new(Error::no_solution("no path"));
+        assert_eq!(Failure::of(err.as_ref()), Failure::NoSolution);
+
+        let err: Box<dyn error::Error> = Box::new(Error::from(io::Error::other("closed")));
+        assert_eq!(Failure::of(err.as_ref()), Failure::Io);
+
+        let err: Box<dyn error::Error> = Box::new(io::Error::other("closed"));
+        assert_eq!(Failure::of(err.as_ref()), Failure::Io);
+
+        let err: Box<dyn error::Error> = "overflow".into();
+        assert_eq!(Failure::of(err.as_ref()), Failure::Other);
+    }
+}
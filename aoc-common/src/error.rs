@@ -0,0 +1,222 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Span identifies a piece of text on a single line of input.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Span {
+    /// line is the 1-based line number.
+    pub line: usize,
+
+    /// column is the 1-based character column where the text starts.
+    pub column: usize,
+
+    /// text is the offending text.
+    pub text: String,
+
+    /// source is the full text of the line. It is used to render snippets.
+    pub source: String,
+}
+
+/// Error is an error found while reading or solving a puzzle input.
+#[derive(Debug)]
+pub enum Error {
+    /// Io is an error reading the input.
+    Io(io::Error),
+
+    /// UnexpectedEof is returned when the input ends early. line is the line number at which more
+    /// input was expected.
+    UnexpectedEof { line: usize, expected: String },
+
+    /// MalformedToken is returned when a token could not be parsed as the expected value.
+    MalformedToken { span: Span, expected: String },
+
+    /// InvalidChar is returned when a character is not one of the expected characters.
+    InvalidChar { span: Span, expected: String },
+
+    /// Semantic is returned when the input is well formed but is not valid for the puzzle.
+    Semantic { span: Option<Span>, msg: String },
+
+    /// NoSolution is returned when the input is valid but the puzzle has no answer for it.
+    NoSolution { msg: String },
+}
+
+impl Error {
+    /// semantic returns a Semantic error that is not tied to a location in the input.
+    pub fn semantic(msg: impl Into<String>) -> Self {
+        Error::Semantic {
+            span: None,
+            msg: msg.into(),
+        }
+    }
+
+    /// no_solution returns a NoSolution error.
+    pub fn no_solution(msg: impl Into<String>) -> Self {
+        Error::NoSolution { msg: msg.into() }
+    }
+
+    /// span returns the location of the error in the input if known.
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Error::MalformedToken { span, .. } | Error::InvalidChar { span, .. } => Some(span),
+            Error::Semantic { span, .. } => span.as_ref(),
+            Error::Io(_) | Error::UnexpectedEof { .. } | Error::NoSolution { .. } => None,
+        }
+    }
+
+    /// line returns the 1-based line number of the error if known.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Error::UnexpectedEof { line, .. } => Some(*line),
+            _ => self.span().map(|s| s.line),
+        }
+    }
+
+    /// column returns the 1-based column of the error if known.
+    pub fn column(&self) -> Option<usize> {
+        self.span().map(|s| s.column)
+    }
+
+    /// message returns a one line description of the error without the source snippet.
+    pub fn message(&self) -> String {
+        match self {
+            Error::Io(e) => format!("i/o error: {e}"),
+            Error::UnexpectedEof { expected, .. } => {
+                format!("unexpected end of input: expected {expected}")
+            }
+            Error::MalformedToken { span, expected } => {
+                format!(
+                    "malformed token: expected {expected}, found {:?}",
+                    span.text
+                )
+            }
+            Error::InvalidChar { span, expected } => {
+                format!(
+                    "invalid character: expected {expected}, found {:?}",
+                    span.text
+                )
+            }
+            Error::Semantic { msg, .. } => msg.clone(),
+            Error::NoSolution { msg } => format!("no solution found: {msg}"),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    // fmt renders the error in a style similar to rustc diagnostics. For example:
+    //
+    //   malformed token: expected integer, found "a"
+    //    --> 3:1
+    //     |
+    //   3 | a   b
+    //     | ^
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())?;
+
+        if let Error::UnexpectedEof { line, .. } = self {
+            return write!(f, "\n --> {line}");
+        }
+
+        let span = match self.span() {
+            Some(span) => span,
+            None => return Ok(()),
+        };
+
+        let gutter = span.line.to_string().len();
+        let indent = span
+            .source
+            .chars()
+            .take(span.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let carets = "^".repeat(span.text.chars().count().max(1));
+
+        write!(f, "\n{:gutter$}--> {}:{}", "", span.line, span.column)?;
+        write!(f, "\n{:gutter$} |", "")?;
+        write!(f, "\n{} |", span.line)?;
+        if !span.source.is_empty() {
+            write!(f, " {}", span.source)?;
+        }
+        write!(f, "\n{:gutter$} | {}{}", "", indent, carets)
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippet() {
+        let err = Error::MalformedToken {
+            span: Span {
+                line: 12,
+                column: 5,
+                text: "4x".to_string(),
+                source: "3   4x".to_string(),
+            },
+            expected: "integer".to_string(),
+        };
+        assert_eq!(err.line(), Some(12));
+        assert_eq!(err.column(), Some(5));
+        assert_eq!(
+            err.to_string(),
+            "malformed token: expected integer, found \"4x\"
+  --> 12:5
+   |
+12 | 3   4x
+   |     ^^"
+        );
+    }
+
+    #[test]
+    fn test_no_span() {
+        let err = Error::semantic("inconsistent rules");
+        assert_eq!(err.line(), None);
+        assert_eq!(err.to_string(), "inconsistent rules");
+
+        let err = Error::UnexpectedEof {
+            line: 3,
+            expected: "button B".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "unexpected end of input: expected button B\n --> 3"
+        );
+
+        let err = Error::no_solution("the exit is unreachable");
+        assert_eq!(err.line(), None);
+        assert_eq!(
+            err.to_string(),
+            "no solution found: the exit is unreachable"
+        );
+    }
+}
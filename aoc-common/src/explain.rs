@@ -0,0 +1,211 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::solution::Solution;
+
+/// Table is a table of text cells with a header row. Tables explain how a solution found its
+/// answers and are printed as aligned text or as CSV.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Table {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// new returns an empty table with the given header. The first cell of the header names the
+    /// table.
+    pub fn new<T: ToString>(header: impl IntoIterator<Item = T>) -> Self {
+        Table {
+            header: header.into_iter().map(|c| c.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// push adds a row to the table.
+    pub fn push<T: ToString>(&mut self, row: impl IntoIterator<Item = T>) {
+        self.rows
+            .push(row.into_iter().map(|c| c.to_string()).collect());
+    }
+
+    /// write_text writes the table as text with its columns aligned. Numbers are aligned to the
+    /// right and other cells to the left.
+    pub fn write_text(&self, w: &mut dyn Write) -> io::Result<()> {
+        let rows = || std::iter::once(&self.header).chain(&self.rows);
+        let columns = rows().map(|r| r.len()).max().unwrap_or(0);
+        let widths: Vec<_> = (0..columns)
+            .map(|i| {
+                rows()
+                    .filter_map(|r| r.get(i))
+                    .map(|c| c.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for row in rows() {
+            let mut line = String::new();
+            for (i, cell) in row.iter().enumerate() {
+                if i > 0 {
+                    line += "  ";
+                }
+                let width = widths[i];
+                if cell.parse::<f64>().is_ok() {
+                    line += &format!("{cell:>width$}");
+                } else {
+                    line += &format!("{cell:<width$}");
+                }
+            }
+            writeln!(w, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+
+    /// write_csv writes the table as CSV. Cells holding commas, quotes or line breaks are quoted.
+    pub fn write_csv(&self, w: &mut dyn Write) -> io::Result<()> {
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            let cells: Vec<_> = row.iter().map(|c| csv_cell(c)).collect();
+            writeln!(w, "{}", cells.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+// csv_cell returns the cell quoted for CSV if needed.
+fn csv_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// TableFormat is the format tables are printed in.
+#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum TableFormat {
+    /// Text prints the tables with their columns aligned.
+    #[default]
+    Text,
+
+    /// Csv prints the tables as CSV.
+    Csv,
+}
+
+impl TableFormat {
+    /// write writes the tables in the format. Tables are separated by a blank line.
+    pub fn write(&self, w: &mut dyn Write, tables: &[Table]) -> io::Result<()> {
+        for (i, table) in tables.iter().enumerate() {
+            if i > 0 {
+                writeln!(w)?;
+            }
+            match self {
+                TableFormat::Text => table.write_text(w)?,
+                TableFormat::Csv => table.write_csv(w)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TableFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TableFormat::Text => write!(f, "text"),
+            TableFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+impl FromStr for TableFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(TableFormat::Text),
+            "csv" => Ok(TableFormat::Csv),
+            _ => Err(format!("invalid table format: {s:?}")),
+        }
+    }
+}
+
+/// Explanation declares a report that a solution can print to explain its answers.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Explanation {
+    /// name is the name the report is chosen by, e.g. "matrix".
+    pub name: &'static str,
+
+    /// help is a short description of the report.
+    pub help: &'static str,
+}
+
+/// Explain is implemented by solutions that can print reports explaining their answers, such as
+/// tables of the values that add up to a total.
+pub trait Explain: Solution {
+    /// EXPLANATIONS declares the reports the solution can print.
+    const EXPLANATIONS: &'static [Explanation];
+
+    /// explain returns the tables of the named report for the input. The name is one of those
+    /// in EXPLANATIONS.
+    fn explain(&self, input: &Self::Input, name: &str)
+        -> Result<Vec<Table>, Box<dyn error::Error>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(["pair", "left", "note"]);
+        table.push(["1", "3", "ok"]);
+        table.push(["10", "-4", "say \"hi\", twice"]);
+        table
+    }
+
+    fn write(format: TableFormat, tables: &[Table]) -> String {
+        let mut out = Vec::new();
+        format.write(&mut out, tables).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            write(TableFormat::Text, &[table(), Table::new(["empty"])]),
+            "pair  left  note
+   1     3  ok
+  10    -4  say \"hi\", twice
+
+empty
+"
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            write(TableFormat::Csv, &[table()]),
+            "pair,left,note\n1,3,ok\n10,-4,\"say \"\"hi\"\", twice\"\n"
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!("csv".parse(), Ok(TableFormat::Csv));
+        assert_eq!(TableFormat::Text.to_string(), "text");
+        assert!("tsv".parse::<TableFormat>().is_err());
+    }
+}
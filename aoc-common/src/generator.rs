@@ -0,0 +1,211 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::ops;
+
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::point::{Direction, Point};
+
+/// Rng is a small, seedable pseudo-random number generator used to generate puzzle inputs. The
+/// same seed always produces the same sequence of numbers on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// new returns a generator seeded with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// next_u64 returns the next random number. It uses the SplitMix64 algorithm.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// below returns a random number in 0..n. n must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "below called with n = 0");
+        // NOTE: The high bits of the product are used rather than the remainder so that every
+        //       value is almost equally likely for any n.
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// range returns a random number in the range. The range must not be empty.
+    pub fn range(&mut self, r: ops::Range<i64>) -> i64 {
+        assert!(r.start < r.end, "range called with an empty range");
+        let n = r.end.abs_diff(r.start);
+        r.start
+            .wrapping_add(((self.next_u64() as u128 * n as u128) >> 64) as i64)
+    }
+
+    /// chance returns true with probability num/den.
+    pub fn chance(&mut self, num: usize, den: usize) -> bool {
+        self.below(den) < num
+    }
+
+    /// choose returns a random element of the slice. The slice must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// shuffle shuffles the slice in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generated is a generated puzzle input along with the parameters needed to solve it and the
+/// answers to each part that are known from the way the input was constructed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Generated {
+    /// input is the puzzle input.
+    pub input: String,
+
+    /// params holds the parameters, such as grid sizes, to solve the input with. It is empty if
+    /// the day's defaults apply.
+    pub params: Params,
+
+    /// part1 is the answer to part 1 if it is known.
+    pub part1: Option<String>,
+
+    /// part2 is the answer to part 2 if it is known.
+    pub part2: Option<String>,
+}
+
+impl Generated {
+    /// expected returns the known answers with one line per part, in the same format as the
+    /// example NAME.out.txt files. The line for a part with an unknown answer is empty.
+    pub fn expected(&self) -> String {
+        format!(
+            "{}\n{}\n",
+            self.part1.as_deref().unwrap_or_default(),
+            self.part2.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// maze returns a size by size maze of '#' walls and '.' open locations with a single path
+/// between any two open locations. Open locations are at odd coordinates and the passages
+/// between them are carved by a randomized depth-first search. size must be odd and at least 3.
+pub fn maze(rng: &mut Rng, size: usize) -> Grid<char> {
+    let mut maze = Grid::new(size, size, '#');
+    let start = Point::new(1, 1);
+    maze[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&p) = stack.last() {
+        let next: Vec<_> = Direction::CARDINAL
+            .into_iter()
+            .filter_map(|dir| Some((dir, maze.step(p, dir, 2)?)))
+            .filter(|(_, n)| n.x < size - 1 && n.y < size - 1 && maze[*n] == '#')
+            .collect();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (dir, n) = *rng.choose(&next);
+        if let Some(wall) = maze.step(p, dir, 1) {
+            maze[wall] = '.';
+        }
+        maze[n] = '.';
+        stack.push(n);
+    }
+    maze
+}
+
+/// maze_path returns the locations on the shortest path from start to end through the locations
+/// of the maze that are not '#', including both ends. An empty path is returned if the end
+/// cannot be reached.
+pub fn maze_path(maze: &Grid<char>, start: Point, end: Point) -> Vec<Point> {
+    let mut from = collections::HashMap::from([(start, start)]);
+    let mut queue = collections::VecDeque::from([start]);
+    while let Some(p) = queue.pop_front() {
+        for (_, n) in maze.neighbors4(p) {
+            if maze[n] != '#' && !from.contains_key(&n) {
+                from.insert(n, p);
+                queue.push_back(n);
+            }
+        }
+    }
+    if !from.contains_key(&end) {
+        return Vec::new();
+    }
+
+    let mut path = vec![end];
+    while let Some(&p) = path.last().filter(|p| **p != start) {
+        path.push(from[&p]);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut a = Rng::new(2024);
+        let mut b = Rng::new(2024);
+        let mut c = Rng::new(2025);
+        let xs: Vec<_> = (0..8).map(|_| a.next_u64()).collect();
+        assert_eq!(xs, (0..8).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(xs, (0..8).map(|_| c.next_u64()).collect::<Vec<_>>());
+
+        for _ in 0..1000 {
+            assert!(a.below(7) < 7);
+            assert!((-3..4).contains(&a.range(-3..4)));
+        }
+        assert_eq!(a.range(i64::MIN..i64::MIN + 1), i64::MIN);
+
+        let mut items: Vec<_> = (0..20).collect();
+        a.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_maze() {
+        let mut rng = Rng::new(16);
+        let maze = maze(&mut rng, 9);
+        let open = maze.iter().filter(|(_, c)| **c == '.').count();
+        // A maze of 4 by 4 rooms has 16 rooms joined by 15 passages.
+        assert_eq!(open, 31);
+
+        let path = maze_path(&maze, Point::new(1, 7), Point::new(7, 1));
+        assert_eq!(path.first(), Some(&Point::new(1, 7)));
+        assert_eq!(path.last(), Some(&Point::new(7, 1)));
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+        assert!(maze_path(&maze, Point::new(1, 1), Point::new(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_expected() {
+        let g = Generated {
+            input: "1 2\n".to_string(),
+            part2: Some("31".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(g.expected(), "\n31\n");
+    }
+}
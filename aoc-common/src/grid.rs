@@ -0,0 +1,294 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io::BufRead;
+use std::ops;
+
+use crate::error::Error;
+use crate::lines::{Lines, Token};
+use crate::point::{Direction, Point};
+
+/// Grid is a rectangular two-dimensional grid of cells stored in row-major order.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// new returns a new grid of the given size with each cell set to value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// from_rows builds a grid from a list of rows. An error is returned if the rows are not all
+    /// the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Error> {
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(Error::semantic(format!(
+                    "row {}: expected {} columns, found {}",
+                    y + 1,
+                    width,
+                    row.len()
+                )));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// width returns the number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// height returns the number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// contains returns true if the point is within the bounds of the grid.
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    /// get returns a reference to the cell at the given point or None if it is out of bounds.
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if self.contains(p) {
+            self.cells.get(p.y * self.width + p.x)
+        } else {
+            None
+        }
+    }
+
+    /// get_mut returns a mutable reference to the cell at the given point or None if it is out
+    /// of bounds.
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            self.cells.get_mut(p.y * self.width + p.x)
+        } else {
+            None
+        }
+    }
+
+    /// step returns the point n steps away in the given direction if it is within the grid.
+    pub fn step(&self, p: Point, dir: Direction, n: usize) -> Option<Point> {
+        p.step(dir, n).filter(|next| self.contains(*next))
+    }
+
+    /// neighbors4 returns the points adjacent to p in the four cardinal directions that are
+    /// within the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |dir| self.step(p, dir, 1).map(|next| (dir, next)))
+    }
+
+    /// neighbors8 returns the points adjacent to p in all eight directions that are within the
+    /// grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(p, dir, 1).map(|next| (dir, next)))
+    }
+
+    /// points returns an iterator over every point in the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// iter returns an iterator over every point in the grid along with its value.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// rows returns an iterator over the rows of the grid.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // NOTE: chunks panics on a zero chunk size.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// position returns the first point, in row-major order, whose value matches the predicate.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, v)| pred(v)).map(|(p, _)| p)
+    }
+
+    /// map returns a new grid of the same size with f applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// read_with reads a grid with one row per line, converting each character with f. The token
+    /// passed to f can be used to return an error pointing at the character. An error pointing
+    /// at the first line with a different length than the first is returned if the lines are not
+    /// all the same length.
+    pub fn read_with(
+        r: impl BufRead,
+        mut f: impl FnMut(char, &Token) -> Result<T, Error>,
+    ) -> Result<Self, Error> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in Lines::new(r) {
+            let line = line?;
+            let row = line
+                .chars()
+                .map(|(c, t)| f(c, &t))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(line.semantic(format!(
+                        "expected {} columns, found {}",
+                        first.len(),
+                        row.len()
+                    )));
+                }
+            }
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+}
+
+impl Grid<char> {
+    /// read reads a grid of characters with one row per line. An error is returned if the lines
+    /// are not all the same length.
+    pub fn read(r: impl BufRead) -> Result<Self, Error> {
+        Grid::read_with(r, |c, _| Ok(c))
+    }
+
+    /// read_charset reads a grid of characters like read. An InvalidChar error is returned for
+    /// the first character that is not in charset.
+    pub fn read_charset(r: impl BufRead, charset: &str) -> Result<Self, Error> {
+        Grid::read_with(r, |c, t| {
+            if charset.contains(c) {
+                Ok(c)
+            } else {
+                Err(t.invalid_char(&format!("one of {charset:?}")))
+            }
+        })
+    }
+
+    /// find returns the first point, in row-major order, containing the character c.
+    pub fn find(&self, c: char) -> Option<Point> {
+        self.position(|v| *v == c)
+    }
+}
+
+impl<T> ops::Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(self.contains(p), "point {p} out of bounds");
+        &self.cells[p.y * self.width + p.x]
+    }
+}
+
+impl<T> ops::IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(self.contains(p), "point {p} out of bounds");
+        &mut self.cells[p.y * self.width + p.x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows().take(self.height) {
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_read() -> Result<(), Error> {
+        let input = Bytes::from("#.S\n..#\n");
+        let grid = Grid::read(input.reader())?;
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.find('S'), Some(Point::new(2, 0)));
+        assert_eq!(grid[Point::new(2, 1)], '#');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "#.S\n..#\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ragged() {
+        let input = Bytes::from("#.S\n.#\n");
+        let err = Grid::read(input.reader()).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.message(), "expected 3 columns, found 2");
+    }
+
+    #[test]
+    fn test_read_with() {
+        let input = Bytes::from("123\n4x6\n");
+        let err = Grid::read_with(input.reader(), |c, t| {
+            c.to_digit(10).ok_or_else(|| t.invalid_char("digit"))
+        })
+        .unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.column(), Some(2));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(0, 2)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.step(Point::new(1, 1), Direction::Right, 2), None);
+    }
+
+    #[test]
+    fn test_empty() -> Result<(), Error> {
+        let grid = Grid::read(Bytes::new().reader())?;
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.to_string(), "");
+        Ok(())
+    }
+}
@@ -0,0 +1,430 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path;
+
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::visualize::{Color, Frame};
+
+/// Rgb is a 24-bit colour.
+#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+
+    /// for_key returns a bright colour picked by the character so that, for example, each type
+    /// of plant or antenna frequency always gets the same colour.
+    pub fn for_key(c: char) -> Rgb {
+        // Spread the hues by multiplying by a number close to the golden ratio conjugate.
+        let hue = (c as u32).wrapping_mul(2654435761) >> 8;
+        Rgb::from_hue((hue % 360) as u16)
+    }
+
+    // from_hue returns the fully saturated colour with the given hue in degrees.
+    fn from_hue(hue: u16) -> Rgb {
+        let x = (255 * (60 - (hue % 120).abs_diff(60)) / 60) as u8;
+        match hue / 60 {
+            0 => Rgb(255, x, 0),
+            1 => Rgb(x, 255, 0),
+            2 => Rgb(0, 255, x),
+            3 => Rgb(0, x, 255),
+            4 => Rgb(x, 0, 255),
+            _ => Rgb(255, 0, x),
+        }
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(c: Color) -> Self {
+        match c {
+            Color::Black => Rgb(0, 0, 0),
+            Color::Red => Rgb(205, 49, 49),
+            Color::Green => Rgb(13, 188, 121),
+            Color::Yellow => Rgb(229, 229, 16),
+            Color::Blue => Rgb(36, 114, 200),
+            Color::Magenta => Rgb(188, 63, 188),
+            Color::Cyan => Rgb(17, 168, 205),
+            Color::White => Rgb(229, 229, 229),
+        }
+    }
+}
+
+impl fmt::Display for Rgb {
+    // fmt writes the colour in the #rrggbb form used by SVG.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// frame_image returns an image of the frame with a pixel per cell. Actors and highlighted cells
+/// take their colours and other cells are black, or gray for characters other than '.'.
+pub fn frame_image(frame: &Frame) -> Grid<Rgb> {
+    frame.cells().map(|cell| match (cell.fg, cell.bg) {
+        (Some(fg), _) if cell.bold => fg.into(),
+        (_, Some(bg)) => bg.into(),
+        (Some(fg), None) => fg.into(),
+        (None, None) if cell.ch == '.' || cell.ch == ' ' => Rgb::BLACK,
+        (None, None) => Rgb::GRAY,
+    })
+}
+
+// scaled returns the rows of the image with each pixel repeated scale times in both directions.
+fn scaled<'a, T: Copy>(image: &'a Grid<T>, scale: usize) -> impl Iterator<Item = Vec<T>> + 'a {
+    image.rows().take(image.height()).flat_map(move |row| {
+        let line: Vec<T> = row
+            .iter()
+            .flat_map(|v| std::iter::repeat_n(*v, scale))
+            .collect();
+        std::iter::repeat_n(line, scale)
+    })
+}
+
+/// write_ppm writes the image as a binary PPM file with each cell drawn as a square of scale by
+/// scale pixels.
+pub fn write_ppm(mut w: impl Write, image: &Grid<Rgb>, scale: usize) -> io::Result<()> {
+    write!(
+        w,
+        "P6\n{} {}\n255\n",
+        image.width() * scale,
+        image.height() * scale
+    )?;
+    for row in scaled(image, scale) {
+        let bytes: Vec<u8> = row.iter().flat_map(|p| [p.0, p.1, p.2]).collect();
+        w.write_all(&bytes)?;
+    }
+    Ok(())
+}
+
+/// write_pgm writes the grayscale image as a binary PGM file with each cell drawn as a square of
+/// scale by scale pixels.
+pub fn write_pgm(mut w: impl Write, image: &Grid<u8>, scale: usize) -> io::Result<()> {
+    write!(
+        w,
+        "P5\n{} {}\n255\n",
+        image.width() * scale,
+        image.height() * scale
+    )?;
+    for row in scaled(image, scale) {
+        w.write_all(&row)?;
+    }
+    Ok(())
+}
+
+// escape escapes text for use in XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Svg is a simple SVG drawing of a grid. Cells are squares and points refer to cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    width: usize,
+    height: usize,
+    cell: usize,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    /// new returns an empty drawing of a grid of the given size with cells of cell by cell units.
+    pub fn new(width: usize, height: usize, cell: usize) -> Self {
+        Svg {
+            width,
+            height,
+            cell,
+            elements: Vec::new(),
+        }
+    }
+
+    /// from_image returns a drawing of the image with a square for each pixel. Runs of pixels of
+    /// the same colour are drawn as a single rectangle.
+    pub fn from_image(image: &Grid<Rgb>, cell: usize) -> Self {
+        let mut svg = Svg::new(image.width(), image.height(), cell);
+        for (y, row) in image.rows().take(image.height()).enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let run = row[x..].iter().take_while(|c| **c == row[x]).count();
+                svg.elements.push(format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x * cell,
+                    y * cell,
+                    run * cell,
+                    cell,
+                    row[x]
+                ));
+                x += run;
+            }
+        }
+        svg
+    }
+
+    // center returns the coordinates of the center of the cell at p.
+    fn center(&self, p: Point) -> (f64, f64) {
+        let half = self.cell as f64 / 2.0;
+        (
+            (p.x * self.cell) as f64 + half,
+            (p.y * self.cell) as f64 + half,
+        )
+    }
+
+    /// rect fills the cell at p.
+    pub fn rect(&mut self, p: Point, fill: Rgb) -> &mut Self {
+        self.elements.push(format!(
+            r#"<rect x="{}" y="{}" width="{c}" height="{c}" fill="{fill}"/>"#,
+            p.x * self.cell,
+            p.y * self.cell,
+            c = self.cell
+        ));
+        self
+    }
+
+    /// path draws a line through the centers of the cells at the given points.
+    pub fn path(&mut self, points: &[Point], stroke: Rgb) -> &mut Self {
+        let coords: Vec<_> = points
+            .iter()
+            .map(|p| {
+                let (x, y) = self.center(*p);
+                format!("{x},{y}")
+            })
+            .collect();
+        self.elements.push(format!(
+            r#"<polyline points="{}" fill="none" stroke="{stroke}" stroke-width="{}" stroke-linejoin="round"/>"#,
+            coords.join(" "),
+            self.cell as f64 / 3.0
+        ));
+        self
+    }
+
+    /// circle draws a dot in the cell at p.
+    pub fn circle(&mut self, p: Point, fill: Rgb) -> &mut Self {
+        let (x, y) = self.center(p);
+        self.elements.push(format!(
+            r#"<circle cx="{x}" cy="{y}" r="{}" fill="{fill}"/>"#,
+            self.cell as f64 / 2.5
+        ));
+        self
+    }
+
+    /// text writes text centered in the cell at p.
+    pub fn text(&mut self, p: Point, text: &str, fill: Rgb) -> &mut Self {
+        let (x, y) = self.center(p);
+        self.elements.push(format!(
+            r#"<text x="{x}" y="{y}" font-size="{}" font-family="monospace" text-anchor="middle" dominant-baseline="central" fill="{fill}">{}</text>"#,
+            self.cell,
+            escape(text)
+        ));
+        self
+    }
+}
+
+impl fmt::Display for Svg {
+    // fmt writes the SVG document.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = self.width * self.cell,
+            h = self.height * self.cell
+        )?;
+        for e in &self.elements {
+            writeln!(f, "{e}")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+/// Images writes images into a directory. Raster images are scaled up so that each cell is a
+/// square of pixels.
+pub struct Images {
+    dir: path::PathBuf,
+    scale: usize,
+    written: Vec<path::PathBuf>,
+}
+
+impl Images {
+    /// new returns an Images writing into dir, which is created if it doesn't exist.
+    pub fn new(dir: impl Into<path::PathBuf>, scale: usize) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Images {
+            dir,
+            scale: scale.max(1),
+            written: Vec::new(),
+        })
+    }
+
+    // create creates the named file in the directory.
+    fn create(&mut self, name: &str) -> io::Result<io::BufWriter<fs::File>> {
+        let path = self.dir.join(name);
+        let f = fs::File::create(&path)?;
+        self.written.push(path);
+        Ok(io::BufWriter::new(f))
+    }
+
+    /// ppm writes the image to NAME.ppm.
+    pub fn ppm(&mut self, name: &str, image: &Grid<Rgb>) -> io::Result<()> {
+        let scale = self.scale;
+        write_ppm(self.create(&format!("{name}.ppm"))?, image, scale)
+    }
+
+    /// pgm writes the grayscale image to NAME.pgm.
+    pub fn pgm(&mut self, name: &str, image: &Grid<u8>) -> io::Result<()> {
+        let scale = self.scale;
+        write_pgm(self.create(&format!("{name}.pgm"))?, image, scale)
+    }
+
+    /// svg writes the drawing to NAME.svg.
+    pub fn svg(&mut self, name: &str, svg: &Svg) -> io::Result<()> {
+        write!(self.create(&format!("{name}.svg"))?, "{svg}")
+    }
+
+    /// sequence returns a Sequence that writes the frames of an animation to numbered files
+    /// starting with name.
+    pub fn sequence(&mut self, name: &str) -> Sequence<'_> {
+        Sequence {
+            images: self,
+            name: name.to_string(),
+            next: 0,
+        }
+    }
+
+    /// written returns the paths of the files written so far.
+    pub fn written(&self) -> &[path::PathBuf] {
+        &self.written
+    }
+}
+
+/// Sequence writes the frames of an animation to numbered files, such as frame-0000.ppm,
+/// frame-0001.ppm and so on.
+pub struct Sequence<'a> {
+    images: &'a mut Images,
+    name: String,
+    next: usize,
+}
+
+impl Sequence<'_> {
+    // next_name returns the name of the next frame's file without its extension.
+    fn next_name(&mut self) -> String {
+        self.next += 1;
+        format!("{}-{:04}", self.name, self.next - 1)
+    }
+
+    /// ppm writes the next frame as a PPM file.
+    pub fn ppm(&mut self, image: &Grid<Rgb>) -> io::Result<()> {
+        let name = self.next_name();
+        self.images.ppm(&name, image)
+    }
+
+    /// pgm writes the next frame as a PGM file.
+    pub fn pgm(&mut self, image: &Grid<u8>) -> io::Result<()> {
+        let name = self.next_name();
+        self.images.pgm(&name, image)
+    }
+
+    /// len returns the number of frames written.
+    pub fn len(&self) -> usize {
+        self.next
+    }
+
+    /// is_empty returns true if no frames have been written.
+    pub fn is_empty(&self) -> bool {
+        self.next == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ppm() -> io::Result<()> {
+        let mut image = Grid::new(2, 1, Rgb::BLACK);
+        image[Point::new(1, 0)] = Rgb(1, 2, 3);
+        let mut out = Vec::new();
+        write_ppm(&mut out, &image, 2)?;
+        assert_eq!(
+            out,
+            b"P6\n4 2\n255\n\0\0\0\0\0\0\x01\x02\x03\x01\x02\x03\0\0\0\0\0\0\x01\x02\x03\x01\x02\x03"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pgm() -> io::Result<()> {
+        let image = Grid::from_rows(vec![vec![0, 255], vec![7, 9]]).unwrap();
+        let mut out = Vec::new();
+        write_pgm(&mut out, &image, 1)?;
+        assert_eq!(out, b"P5\n2 2\n255\n\x00\xff\x07\x09");
+        Ok(())
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut image = Grid::new(3, 1, Rgb::WHITE);
+        image[Point::new(2, 0)] = Rgb(255, 0, 0);
+        let mut svg = Svg::from_image(&image, 10);
+        svg.path(&[Point::new(0, 0), Point::new(1, 0)], Rgb::BLACK)
+            .text(Point::new(2, 0), "<", Rgb::BLACK);
+        assert_eq!(
+            svg.to_string(),
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="10" viewBox="0 0 30 10">
+<rect x="0" y="0" width="20" height="10" fill="#ffffff"/>
+<rect x="20" y="0" width="10" height="10" fill="#ff0000"/>
+<polyline points="5,5 15,5" fill="none" stroke="#000000" stroke-width="3.3333333333333335" stroke-linejoin="round"/>
+<text x="25" y="5" font-size="10" font-family="monospace" text-anchor="middle" dominant-baseline="central" fill="#000000">&lt;</text>
+</svg>
+"##
+        );
+    }
+
+    #[test]
+    fn test_frame_image() {
+        let mut frame = Frame::blank(3, 1, '.');
+        frame
+            .set(Point::new(0, 0), '#')
+            .actor(Point::new(2, 0), '@', Color::Red);
+        assert_eq!(
+            frame_image(&frame),
+            Grid::from_rows(vec![vec![Rgb::GRAY, Rgb::BLACK, Rgb::from(Color::Red)]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_for_key() {
+        assert_eq!(Rgb::for_key('A'), Rgb::for_key('A'));
+        assert_ne!(Rgb::for_key('A'), Rgb::for_key('B'));
+    }
+
+    #[test]
+    fn test_sequence() -> io::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-common-images-{}", std::process::id()));
+        let mut images = Images::new(&dir, 1)?;
+        let mut seq = images.sequence("frame");
+        seq.pgm(&Grid::new(1, 1, 0))?;
+        seq.pgm(&Grid::new(1, 1, 255))?;
+        assert_eq!(seq.len(), 2);
+        assert_eq!(fs::read(dir.join("frame-0001.pgm"))?, b"P5\n1 1\n255\n\xff");
+        assert_eq!(images.written().len(), 2);
+        fs::remove_dir_all(&dir)
+    }
+}
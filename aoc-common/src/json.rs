@@ -0,0 +1,338 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// Value is a JSON value. Objects keep their keys in insertion order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// object returns an Object value with the given fields.
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Value)>) -> Value {
+        Value::Object(fields.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    /// get returns the value of the field with the given key if the value is an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// as_int returns the value if it is an integer.
+    pub fn as_int(&self) -> Option<i128> {
+        match *self {
+            Value::Int(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// as_f64 returns the value if it is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Int(n) => Some(n as f64),
+            Value::Float(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// as_str returns the value if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// as_array returns the values if it is an array.
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// parse parses a JSON document. Numbers without a fraction or exponent are parsed as Int.
+pub fn parse(s: &str) -> Result<Value, String> {
+    let mut p = Parser {
+        chars: s.char_indices().peekable(),
+    };
+    let v = p.value()?;
+    p.skip_whitespace();
+    match p.chars.next() {
+        Some((i, c)) => Err(format!("unexpected {c:?} at offset {i}")),
+        None => Ok(v),
+    }
+}
+
+// Parser is a recursive descent JSON parser.
+struct Parser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_whitespace())
+            .is_some()
+        {}
+    }
+
+    // expect consumes the next character and returns an error if it is not c.
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        match self.chars.next() {
+            Some((_, next)) if next == c => Ok(()),
+            Some((i, next)) => Err(format!("expected {c:?} at offset {i}, found {next:?}")),
+            None => Err(format!("expected {c:?}, found end of input")),
+        }
+    }
+
+    // keyword consumes the given keyword and returns v.
+    fn keyword(&mut self, word: &str, v: Value) -> Result<Value, String> {
+        for c in word.chars() {
+            self.expect(c)?;
+        }
+        Ok(v)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.chars.peek().copied() {
+            Some((_, '{')) => self.object(),
+            Some((_, '[')) => self.array(),
+            Some((_, '"')) => Ok(Value::String(self.string()?)),
+            Some((_, 't')) => self.keyword("true", Value::Bool(true)),
+            Some((_, 'f')) => self.keyword("false", Value::Bool(false)),
+            Some((_, 'n')) => self.keyword("null", Value::Null),
+            Some((_, c)) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some((i, c)) => Err(format!("unexpected {c:?} at offset {i}")),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == '}').is_some() {
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            if self.chars.next_if(|(_, c)| *c == ',').is_none() {
+                self.expect('}')?;
+                return Ok(Value::Object(fields));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|(_, c)| *c == ']').is_some() {
+            return Ok(Value::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            if self.chars.next_if(|(_, c)| *c == ',').is_none() {
+                self.expect(']')?;
+                return Ok(Value::Array(values));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(s),
+                Some((i, '\\')) => match self.chars.next() {
+                    Some((_, '"')) => s.push('"'),
+                    Some((_, '\\')) => s.push('\\'),
+                    Some((_, '/')) => s.push('/'),
+                    Some((_, 'b')) => s.push('\u{8}'),
+                    Some((_, 'f')) => s.push('\u{c}'),
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 'r')) => s.push('\r'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((_, 'u')) => {
+                        let hex: String = (0..4)
+                            .filter_map(|_| self.chars.next())
+                            .map(|(_, c)| c)
+                            .collect();
+                        // NOTE: Surrogate pairs are not supported since they are never written by
+                        //       Display.
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| format!("invalid unicode escape at offset {i}"))?;
+                        s.push(c);
+                    }
+                    _ => return Err(format!("invalid escape at offset {i}")),
+                },
+                Some((_, c)) => s.push(c),
+                None => return Err("unterminated string".to_string()),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let mut text = String::new();
+        let mut offset = None;
+        while let Some((i, c)) = self
+            .chars
+            .next_if(|(_, c)| c.is_ascii_digit() || "+-.eE".contains(*c))
+        {
+            offset.get_or_insert(i);
+            text.push(c);
+        }
+        let err = || format!("invalid number {text:?} at offset {}", offset.unwrap_or(0));
+        if text.contains(['.', 'e', 'E']) {
+            text.parse().map(Value::Float).map_err(|_| err())
+        } else {
+            text.parse().map(Value::Int).map_err(|_| err())
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+// write_str writes s as a quoted JSON string.
+fn write_str(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for Value {
+    // fmt writes the value as compact JSON.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Int(n) => write!(f, "{n}"),
+            // NOTE: JSON has no representation for NaN or infinity.
+            Value::Float(n) if !n.is_finite() => write!(f, "null"),
+            Value::Float(n) => write!(f, "{n:?}"),
+            Value::String(s) => write_str(f, s),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{v}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (k, v)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_str(f, k)?;
+                    write!(f, ":{v}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let v = Value::object([
+            ("name", Value::from("a \"b\"\n")),
+            ("n", Value::Int(-3)),
+            ("x", Value::Float(1.0)),
+            (
+                "list",
+                Value::Array(vec![Value::Null, Value::Bool(true), Value::from("\u{1}")]),
+            ),
+            ("empty", Value::object::<String>([])),
+        ]);
+        assert_eq!(
+            v.to_string(),
+            r#"{"name":"a \"b\"\n","n":-3,"x":1.0,"list":[null,true,"\u0001"],"empty":{}}"#
+        );
+    }
+
+    #[test]
+    fn test_parse() -> Result<(), String> {
+        let v = parse(r#" {"a": [1, -2.5e1, "x\u0041\n"], "b": {}, "c": null, "d": false} "#)?;
+        assert_eq!(
+            v.get("a").and_then(|a| a.as_array()),
+            Some(&[Value::Int(1), Value::Float(-25.0), Value::from("xA\n")][..])
+        );
+        assert_eq!(v.get("b"), Some(&Value::object::<String>([])));
+        assert_eq!(v.get("d"), Some(&Value::Bool(false)));
+
+        // Values survive a round trip.
+        assert_eq!(parse(&v.to_string())?, v);
+
+        assert!(parse("").is_err());
+        assert!(parse("[1,]").is_err());
+        assert!(parse(r#"{"a" 1}"#).is_err());
+        assert!(parse("1 2").is_err());
+        assert!(parse(r#""abc"#).is_err());
+        Ok(())
+    }
+}
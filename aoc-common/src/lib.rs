@@ -0,0 +1,44 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Library aoc_common holds helpers shared by the Advent of Code solutions.
+
+pub mod cache;
+pub mod cli;
+pub mod error;
+pub mod explain;
+pub mod generator;
+pub mod grid;
+pub mod image;
+pub mod json;
+pub mod lines;
+pub mod log;
+pub mod params;
+pub mod point;
+pub mod sha256;
+pub mod solution;
+pub mod source;
+pub mod visualize;
+
+pub use cache::{Cache, Key};
+pub use error::{Error, Span};
+pub use explain::{Explain, Explanation, Table, TableFormat};
+pub use generator::{Generated, Rng};
+pub use grid::Grid;
+pub use image::{Images, Rgb, Sequence, Svg};
+pub use lines::{Line, Lines, Normalize, Token};
+pub use params::{Param, Params};
+pub use point::{Direction, Point};
+pub use solution::{Answer, Part, Prepared, Report, Runner, Solution};
+pub use visualize::{Cell, Color, Frame, Playback, Player, Visualize};
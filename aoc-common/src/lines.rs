@@ -0,0 +1,372 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{self, BufRead, Read};
+use std::str::FromStr;
+
+use crate::error::{Error, Span};
+
+// BOM is the UTF-8 encoding of the byte order mark.
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// Normalize reads input with line endings and whitespace normalized so that inputs saved by
+/// different editors and operating systems read the same. A UTF-8 byte order mark at the start
+/// of the input is removed, trailing whitespace, including the carriage return of CRLF line
+/// endings, is removed from every line, blank lines at the end of the input are removed and
+/// the last line always ends with a newline. Blank lines elsewhere are kept so that line numbers
+/// are not changed.
+pub struct Normalize<R> {
+    inner: R,
+    // buf holds the normalized text of the lines read so far that hasn't been consumed.
+    buf: Vec<u8>,
+    pos: usize,
+    // blank is the number of blank lines that have been read but not yet returned. They are
+    // only returned once another line is read.
+    blank: usize,
+    start: bool,
+}
+
+impl<R: BufRead> Normalize<R> {
+    /// new returns a new Normalize reading from r.
+    pub fn new(r: R) -> Self {
+        Normalize {
+            inner: r,
+            buf: Vec::new(),
+            pos: 0,
+            blank: 0,
+            start: true,
+        }
+    }
+}
+
+impl<R: BufRead> Read for Normalize<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let n = {
+            let available = self.fill_buf()?;
+            let n = available.len().min(out.len());
+            out[..n].copy_from_slice(&available[..n]);
+            n
+        };
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for Normalize<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.pos == self.buf.len() {
+            self.buf.clear();
+            self.pos = 0;
+            if self.inner.read_until(b'\n', &mut self.buf)? == 0 {
+                // Blank lines at the end of the input are dropped.
+                return Ok(&[]);
+            }
+            if self.start {
+                self.start = false;
+                if self.buf.starts_with(BOM) {
+                    self.buf.drain(..BOM.len());
+                }
+            }
+            let len = self
+                .buf
+                .iter()
+                .rposition(|b| !b.is_ascii_whitespace())
+                .map_or(0, |i| i + 1);
+            self.buf.truncate(len);
+            if self.buf.is_empty() {
+                self.blank += 1;
+                continue;
+            }
+            self.buf.push(b'\n');
+            if self.blank > 0 {
+                self.buf.splice(..0, std::iter::repeat_n(b'\n', self.blank));
+                self.blank = 0;
+            }
+        }
+        Ok(&self.buf[self.pos..])
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.pos = (self.pos + n).min(self.buf.len());
+    }
+}
+
+/// Lines reads input line by line while keeping track of line numbers so that errors can point
+/// at the offending text. The input is read through Normalize.
+pub struct Lines<R> {
+    inner: io::Lines<Normalize<R>>,
+    number: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    /// new returns a new Lines reading from r.
+    pub fn new(r: R) -> Self {
+        Lines {
+            inner: Normalize::new(r).lines(),
+            number: 0,
+        }
+    }
+
+    /// number returns the line number of the last line read.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    /// expect returns the next line. An UnexpectedEof error is returned if there are no more
+    /// lines.
+    pub fn expect(&mut self, expected: &str) -> Result<Line, Error> {
+        match self.next() {
+            Some(line) => line,
+            None => Err(Error::UnexpectedEof {
+                line: self.number + 1,
+                expected: expected.to_string(),
+            }),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<Line, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = match self.inner.next()? {
+            Ok(text) => text,
+            Err(e) => return Some(Err(e.into())),
+        };
+        self.number += 1;
+        Some(Ok(Line {
+            number: self.number,
+            text,
+        }))
+    }
+}
+
+/// Line is a single line of input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Line {
+    /// number is the 1-based line number.
+    pub number: usize,
+
+    /// text is the text of the line without the line ending.
+    pub text: String,
+}
+
+impl Line {
+    /// token returns the whole line as a token.
+    pub fn token(&self) -> Token<'_> {
+        Token {
+            line: self,
+            offset: 0,
+            text: &self.text,
+        }
+    }
+
+    /// fields splits the line on whitespace.
+    pub fn fields(&self) -> impl Iterator<Item = Token<'_>> {
+        self.token().fields()
+    }
+
+    /// split splits the line on the separator.
+    pub fn split<'a>(&'a self, sep: &'a str) -> impl Iterator<Item = Token<'a>> {
+        self.token().split(sep)
+    }
+
+    /// chars returns each character in the line as a token.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Token<'_>)> {
+        self.token().chars()
+    }
+
+    /// is_empty returns true if the line contains only whitespace.
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// semantic returns a Semantic error pointing at the whole line.
+    pub fn semantic(&self, msg: impl Into<String>) -> Error {
+        self.token().semantic(msg)
+    }
+}
+
+/// Token is a piece of text within a line of input.
+#[derive(Debug, Copy, Clone)]
+pub struct Token<'a> {
+    line: &'a Line,
+    offset: usize,
+
+    /// text is the text of the token.
+    pub text: &'a str,
+}
+
+impl<'a> Token<'a> {
+    // sub returns a token for a slice of this token's text.
+    fn sub(&self, text: &'a str) -> Token<'a> {
+        // NOTE: text is always a slice of self.text so the offset is its distance from the start.
+        let start = text.as_ptr() as usize - self.text.as_ptr() as usize;
+        Token {
+            line: self.line,
+            offset: self.offset + start,
+            text,
+        }
+    }
+
+    /// span returns the location of the token in the input.
+    pub fn span(&self) -> Span {
+        Span {
+            line: self.line.number,
+            column: self.line.text[..self.offset].chars().count() + 1,
+            text: self.text.to_string(),
+            source: self.line.text.clone(),
+        }
+    }
+
+    /// fields splits the token on whitespace.
+    pub fn fields(&self) -> impl Iterator<Item = Token<'a>> {
+        let t = *self;
+        self.text.split_whitespace().map(move |s| t.sub(s))
+    }
+
+    /// split splits the token on the separator.
+    pub fn split(&self, sep: &'a str) -> impl Iterator<Item = Token<'a>> {
+        let t = *self;
+        self.text.split(sep).map(move |s| t.sub(s))
+    }
+
+    /// split_once splits the token on the first occurrence of the separator. A MalformedToken
+    /// error is returned if the separator is not found.
+    pub fn split_once(&self, sep: &str) -> Result<(Token<'a>, Token<'a>), Error> {
+        match self.text.split_once(sep) {
+            Some((a, b)) => Ok((self.sub(a), self.sub(b))),
+            None => Err(self.malformed(&format!("{sep:?} separator"))),
+        }
+    }
+
+    /// chars returns each character in the token as a token.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Token<'a>)> {
+        let t = *self;
+        self.text
+            .char_indices()
+            .map(move |(i, c)| (c, t.sub(&t.text[i..i + c.len_utf8()])))
+    }
+
+    /// trim returns the token with leading and trailing whitespace removed.
+    pub fn trim(&self) -> Token<'a> {
+        self.sub(self.text.trim())
+    }
+
+    /// strip_prefix returns the token with the prefix removed. A MalformedToken error is returned
+    /// if the token does not start with the prefix.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Token<'a>, Error> {
+        match self.text.strip_prefix(prefix) {
+            Some(s) => Ok(self.sub(s)),
+            None => Err(self.malformed(&format!("{prefix:?}"))),
+        }
+    }
+
+    /// parse parses the token as a value of type T. A MalformedToken error describing the expected
+    /// value is returned if it cannot be parsed.
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, Error> {
+        self.text.parse::<T>().map_err(|_| self.malformed(expected))
+    }
+
+    /// malformed returns a MalformedToken error for this token.
+    pub fn malformed(&self, expected: &str) -> Error {
+        Error::MalformedToken {
+            span: self.span(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// invalid_char returns an InvalidChar error for this token.
+    pub fn invalid_char(&self, expected: &str) -> Error {
+        Error::InvalidChar {
+            span: self.span(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// semantic returns a Semantic error pointing at this token.
+    pub fn semantic(&self, msg: impl Into<String>) -> Error {
+        Error::Semantic {
+            span: Some(self.span()),
+            msg: msg.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_spans() -> Result<(), Error> {
+        let mut lines = Lines::new(Bytes::from("p=0,4 v=3,-3\np=6,3 v=x,-3\n").reader());
+        lines.expect("robot")?;
+        let line = lines.expect("robot")?;
+
+        let fields: Vec<_> = line.fields().collect();
+        let (_, vel) = fields[1].split_once("=")?;
+        let v: Vec<_> = vel.split(",").collect();
+        let err = v[0].parse::<i64>("integer").unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.column(), Some(9));
+        assert_eq!(v[1].parse::<i64>("integer")?, -3);
+
+        let err = lines.expect("robot").unwrap_err();
+        assert_eq!(err.line(), Some(3));
+        Ok(())
+    }
+
+    // normalize returns the input read through Normalize.
+    fn normalize(input: &[u8]) -> String {
+        let mut s = String::new();
+        Normalize::new(input).read_to_string(&mut s).unwrap();
+        s
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(b"a b\nc\n"), "a b\nc\n");
+        assert_eq!(normalize(b"a b\r\nc\r\n"), "a b\nc\n");
+        assert_eq!(normalize(b"\xef\xbb\xbfa\nc"), "a\nc\n");
+        assert_eq!(normalize(b"a \t\n\n \nc  \r\n\r\n  \n\n"), "a\n\n\nc\n");
+        assert_eq!(normalize(b"  a\n"), "  a\n");
+        assert_eq!(normalize(b"a\xef\xbb\xbf\n"), "a\u{feff}\n");
+        assert_eq!(normalize(b""), "");
+        assert_eq!(normalize(b"\n\r\n \n"), "");
+    }
+
+    #[test]
+    fn test_normalized_lines() -> Result<(), Error> {
+        let input = b"\xef\xbb\xbf3   4x \r\n\r\n5   6\r\n\r\n";
+        let lines = Lines::new(&input[..]).collect::<Result<Vec<_>, _>>()?;
+        let text: Vec<_> = lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(text, ["3   4x", "", "5   6"]);
+        let err = lines[0].fields().nth(1).unwrap().parse::<i64>("integer");
+        assert_eq!(err.unwrap_err().column(), Some(5));
+        assert_eq!(lines[2].number, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_chars() -> Result<(), Error> {
+        let mut lines = Lines::new(Bytes::from("ab\n").reader());
+        let line = lines.expect("line")?;
+        let (c, t) = line.chars().nth(1).unwrap();
+        assert_eq!(c, 'b');
+        assert_eq!(t.span().column, 2);
+        assert_eq!(t.strip_prefix("b")?.span().column, 3);
+        Ok(())
+    }
+}
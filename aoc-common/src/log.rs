@@ -0,0 +1,286 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{self, AtomicU8};
+use std::sync::RwLock;
+
+/// ENV_VAR is the environment variable holding the filter, such as "info,day16=trace".
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// Level is the importance of an event. Levels are ordered from the most to the least important.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// from_verbosity returns the least important level shown when -v is given n times. Warnings
+    /// and errors are shown by default.
+    pub fn from_verbosity(n: usize) -> Level {
+        match n {
+            0 => Level::Warn,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("invalid log level: {s:?}")),
+        }
+    }
+}
+
+/// Filter decides which events are shown. Each module can have its own level and modules
+/// without one use the default level.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Filter {
+    default: Level,
+    modules: Vec<(String, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::new(Level::Warn)
+    }
+}
+
+impl Filter {
+    /// new returns a filter showing events at level or above in every module.
+    pub const fn new(default: Level) -> Self {
+        Filter {
+            default,
+            modules: Vec::new(),
+        }
+    }
+
+    /// set_default sets the level of modules without their own.
+    pub fn set_default(&mut self, level: Level) {
+        self.default = level;
+    }
+
+    /// set_module sets the level of the module and the modules within it. The module is a path
+    /// such as day16 or day23::clique.
+    pub fn set_module(&mut self, module: &str, level: Level) {
+        self.modules.retain(|(m, _)| m != module);
+        self.modules.push((module.to_string(), level));
+    }
+
+    /// apply applies a comma-separated list of directives. Each is a level, which sets the
+    /// default, a MODULE=LEVEL pair, or a module on its own, which shows all of its events. For
+    /// example, "info,day16=trace,day23".
+    pub fn apply(&mut self, spec: &str) -> Result<(), String> {
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => self.set_module(module.trim(), level.trim().parse()?),
+                None => match directive.parse() {
+                    Ok(level) => self.default = level,
+                    Err(_) => self.set_module(directive, Level::Trace),
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// level returns the least important level shown for the module. The level of the most
+    /// specific module containing it is used.
+    pub fn level(&self, module: &str) -> Level {
+        self.modules
+            .iter()
+            .filter(|(m, _)| {
+                module == m
+                    || (module.starts_with(m.as_str()) && module[m.len()..].starts_with("::"))
+            })
+            .max_by_key(|(m, _)| m.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    // max_level returns the least important level shown for any module.
+    fn max_level(&self) -> Level {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+// MAX_LEVEL is the least important level shown for any module. It lets events that no module
+// shows be skipped without taking the lock.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+// FILTER is the filter set by init.
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new(Level::Warn));
+
+/// init sets the filter used to decide which events are printed.
+pub fn init(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8, atomic::Ordering::Relaxed);
+    if let Ok(mut f) = FILTER.write() {
+        *f = filter;
+    }
+}
+
+/// configure builds the filter from the AOC_LOG environment variable, the number of times -v
+/// was given, and the --log flags, in that order so that later ones take precedence, and sets
+/// it with init.
+pub fn configure(verbosity: usize, specs: &[String]) -> Result<(), String> {
+    let mut filter = Filter::default();
+    if let Ok(spec) = env::var(ENV_VAR) {
+        filter
+            .apply(&spec)
+            .map_err(|e| format!("invalid {ENV_VAR}: {e}"))?;
+    }
+    if verbosity > 0 {
+        filter.set_default(Level::from_verbosity(verbosity));
+    }
+    for spec in specs {
+        filter.apply(spec)?;
+    }
+    init(filter);
+    Ok(())
+}
+
+/// enabled returns true if events at the level are printed for the module.
+pub fn enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(atomic::Ordering::Relaxed) {
+        return false;
+    }
+    FILTER.read().is_ok_and(|f| level <= f.level(module))
+}
+
+/// format_event returns the line printed for an event. Field values holding spaces, quotes or
+/// equals signs are quoted so that the line can be split back into fields.
+pub fn format_event(
+    level: Level,
+    module: &str,
+    name: &str,
+    fields: &[(&str, &dyn fmt::Display)],
+) -> String {
+    let mut s = format!("{:5} {module}: {name}", level.to_string().to_uppercase());
+    for (key, value) in fields {
+        let value = value.to_string();
+        if value.is_empty() || value.contains([' ', '"', '=']) {
+            s += &format!(" {key}={value:?}");
+        } else {
+            s += &format!(" {key}={value}");
+        }
+    }
+    s
+}
+
+/// emit prints an event to stderr. It is called by the event macro once the event is known to
+/// be enabled.
+pub fn emit(level: Level, module: &str, name: &str, fields: &[(&str, &dyn fmt::Display)]) {
+    eprintln!("{}", format_event(level, module, name, fields));
+}
+
+/// event records a structured event with a name and key=value fields if its level is enabled
+/// for the calling module. The fields are only evaluated when the event is printed. For example:
+///
+/// ```
+/// use aoc_common::event;
+/// use aoc_common::log::Level;
+///
+/// let stack = vec![1, 2, 3];
+/// event!(Level::Debug, "search", stack = stack.len(), depth = 2);
+/// ```
+#[macro_export]
+macro_rules! event {
+    ($level:expr, $name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::emit(
+                $level,
+                module_path!(),
+                $name,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Display)),*],
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() -> Result<(), String> {
+        let mut filter = Filter::default();
+        assert_eq!(filter.level("day16"), Level::Warn);
+
+        filter.apply("info,day16=trace,day23, day23::clique=debug")?;
+        assert_eq!(filter.level("day1"), Level::Info);
+        assert_eq!(filter.level("day16"), Level::Trace);
+        assert_eq!(filter.level("day16::paths"), Level::Trace);
+        assert_eq!(filter.level("day160"), Level::Info);
+        assert_eq!(filter.level("day23"), Level::Trace);
+        assert_eq!(filter.level("day23::clique::search"), Level::Debug);
+        assert_eq!(filter.max_level(), Level::Trace);
+
+        assert!(filter.apply("day16=loud").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verbosity() {
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(5), Level::Trace);
+    }
+
+    #[test]
+    fn test_format_event() {
+        assert_eq!(
+            format_event(
+                Level::Debug,
+                "day19",
+                "cache",
+                &[("hits", &3), ("rate", &0.75), ("design", &"bw r")]
+            ),
+            "DEBUG day19: cache hits=3 rate=0.75 design=\"bw r\""
+        );
+        assert_eq!(
+            format_event(Level::Info, "day6", "loop", &[]),
+            "INFO  day6: loop"
+        );
+    }
+}
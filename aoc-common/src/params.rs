@@ -0,0 +1,282 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::lines::Lines;
+
+/// Param declares a tunable puzzle parameter of a day along with its default value.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Param {
+    /// name is the name of the parameter in parameter files.
+    pub name: &'static str,
+
+    /// default is the value used when the parameter is not set.
+    pub default: &'static str,
+
+    /// help is a short description of the parameter.
+    pub help: &'static str,
+}
+
+impl Param {
+    /// flag returns the command line flag that sets the parameter, for example --max-cheat-length
+    /// for max_cheat_length.
+    pub fn flag(&self) -> String {
+        format!("--{}", self.name.replace('_', "-"))
+    }
+
+    /// env_var returns the environment variable that sets the parameter for the given day, for
+    /// example AOC_DAY14_WIDTH for day 14's width.
+    pub fn env_var(&self, day: u8) -> String {
+        format!("AOC_DAY{day}_{}", self.name.to_uppercase())
+    }
+}
+
+/// Params holds named puzzle parameters, such as grid sizes, that differ between the example
+/// and the real puzzle input. Days read their parameters with value, which falls back to the
+/// defaults declared in their PARAMS for parameters that are not set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// new returns an empty set of parameters.
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    /// read reads parameters from "name = value" lines. Empty lines and lines starting with '#'
+    /// are ignored.
+    pub fn read(r: impl BufRead) -> Result<Self, Error> {
+        let mut params = Params::new();
+        for line in Lines::new(r) {
+            let line = line?;
+            let t = line.token().trim();
+            if t.text.is_empty() || t.text.starts_with('#') {
+                continue;
+            }
+            let (name, value) = t.split_once("=")?;
+            let name = name.trim();
+            if name.text.is_empty() {
+                return Err(name.malformed("parameter name"));
+            }
+            params.set(name.text, value.trim().text);
+        }
+        Ok(params)
+    }
+
+    /// load reads parameters from a file of "name = value" lines like read.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let f = fs::File::open(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        Params::read(io::BufReader::new(f))
+    }
+
+    /// resolve returns the parameters of a day set by the file, if any, then by the day's
+    /// environment variables and then by flags, each overriding the ones before.
+    pub fn resolve(
+        day: u8,
+        specs: &[Param],
+        file: Option<&Path>,
+        flags: &Params,
+    ) -> Result<Self, Error> {
+        let mut params = match file {
+            Some(path) => Params::load(path)?,
+            None => Params::new(),
+        };
+        params.merge(&Params::from_env(day, specs));
+        params.merge(flags);
+        Ok(params)
+    }
+
+    /// from_vars returns the parameters in specs that are set by the day's environment variables.
+    /// lookup returns the value of an environment variable if it is set.
+    pub fn from_vars(day: u8, specs: &[Param], lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let mut params = Params::new();
+        for spec in specs {
+            if let Some(value) = lookup(&spec.env_var(day)) {
+                params.set(spec.name, &value);
+            }
+        }
+        params
+    }
+
+    /// from_env returns the parameters in specs that are set by the day's environment variables
+    /// in the environment of the process.
+    pub fn from_env(day: u8, specs: &[Param]) -> Self {
+        Params::from_vars(day, specs, |name| env::var(name).ok())
+    }
+
+    /// merge sets every parameter that is set in other, overriding existing values.
+    pub fn merge(&mut self, other: &Params) {
+        for (name, value) in &other.values {
+            self.set(name, value);
+        }
+    }
+
+    /// set sets the value of the parameter.
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// is_empty returns true if no parameters are set.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// value returns the value of the parameter named name in specs parsed as T, or its default
+    /// if it is not set. An error is returned if specs has no such parameter or the value is
+    /// invalid.
+    pub fn value<T: FromStr>(&self, specs: &[Param], name: &str) -> Result<T, Error> {
+        let spec = specs
+            .iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| Error::semantic(format!("undeclared parameter {name}")))?;
+        let v = self.values.get(name).map_or(spec.default, |v| v.as_str());
+        v.parse::<T>()
+            .map_err(|_| Error::semantic(format!("invalid value for parameter {name}: {v:?}")))
+    }
+
+    /// check returns an error if a parameter is set that is not declared in specs.
+    pub fn check(&self, specs: &[Param]) -> Result<(), Error> {
+        self.check_known(&specs.iter().map(|s| s.name).collect::<Vec<_>>())
+    }
+
+    /// check_known returns an error if a parameter is set that is not one of names.
+    pub fn check_known(&self, names: &[&str]) -> Result<(), Error> {
+        match self.values.keys().find(|k| !names.contains(&k.as_str())) {
+            Some(k) if names.is_empty() => Err(Error::semantic(format!(
+                "unknown parameter {k}: this day has no parameters"
+            ))),
+            Some(k) => Err(Error::semantic(format!(
+                "unknown parameter {k}: expected one of {}",
+                names.join(", ")
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Params {
+    // fmt writes the parameters as "name = value" lines that can be read back with read.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, value) in &self.values {
+            writeln!(f, "{name} = {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_read() -> Result<(), Error> {
+        let input = Bytes::from("# The example grid.\nwidth = 11\n\nheight=7\n");
+        let params = Params::read(input.reader())?;
+        assert_eq!(params.to_string(), "height = 7\nwidth = 11\n");
+        params.check_known(&["width", "height", "seconds"])?;
+        assert_eq!(Params::read(params.to_string().as_bytes())?, params);
+
+        let err = params.check_known(&["width"]).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "unknown parameter height: expected one of width"
+        );
+        Ok(())
+    }
+
+    const SPECS: &[Param] = &[
+        Param {
+            name: "width",
+            default: "101",
+            help: "width of the area",
+        },
+        Param {
+            name: "max_len",
+            default: "2",
+            help: "maximum length",
+        },
+    ];
+
+    #[test]
+    fn test_value() -> Result<(), Error> {
+        let mut params = Params::new();
+        params.set("width", "11");
+        params.check(SPECS)?;
+        assert_eq!(params.value::<usize>(SPECS, "width")?, 11);
+        assert_eq!(params.value::<usize>(SPECS, "max_len")?, 2);
+        assert!(params.value::<usize>(SPECS, "height").is_err());
+
+        params.set("height", "7");
+        let err = params.check(SPECS).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "unknown parameter height: expected one of width, max_len"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_vars() {
+        assert_eq!(SPECS[1].flag(), "--max-len");
+        assert_eq!(SPECS[1].env_var(20), "AOC_DAY20_MAX_LEN");
+
+        let mut params = Params::from_vars(20, SPECS, |name| match name {
+            "AOC_DAY20_WIDTH" => Some("11".to_string()),
+            "AOC_DAY20_MAX_LEN" => Some("20".to_string()),
+            _ => None,
+        });
+        let mut flags = Params::new();
+        flags.set("width", "7");
+        params.merge(&flags);
+        assert_eq!(params.to_string(), "max_len = 20\nwidth = 7\n");
+    }
+
+    #[test]
+    fn test_resolve() -> Result<(), Error> {
+        let path = std::env::temp_dir().join(format!("params-{}.txt", std::process::id()));
+        fs::write(&path, "width = 11\nmax_len = 3\n")?;
+        let mut flags = Params::new();
+        flags.set("max_len", "4");
+        let params = Params::resolve(20, SPECS, Some(&path), &flags);
+        fs::remove_file(&path)?;
+        assert_eq!(params?.to_string(), "max_len = 4\nwidth = 11\n");
+
+        let err = Params::resolve(20, SPECS, Some(&path), &flags).unwrap_err();
+        assert!(err.message().contains(&path.display().to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_invalid() {
+        let err = Params::read(Bytes::from("width 11\n").reader()).unwrap_err();
+        assert_eq!(err.line(), Some(1));
+
+        let mut params = Params::new();
+        params.set("width", "x");
+        let err = params.value::<usize>(SPECS, "width").unwrap_err();
+        assert_eq!(err.message(), "invalid value for parameter width: \"x\"");
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+/// Point is a location on a grid. The origin is the top left corner and y increases downwards.
+#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    /// new returns a new Point at the given coordinates.
+    pub const fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    /// checked_add_signed adds the given offset to the point. None is returned if the result would
+    /// have a negative coordinate or overflow.
+    pub fn checked_add_signed(&self, (dx, dy): (isize, isize)) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// step returns the point n steps away in the given direction. None is returned if the result
+    /// would have a negative coordinate or overflow.
+    pub fn step(&self, dir: Direction, n: usize) -> Option<Point> {
+        let n = isize::try_from(n).ok()?;
+        let (dx, dy) = dir.delta();
+        self.checked_add_signed((dx.checked_mul(n)?, dy.checked_mul(n)?))
+    }
+
+    /// manhattan returns the manhattan distance between two points.
+    pub fn manhattan(&self, other: &Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Direction is one of the eight compass directions on a grid.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    /// CARDINAL holds the four directions used for 4-connectivity.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// DIAGONAL holds the four diagonal directions.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    /// ALL holds all eight directions used for 8-connectivity.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    /// delta returns the (dx, dy) offset of a single step in this direction.
+    pub fn delta(&self) -> (isize, isize) {
+        match *self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+
+    /// clockwise returns the direction after turning 90 degrees clockwise.
+    pub fn clockwise(&self) -> Direction {
+        match *self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::UpLeft => Direction::UpRight,
+            Direction::UpRight => Direction::DownRight,
+            Direction::DownRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpLeft,
+        }
+    }
+
+    /// counter_clockwise returns the direction after turning 90 degrees counter-clockwise.
+    pub fn counter_clockwise(&self) -> Direction {
+        self.opposite().clockwise()
+    }
+
+    /// opposite returns the direction after turning 180 degrees.
+    pub fn opposite(&self) -> Direction {
+        self.clockwise().clockwise()
+    }
+
+    /// is_horizontal returns true if the direction is Left or Right.
+    pub fn is_horizontal(&self) -> bool {
+        matches!(*self, Direction::Left | Direction::Right)
+    }
+
+    /// is_vertical returns true if the direction is Up or Down.
+    pub fn is_vertical(&self) -> bool {
+        matches!(*self, Direction::Up | Direction::Down)
+    }
+
+    /// from_arrow returns the cardinal direction for an arrow character (^, v, <, or >).
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// arrow returns the arrow character for a cardinal direction. None is returned for
+    /// diagonal directions.
+    pub fn arrow(&self) -> Option<char> {
+        match *self {
+            Direction::Up => Some('^'),
+            Direction::Down => Some('v'),
+            Direction::Left => Some('<'),
+            Direction::Right => Some('>'),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.clockwise(), Direction::Right);
+        assert_eq!(Direction::Up.counter_clockwise(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::UpLeft.clockwise(), Direction::UpRight);
+        for dir in Direction::ALL {
+            assert_eq!(dir.clockwise().counter_clockwise(), dir);
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let p = Point::new(1, 1);
+        assert_eq!(p.step(Direction::Up, 1), Some(Point::new(1, 0)));
+        assert_eq!(p.step(Direction::DownRight, 3), Some(Point::new(4, 4)));
+        assert_eq!(p.step(Direction::Left, 2), None);
+        assert_eq!(p.manhattan(&Point::new(4, 0)), 4);
+    }
+
+    #[test]
+    fn test_arrow() {
+        for dir in Direction::CARDINAL {
+            assert_eq!(Direction::from_arrow(dir.arrow().unwrap()), Some(dir));
+        }
+        assert_eq!(Direction::from_arrow('x'), None);
+    }
+}
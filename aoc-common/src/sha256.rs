@@ -0,0 +1,243 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{self, Read};
+
+// K holds the round constants.
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+// H0 holds the initial hash value.
+const H0: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Sha256 computes the SHA-256 digest, as described in FIPS 180-4, of data given to it in
+/// pieces so that the data doesn't have to be held in memory whole.
+#[derive(Debug, Clone)]
+pub struct Sha256 {
+    // h holds the hash value of the blocks compressed so far.
+    h: [u32; 8],
+
+    // block holds the data of the current block that has not been compressed yet.
+    block: Vec<u8>,
+
+    // len is the length of the data in bytes.
+    len: u64,
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256 {
+    /// new returns a Sha256 that has not been given any data.
+    pub fn new() -> Self {
+        Sha256 {
+            h: H0,
+            block: Vec::with_capacity(64),
+            len: 0,
+        }
+    }
+
+    /// update adds data to the data whose digest is computed.
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len = self.len.wrapping_add(data.len() as u64);
+        while !data.is_empty() {
+            let n = (64 - self.block.len()).min(data.len());
+            self.block.extend_from_slice(&data[..n]);
+            data = &data[n..];
+            if self.block.len() == 64 {
+                compress(&mut self.h, &self.block);
+                self.block.clear();
+            }
+        }
+    }
+
+    /// finish returns the digest of the data.
+    pub fn finish(mut self) -> [u8; 32] {
+        // Pad the message with a single 1 bit, zeros, and the message length in bits so that its
+        // length is a multiple of 64 bytes.
+        let bits = self.len.wrapping_mul(8);
+        self.update(&[0x80]);
+        while self.block.len() != 56 {
+            self.update(&[0]);
+        }
+        self.update(&bits.to_be_bytes());
+
+        let mut out = [0; 32];
+        for (chunk, word) in out.chunks_exact_mut(4).zip(self.h) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        out
+    }
+
+    /// finish_hex returns the digest of the data as a lowercase hex string.
+    pub fn finish_hex(self) -> String {
+        hex(&self.finish())
+    }
+}
+
+/// Tee reads from a reader and computes the SHA-256 digest of the bytes as they are read, so
+/// that input can be parsed and its digest computed in a single pass.
+pub struct Tee<R> {
+    inner: R,
+    sha256: Sha256,
+}
+
+impl<R: Read> Tee<R> {
+    /// new returns a new Tee reading from r.
+    pub fn new(r: R) -> Self {
+        Tee {
+            inner: r,
+            sha256: Sha256::new(),
+        }
+    }
+
+    /// finish reads the rest of the input and returns the SHA-256 digest of all of it as a
+    /// lowercase hex string.
+    pub fn finish(mut self) -> io::Result<String> {
+        io::copy(&mut self, &mut io::sink())?;
+        Ok(self.sha256.finish_hex())
+    }
+}
+
+impl<R: Read> Read for Tee<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.sha256.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// digest returns the SHA-256 digest of data.
+pub fn digest(data: &[u8]) -> [u8; 32] {
+    let mut sha256 = Sha256::new();
+    sha256.update(data);
+    sha256.finish()
+}
+
+/// hex_digest returns the SHA-256 digest of data as a lowercase hex string.
+pub fn hex_digest(data: &[u8]) -> String {
+    hex(&digest(data))
+}
+
+// hex returns the digest as a lowercase hex string.
+fn hex(digest: &[u8; 32]) -> String {
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// compress processes a single 64 byte block.
+fn compress(h: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0_u32; 64];
+    for (i, word) in block.chunks_exact(4).enumerate() {
+        w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = *h;
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = hh
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+
+        hh = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+
+    for (x, y) in h.iter_mut().zip([a, b, c, d, e, f, g, hh]) {
+        *x = x.wrapping_add(y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digest() {
+        assert_eq!(
+            hex_digest(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex_digest(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // Two blocks of padding.
+        assert_eq!(
+            hex_digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        // Pieces of every length up to two blocks give the same digest as the whole data.
+        let data: Vec<u8> = (0..300).map(|i| (i * 7) as u8).collect();
+        for size in 1..=128 {
+            let mut sha256 = Sha256::new();
+            for chunk in data.chunks(size) {
+                sha256.update(chunk);
+            }
+            assert_eq!(sha256.finish(), digest(&data), "size {size}");
+        }
+    }
+
+    #[test]
+    fn test_tee() -> io::Result<()> {
+        let mut tee = Tee::new(io::BufReader::with_capacity(
+            4,
+            &b"abc
+def
+"[..],
+        ));
+        let mut line = [0; 4];
+        tee.read_exact(&mut line)?;
+        assert_eq!(&line, b"abc\n");
+        // The rest of the input is hashed even though it isn't read.
+        assert_eq!(tee.finish()?, hex_digest(b"abc\ndef\n"));
+        Ok(())
+    }
+}
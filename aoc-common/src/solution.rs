@@ -0,0 +1,277 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any;
+use std::error;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+use std::time;
+
+use crate::error::Error;
+
+/// Solution is implemented by each day's puzzle solution. Parsing is separate from solving so that
+/// each stage can be timed and the parsed input can be shared by both parts.
+pub trait Solution {
+    /// Input is the parsed puzzle input.
+    type Input;
+
+    /// Part1 is the type of the answer to part one.
+    type Part1: fmt::Display;
+
+    /// Part2 is the type of the answer to part two.
+    type Part2: fmt::Display;
+
+    /// parse reads and parses the puzzle input. Errors point at the offending input where
+    /// possible.
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error>;
+
+    /// part1 solves part one of the puzzle.
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>>;
+
+    /// part2 solves part two of the puzzle.
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>>;
+
+    /// solve parses the input and solves both parts.
+    fn solve(&self, r: impl BufRead) -> Result<(Self::Part1, Self::Part2), Box<dyn error::Error>> {
+        let input = self.parse(r)?;
+        Ok((self.part1(&input)?, self.part2(&input)?))
+    }
+}
+
+/// Part is one of the two parts of a puzzle.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// BOTH holds both parts in order.
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part: {s:?}")),
+        }
+    }
+}
+
+/// Answer is the answer to a single part and the time taken to solve it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+
+    /// type_name is the name of the answer's type without its module path, e.g. "i64" or
+    /// "Point".
+    pub type_name: String,
+
+    pub elapsed: time::Duration,
+}
+
+// short_type_name returns the name of T with module paths removed, e.g. "Vec<String>" rather
+// than "alloc::vec::Vec<alloc::string::String>".
+fn short_type_name<T>() -> String {
+    let mut name = String::new();
+    let mut path = String::new();
+    for c in any::type_name::<T>().chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+            continue;
+        }
+        name.push_str(path.rsplit("::").next().unwrap_or_default());
+        path.clear();
+        name.push(c);
+    }
+    name.push_str(path.rsplit("::").next().unwrap_or_default());
+    name
+}
+
+/// Report holds the results of running a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// parse_time is the time taken to parse the input.
+    pub parse_time: time::Duration,
+
+    /// answers holds the answers to the parts that were run in order.
+    pub answers: Vec<Answer>,
+
+    /// cached is true if the answers were read from the cache rather than solved. The times are
+    /// those taken when they were solved.
+    pub cached: bool,
+}
+
+impl Report {
+    /// answer returns the answer to the given part if it was run.
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.answers.iter().find(|a| a.part == part)
+    }
+}
+
+/// Runner runs a Solution without knowing its input and answer types. It is implemented for all
+/// solutions so that different days can be run through the same interface.
+pub trait Runner {
+    /// prepare parses the input. The parts can then be solved any number of times without
+    /// parsing the input again.
+    fn prepare(&self, r: &mut dyn BufRead) -> Result<Box<dyn Prepared + '_>, Error>;
+
+    /// run parses the input and solves the given parts.
+    fn run(&self, r: &mut dyn BufRead, parts: &[Part]) -> Result<Report, Box<dyn error::Error>> {
+        let start = time::Instant::now();
+        let prepared = self.prepare(r)?;
+        let parse_time = start.elapsed();
+
+        let answers = parts
+            .iter()
+            .map(|part| prepared.solve(*part))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Report {
+            parse_time,
+            answers,
+            cached: false,
+        })
+    }
+}
+
+/// Prepared is a parsed puzzle input returned by Runner::prepare.
+pub trait Prepared {
+    /// solve solves the given part of the puzzle.
+    fn solve(&self, part: Part) -> Result<Answer, Box<dyn error::Error>>;
+}
+
+// PreparedInput holds a solution along with its parsed input.
+struct PreparedInput<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Prepared for PreparedInput<'_, S> {
+    fn solve(&self, part: Part) -> Result<Answer, Box<dyn error::Error>> {
+        let start = time::Instant::now();
+        let (value, type_name) = match part {
+            Part::One => (
+                self.solution.part1(&self.input)?.to_string(),
+                short_type_name::<S::Part1>(),
+            ),
+            Part::Two => (
+                self.solution.part2(&self.input)?.to_string(),
+                short_type_name::<S::Part2>(),
+            ),
+        };
+        Ok(Answer {
+            part,
+            value,
+            type_name,
+            elapsed: start.elapsed(),
+        })
+    }
+}
+
+impl<S: Solution> Runner for S {
+    fn prepare(&self, r: &mut dyn BufRead) -> Result<Box<dyn Prepared + '_>, Error> {
+        Ok(Box::new(PreparedInput {
+            solution: self,
+            input: self.parse(r)?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lines::Lines;
+    use crate::point::Point;
+    use bytes::{Buf, Bytes};
+
+    // Sum sums the numbers on each line for part one and multiplies them for part two.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+        type Part1 = i64;
+        type Part2 = i64;
+
+        fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+            let mut nums = Vec::new();
+            for line in Lines::new(r) {
+                nums.push(line?.token().parse("integer")?);
+            }
+            Ok(nums)
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+            Ok(input.iter().product())
+        }
+    }
+
+    #[test]
+    fn test_solve() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("2\n3\n4\n");
+        assert_eq!(Sum.solve(input.reader())?, (9, 24));
+        Ok(())
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<Point>(), "Point");
+        assert_eq!(
+            short_type_name::<Vec<(String, Point)>>(),
+            "Vec<(String, Point)>"
+        );
+    }
+
+    #[test]
+    fn test_runner() -> Result<(), Box<dyn error::Error>> {
+        let runner: &dyn Runner = &Sum;
+        let mut input = Bytes::from("2\n3\n4\n").reader();
+
+        let report = runner.run(&mut input, &[Part::Two])?;
+        assert_eq!(report.answer(Part::One), None);
+        assert_eq!(
+            report.answer(Part::Two).map(|a| a.value.as_str()),
+            Some("24")
+        );
+        assert_eq!(
+            report.answer(Part::Two).map(|a| a.type_name.as_str()),
+            Some("i64")
+        );
+
+        let mut input = Bytes::from("2\n3\n4\n").reader();
+        let prepared = runner.prepare(&mut input)?;
+        assert_eq!(prepared.solve(Part::One)?.value, "9");
+        assert_eq!(prepared.solve(Part::One)?.value, "9");
+        Ok(())
+    }
+}
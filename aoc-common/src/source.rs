@@ -0,0 +1,92 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::sha256;
+
+// files appends the paths of the files under dir to paths.
+fn files(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files(&path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// digest returns the SHA-256 digest, as a hex string, of the names and contents of the files
+/// under the directories. It only changes when the files do.
+pub fn digest(dirs: &[PathBuf]) -> io::Result<String> {
+    let mut data = Vec::new();
+    for dir in dirs {
+        let mut paths = Vec::new();
+        files(dir, &mut paths)?;
+        paths.sort();
+        for path in paths {
+            let contents = fs::read(&path)?;
+            let name = path.strip_prefix(dir).unwrap_or(&path);
+            data.extend_from_slice(format!("{}\0{}\0", name.display(), contents.len()).as_bytes());
+            data.extend_from_slice(&contents);
+        }
+    }
+    Ok(sha256::hex_digest(&data))
+}
+
+/// emit_version is called by the build script of a day's crate. It sets the AOC_VERSION
+/// environment variable of the crate to its version followed by a digest of its sources and
+/// those of aoc-common, such as "0.1.0+3f2a9c0d41b7e865", so that the version changes whenever
+/// the code does. The build script is run again when the sources change.
+pub fn emit_version() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set");
+    let dirs = [
+        Path::new(&manifest_dir).join("src"),
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+    ];
+    for dir in &dirs {
+        println!("cargo:rerun-if-changed={}", dir.display());
+    }
+    let digest = digest(&dirs).expect("failed to read the sources");
+    let version = env::var("CARGO_PKG_VERSION").expect("CARGO_PKG_VERSION is not set");
+    println!("cargo:rustc-env=AOC_VERSION={version}+{}", &digest[..16]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digest() -> io::Result<()> {
+        let dir = env::temp_dir().join(format!("source-{}", std::process::id()));
+        fs::create_dir_all(dir.join("sub"))?;
+        fs::write(dir.join("lib.rs"), "pub mod sub;\n")?;
+        fs::write(dir.join("sub").join("mod.rs"), "pub fn f() {}\n")?;
+
+        let first = digest(std::slice::from_ref(&dir))?;
+        let same = digest(std::slice::from_ref(&dir))?;
+        fs::write(dir.join("sub").join("mod.rs"), "pub fn g() {}\n")?;
+        let changed = digest(std::slice::from_ref(&dir))?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(first, same);
+        assert_ne!(first, changed);
+        Ok(())
+    }
+}
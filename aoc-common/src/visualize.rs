@@ -0,0 +1,404 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time;
+
+use crate::grid::Grid;
+use crate::image::{self, Images, Sequence};
+use crate::point::{Direction, Point};
+use crate::solution::Solution;
+
+/// Color is one of the eight standard terminal colours.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// PALETTE holds the colours used to tell neighbouring things, such as regions, apart. Black
+    /// and white are left out so that the palette shows up on both dark and light terminals.
+    pub const PALETTE: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    // code returns the offset of the colour in the ANSI colour codes.
+    fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// Cell is a single character of a frame along with its colours.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Cell {
+    /// ch is the character shown in the cell.
+    pub ch: char,
+
+    /// fg is the colour of the character, if any.
+    pub fg: Option<Color>,
+
+    /// bg is the background colour of the cell, if any.
+    pub bg: Option<Color>,
+
+    /// bold shows the character in bold.
+    pub bold: bool,
+}
+
+impl Cell {
+    /// new returns a cell showing ch with no colours.
+    pub fn new(ch: char) -> Self {
+        Cell {
+            ch,
+            fg: None,
+            bg: None,
+            bold: false,
+        }
+    }
+
+    // escape returns the ANSI escape sequence that selects the cell's colours.
+    fn escape(&self) -> String {
+        let mut codes = vec!["0".to_string()];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(fg) = self.fg {
+            codes.push((30 + fg.code()).to_string());
+        }
+        if let Some(bg) = self.bg {
+            codes.push((40 + bg.code()).to_string());
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+/// Frame is a picture of a grid along with overlays such as paths, highlighted cells and actors,
+/// and caption lines shown below it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    captions: Vec<String>,
+}
+
+impl Frame {
+    /// from_grid returns a frame showing the grid with each cell drawn as the character returned
+    /// by f.
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> char) -> Self {
+        Frame {
+            cells: grid.map(|v| Cell::new(f(v))),
+            captions: Vec::new(),
+        }
+    }
+
+    /// from_chars returns a frame showing a grid of characters.
+    pub fn from_chars(grid: &Grid<char>) -> Self {
+        Frame::from_grid(grid, |c| *c)
+    }
+
+    /// blank returns a frame of the given size filled with ch.
+    pub fn blank(width: usize, height: usize, ch: char) -> Self {
+        Frame {
+            cells: Grid::new(width, height, Cell::new(ch)),
+            captions: Vec::new(),
+        }
+    }
+
+    /// cells returns the cells of the frame.
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// cell returns a mutable reference to the cell at p or None if it is out of bounds.
+    pub fn cell(&mut self, p: Point) -> Option<&mut Cell> {
+        self.cells.get_mut(p)
+    }
+
+    /// set changes the character at p. Points out of bounds are ignored.
+    pub fn set(&mut self, p: Point, ch: char) -> &mut Self {
+        if let Some(cell) = self.cells.get_mut(p) {
+            cell.ch = ch;
+        }
+        self
+    }
+
+    /// color sets the colour of the characters at the given points.
+    pub fn color(&mut self, points: impl IntoIterator<Item = Point>, color: Color) -> &mut Self {
+        for p in points {
+            if let Some(cell) = self.cells.get_mut(p) {
+                cell.fg = Some(color);
+            }
+        }
+        self
+    }
+
+    /// highlight sets the background colour of the cells at the given points.
+    pub fn highlight(
+        &mut self,
+        points: impl IntoIterator<Item = Point>,
+        color: Color,
+    ) -> &mut Self {
+        for p in points {
+            if let Some(cell) = self.cells.get_mut(p) {
+                cell.bg = Some(color);
+            }
+        }
+        self
+    }
+
+    /// path draws a path through the given points. Each point shows an arrow pointing at the
+    /// next point if it is a neighbour, and the last point is left as it is.
+    pub fn path(&mut self, points: &[Point], color: Color) -> &mut Self {
+        for pair in points.windows(2) {
+            let dir = Direction::CARDINAL
+                .into_iter()
+                .find(|d| pair[0].step(*d, 1) == Some(pair[1]));
+            if let Some(c) = dir.and_then(|d| d.arrow()) {
+                self.set(pair[0], c);
+            }
+        }
+        self.color(points.iter().copied(), color)
+    }
+
+    /// actor draws an actor, such as a guard or a robot, as a bold character at p.
+    pub fn actor(&mut self, p: Point, ch: char, color: Color) -> &mut Self {
+        if let Some(cell) = self.cells.get_mut(p) {
+            *cell = Cell {
+                ch,
+                fg: Some(color),
+                bg: cell.bg,
+                bold: true,
+            };
+        }
+        self
+    }
+
+    /// caption adds a line of text shown below the frame.
+    pub fn caption(&mut self, text: impl Into<String>) -> &mut Self {
+        self.captions.push(text.into());
+        self
+    }
+
+    /// render returns the frame as text with a line per row followed by the captions. Colours are
+    /// added with ANSI escape sequences if ansi is true.
+    pub fn render(&self, ansi: bool) -> String {
+        let mut s = String::new();
+        for row in self.cells.rows().take(self.cells.height()) {
+            let mut style = Cell::new(' ');
+            for cell in row {
+                let next = Cell { ch: ' ', ..*cell };
+                if ansi && next != style {
+                    s += &next.escape();
+                    style = next;
+                }
+                s.push(cell.ch);
+            }
+            if ansi && style != Cell::new(' ') {
+                s += "\x1b[0m";
+            }
+            s.push('\n');
+        }
+        for caption in &self.captions {
+            s += caption;
+            s.push('\n');
+        }
+        s
+    }
+}
+
+/// Playback controls how a Player moves from one frame to the next.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Playback {
+    /// Timed shows each frame for the given time.
+    Timed(time::Duration),
+
+    /// Step waits for a line of input after each frame. Entering q stops playback.
+    Step,
+}
+
+/// Player shows frames one after another.
+pub struct Player<'a> {
+    out: Box<dyn Write + 'a>,
+    keys: Option<Box<dyn BufRead + 'a>>,
+    images: Option<Sequence<'a>>,
+    playback: Playback,
+    ansi: bool,
+    frames: usize,
+    stopped: bool,
+}
+
+impl<'a> Player<'a> {
+    /// new returns a player that writes frames to out. If ansi is true, frames are drawn in
+    /// colour and the screen is cleared before each frame. Otherwise frames are separated by
+    /// blank lines.
+    pub fn new(out: impl Write + 'a, playback: Playback, ansi: bool) -> Self {
+        Player {
+            out: Box::new(out),
+            keys: None,
+            images: None,
+            playback,
+            ansi,
+            frames: 0,
+            stopped: false,
+        }
+    }
+
+    /// to_images returns a player that writes each frame as the next image of the sequence
+    /// rather than showing it, so that the frames can be made into an animation.
+    pub fn to_images(images: Sequence<'a>) -> Self {
+        Player {
+            images: Some(images),
+            ..Player::new(io::sink(), Playback::Step, false)
+        }
+    }
+
+    /// with_keys sets where Step playback reads its input from. Step playback doesn't wait
+    /// without it.
+    pub fn with_keys(mut self, keys: impl BufRead + 'a) -> Self {
+        self.keys = Some(Box::new(keys));
+        self
+    }
+
+    /// show shows the frame and waits before returning as set by the playback. Nothing is shown
+    /// once playback is stopped.
+    pub fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.stopped {
+            return Ok(());
+        }
+
+        if let Some(images) = &mut self.images {
+            self.frames += 1;
+            return images.ppm(&image::frame_image(frame));
+        }
+
+        if self.ansi {
+            write!(self.out, "\x1b[H\x1b[2J")?;
+        } else if self.frames > 0 {
+            writeln!(self.out)?;
+        }
+        write!(self.out, "{}", frame.render(self.ansi))?;
+        self.frames += 1;
+
+        match self.playback {
+            Playback::Timed(d) => {
+                self.out.flush()?;
+                thread::sleep(d);
+            }
+            Playback::Step => {
+                let keys = match &mut self.keys {
+                    Some(keys) => keys,
+                    None => return self.out.flush(),
+                };
+                write!(
+                    self.out,
+                    "frame {}: Enter for the next, q to quit ",
+                    self.frames
+                )?;
+                self.out.flush()?;
+                let mut line = String::new();
+                if keys.read_line(&mut line)? == 0 || line.trim() == "q" {
+                    self.stopped = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// stopped returns true if playback was stopped. Solutions should stop making frames once
+    /// it is.
+    pub fn stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// frames returns the number of frames shown.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+/// Visualize is implemented by solutions that can show how they solve a puzzle as a sequence of
+/// frames.
+pub trait Visualize: Solution {
+    /// visualize shows frames of the solution working on the input on the player.
+    fn visualize(
+        &self,
+        input: &Self::Input,
+        player: &mut Player,
+    ) -> Result<(), Box<dyn error::Error>>;
+
+    /// export writes still images of the solution, such as the answer drawn over the input, in
+    /// formats that other programs can open. Frames shown by visualize are written separately.
+    fn export(
+        &self,
+        _input: &Self::Input,
+        _images: &mut Images,
+    ) -> Result<(), Box<dyn error::Error>> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_render() {
+        let mut frame = Frame::blank(3, 2, '.');
+        frame
+            .path(
+                &[Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)],
+                Color::Yellow,
+            )
+            .actor(Point::new(2, 1), '@', Color::Red)
+            .caption("t=1");
+        assert_eq!(frame.render(false), ">v.\n..@\nt=1\n");
+        assert_eq!(
+            frame.render(true),
+            "\x1b[0;33m>v\x1b[0m.\n.\x1b[0;33m.\x1b[0;1;31m@\x1b[0m\nt=1\n"
+        );
+    }
+
+    #[test]
+    fn test_player() -> io::Result<()> {
+        let mut frame = Frame::blank(2, 1, '#');
+        let mut out = Vec::new();
+        let mut player =
+            Player::new(&mut out, Playback::Step, false).with_keys(Bytes::from("\nq\n").reader());
+        player.show(&frame)?;
+        frame.set(Point::new(0, 0), '.');
+        player.show(&frame)?;
+        assert!(player.stopped());
+        player.show(&frame)?;
+        assert_eq!(player.frames(), 2);
+        drop(player);
+        assert_eq!(
+            String::from_utf8_lossy(&out),
+            "##\nframe 1: Enter for the next, q to quit \n.#\nframe 2: Enter for the next, q to quit "
+        );
+        Ok(())
+    }
+}
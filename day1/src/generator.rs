@@ -0,0 +1,62 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of lines in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 1000;
+
+/// generate returns an input with size pairs of location IDs. Both lists are built in sorted
+/// order before being shuffled so that the pairs compared for part 1 are known, and about a third
+/// of the right list repeats IDs from the left list so that part 2 has a non-zero score.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut left: Vec<i64> = (0..size).map(|_| rng.range(10000..100000)).collect();
+    left.sort();
+
+    let mut right: Vec<i64> = (0..size)
+        .map(|_| {
+            if !left.is_empty() && rng.chance(1, 3) {
+                *rng.choose(&left)
+            } else {
+                rng.range(10000..100000)
+            }
+        })
+        .collect();
+    right.sort();
+
+    let distance: i64 = left.iter().zip(&right).map(|(l, r)| (l - r).abs()).sum();
+
+    let mut counts = collections::HashMap::new();
+    for r in &right {
+        *counts.entry(*r).or_insert(0) += 1;
+    }
+    let similarity: i64 = left.iter().map(|l| l * counts.get(l).unwrap_or(&0)).sum();
+
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+    let input = left
+        .iter()
+        .zip(&right)
+        .map(|(l, r)| format!("{l}   {r}\n"))
+        .collect();
+
+    Generated {
+        input,
+        part1: Some(distance.to_string()),
+        part2: Some(similarity.to_string()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,556 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reconcile;
+pub mod reference;
+pub mod sort;
+
+use std::collections;
+use std::error;
+use std::io::{self, BufRead};
+
+use aoc_common::log::Level;
+use aoc_common::{event, Error, Explain, Explanation, Line, Lines, Param, Params, Solution, Table};
+
+pub use reconcile::{Count, Pair, Reconciliation};
+pub use sort::{Budget, Sorted, Sorter, MIN_BUDGET};
+
+/// read_row reads the location IDs on a line, one for each list. columns is the number of lists
+/// given by the lines read so far, or None for the first line. An error is returned if a line
+/// has fewer than two IDs or a different number of IDs than the lines before it.
+pub fn read_row(line: &Line, columns: Option<usize>) -> Result<Vec<i64>, Error> {
+    let row = line
+        .fields()
+        .map(|v| v.parse::<i64>("location ID"))
+        .collect::<Result<Vec<_>, _>>()?;
+    match (row.len(), columns) {
+        (0, _) => Err(line.semantic("no left value")),
+        (1, None | Some(2)) => Err(line.semantic("no right value")),
+        (n, Some(columns)) if n != columns => {
+            Err(line.semantic(format!("expected {columns} location IDs, found {n}")))
+        }
+        _ => Ok(row),
+    }
+}
+
+/// read_columns reads the lists of location IDs, one per column, in input order. Every line
+/// must have the same number of IDs, at least two.
+pub fn read_columns(r: impl BufRead) -> Result<Vec<Vec<i64>>, Error> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+    for line in Lines::new(r) {
+        let row = read_row(&line?, columns.first().map(|_| columns.len()))?;
+        columns.resize_with(row.len(), Vec::new);
+        for (column, id) in columns.iter_mut().zip(row) {
+            column.push(id);
+        }
+    }
+    Ok(columns)
+}
+
+/// read_lists reads the left and right lists of location IDs in input order. Any other lists in
+/// the input are ignored.
+pub fn read_lists(r: impl BufRead) -> Result<(Vec<i64>, Vec<i64>), Error> {
+    let mut columns = read_columns(r)?.into_iter();
+    let first = columns.next().unwrap_or_default();
+    let second = columns.next().unwrap_or_default();
+    Ok((first, second))
+}
+
+/// read_sorted reads the lists of location IDs and sorts them. Each list gets an equal share of
+/// the memory budget and is sorted in temporary files if it doesn't fit. At least two lists are
+/// returned, which are empty if the input is. An error is returned if a share is less than
+/// MIN_BUDGET.
+pub fn read_sorted(r: impl BufRead, memory: Budget) -> Result<Vec<Sorted>, Error> {
+    let mut sorters: Vec<Sorter> = Vec::new();
+    for line in Lines::new(r) {
+        let row = read_row(&line?, sorters.first().map(|_| sorters.len()))?;
+        if sorters.is_empty() {
+            let share = Budget(memory.0 / row.len());
+            sorters = row
+                .iter()
+                .map(|_| Sorter::new(share).map_err(Error::semantic))
+                .collect::<Result<_, _>>()?;
+        }
+        for (sorter, id) in sorters.iter_mut().zip(row) {
+            sorter.push(id)?;
+        }
+    }
+    let columns = if sorters.is_empty() {
+        vec![Sorted::from(Vec::new()), Sorted::from(Vec::new())]
+    } else {
+        sorters
+            .into_iter()
+            .map(Sorter::finish)
+            .collect::<io::Result<Vec<_>>>()?
+    };
+    event!(
+        Level::Debug,
+        "sorted",
+        columns = columns.len(),
+        ids = columns[0].len(),
+        memory = memory,
+        runs = columns.iter().map(Sorted::runs).sum::<usize>()
+    );
+    Ok(columns)
+}
+
+/// total_distance returns the sum of the distances between the pairs of IDs at the same position
+/// in the two lists. Both lists must be sorted. An error is returned if the sum overflows.
+pub fn total_distance(first: &[i64], second: &[i64]) -> Result<i64, Box<dyn error::Error>> {
+    sorted_distance(
+        first.iter().copied().map(Ok),
+        second.iter().copied().map(Ok),
+    )
+}
+
+/// sorted_distance returns the total distance between two lists read as streams of IDs in sorted
+/// order. An error is returned if reading a list fails or the sum overflows.
+pub fn sorted_distance(
+    first: impl Iterator<Item = io::Result<i64>>,
+    second: impl Iterator<Item = io::Result<i64>>,
+) -> Result<i64, Box<dyn error::Error>> {
+    let mut total = 0_i64;
+    for (l, r) in first.zip(second) {
+        total = l?
+            .checked_sub(r?)
+            .and_then(i64::checked_abs)
+            .and_then(|d| total.checked_add(d))
+            .ok_or("total distance overflow")?;
+    }
+    Ok(total)
+}
+
+/// frequencies returns an index of the number of times each ID appears in the list.
+pub fn frequencies(list: &[i64]) -> collections::HashMap<i64, i64> {
+    let mut index = collections::HashMap::new();
+    for id in list {
+        *index.entry(*id).or_insert(0) += 1;
+    }
+    index
+}
+
+/// similarity_score returns the sum of each ID in the first list multiplied by the number of times
+/// it appears in the second list. The lists don't need to be sorted. An error is returned if the
+/// sum overflows.
+pub fn similarity_score(first: &[i64], second: &[i64]) -> Result<i64, Box<dyn error::Error>> {
+    let index = frequencies(second);
+    Ok(first
+        .iter()
+        .try_fold(0_i64, |acc, n| {
+            acc.checked_add(n.checked_mul(*index.get(n).unwrap_or(&0))?)
+        })
+        .ok_or("similarity score overflow")?)
+}
+
+/// SortedFrequencies counts the number of times each ID appears in a sorted stream of IDs. It
+/// returns each distinct ID in order with its count.
+pub struct SortedFrequencies<I> {
+    ids: I,
+    // next is the first ID of the next group, which was read while counting the previous one.
+    next: Option<i64>,
+}
+
+impl<I: Iterator<Item = io::Result<i64>>> SortedFrequencies<I> {
+    /// new returns the frequencies of the sorted IDs.
+    pub fn new(ids: I) -> Self {
+        SortedFrequencies { ids, next: None }
+    }
+}
+
+impl<I: Iterator<Item = io::Result<i64>>> Iterator for SortedFrequencies<I> {
+    type Item = io::Result<(i64, i64)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = match self.next.take() {
+            Some(id) => id,
+            None => match self.ids.next()? {
+                Ok(id) => id,
+                Err(e) => return Some(Err(e)),
+            },
+        };
+        let mut count = 1;
+        loop {
+            match self.ids.next() {
+                Some(Ok(next)) if next == id => count += 1,
+                Some(Ok(next)) => {
+                    self.next = Some(next);
+                    break;
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => break,
+            }
+        }
+        Some(Ok((id, count)))
+    }
+}
+
+/// sorted_similarity_score returns the similarity score of two lists read as streams of IDs in
+/// sorted order. The frequencies of the IDs in both lists are joined as they are read so that
+/// only one group of each is held at a time. An error is returned if reading a list fails or
+/// the sum overflows.
+pub fn sorted_similarity_score(
+    first: impl Iterator<Item = io::Result<i64>>,
+    second: impl Iterator<Item = io::Result<i64>>,
+) -> Result<i64, Box<dyn error::Error>> {
+    let mut right = SortedFrequencies::new(second);
+    let mut r = right.next().transpose()?;
+    let mut total = 0_i64;
+    for entry in SortedFrequencies::new(first) {
+        let (id, count) = entry?;
+        while let Some((r_id, _)) = r {
+            if r_id >= id {
+                break;
+            }
+            r = right.next().transpose()?;
+        }
+        if let Some((_, r_count)) = r.filter(|(r_id, _)| *r_id == id) {
+            total = id
+                .checked_mul(count)
+                .and_then(|n| n.checked_mul(r_count))
+                .and_then(|n| total.checked_add(n))
+                .ok_or("similarity score overflow")?;
+        }
+    }
+    Ok(total)
+}
+
+/// PARAMS declares the parameters of Day1 and their defaults.
+pub const PARAMS: &[Param] = &[Param {
+    name: "memory",
+    default: "256M",
+    help: "memory used to sort the lists, e.g. 64K; larger lists are sorted in temporary files",
+}];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day1 is the solution for day 1.
+pub struct Day1 {
+    /// memory is the memory used to sort the lists. Lists that don't fit are sorted in temporary
+    /// files.
+    pub memory: Budget,
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Day1::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day1 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        let memory: Budget = params.value(PARAMS, "memory")?;
+        // Each of at least two lists needs a share of the budget to sort.
+        if memory.0 / 2 < MIN_BUDGET.0 {
+            return Err(Error::semantic(format!(
+                "memory must be at least {} to sort two lists",
+                Budget(2 * MIN_BUDGET.0)
+            )));
+        }
+        Ok(Day1 { memory })
+    }
+}
+
+/// Matrix holds a value for every ordered pair of lists. `matrix[i][j]` is the value for the ith
+/// and jth lists.
+pub type Matrix = Vec<Vec<i64>>;
+
+/// distance_matrix returns the total distance between every ordered pair of the sorted lists.
+pub fn distance_matrix(columns: &[Sorted]) -> Result<Matrix, Box<dyn error::Error>> {
+    pairwise(columns, |a, b| sorted_distance(a.iter()?, b.iter()?))
+}
+
+/// similarity_matrix returns the similarity score of every ordered pair of the sorted lists.
+/// `matrix[i][j]` is the score of the ith list's IDs counted in the jth list.
+pub fn similarity_matrix(columns: &[Sorted]) -> Result<Matrix, Box<dyn error::Error>> {
+    pairwise(columns, |a, b| {
+        sorted_similarity_score(a.iter()?, b.iter()?)
+    })
+}
+
+// pairwise returns the matrix of f applied to every ordered pair of lists.
+fn pairwise(
+    columns: &[Sorted],
+    f: impl Fn(&Sorted, &Sorted) -> Result<i64, Box<dyn error::Error>>,
+) -> Result<Matrix, Box<dyn error::Error>> {
+    columns
+        .iter()
+        .map(|a| columns.iter().map(|b| f(a, b)).collect())
+        .collect()
+}
+
+/// matrix_table returns the matrix as a table with a row and column for each list, numbered
+/// from 1. The first cell of the header is the name of the matrix.
+pub fn matrix_table(name: &str, matrix: &Matrix) -> Table {
+    let mut table = Table::new(
+        std::iter::once(name.to_string()).chain((1..=matrix.len()).map(|j| j.to_string())),
+    );
+    for (i, row) in matrix.iter().enumerate() {
+        table.push(std::iter::once((i + 1).to_string()).chain(row.iter().map(|v| v.to_string())));
+    }
+    table
+}
+
+impl Solution for Day1 {
+    // Input holds each list of IDs in sorted order. The answers are found for the first two.
+    type Input = Vec<Sorted>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_sorted(r, self.memory)
+    }
+
+    fn part1(&self, columns: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        sorted_distance(columns[0].iter()?, columns[1].iter()?)
+    }
+
+    fn part2(&self, columns: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        sorted_similarity_score(columns[0].iter()?, columns[1].iter()?)
+    }
+}
+
+impl Explain for Day1 {
+    const EXPLANATIONS: &'static [Explanation] = &[
+        Explanation {
+            name: "matrix",
+            help: "the distance and similarity score of every ordered pair of lists",
+        },
+        Explanation {
+            name: "reconcile",
+            help: "each pair and left ID the answers are summed from, with summary statistics",
+        },
+    ];
+
+    fn explain(
+        &self,
+        columns: &Self::Input,
+        name: &str,
+    ) -> Result<Vec<Table>, Box<dyn error::Error>> {
+        match name {
+            "matrix" => Ok(vec![
+                matrix_table("distance", &distance_matrix(columns)?),
+                matrix_table("similarity", &similarity_matrix(columns)?),
+            ]),
+            "reconcile" => Reconciliation::new(&columns[0], &columns[1], self.memory)?.tables(),
+            _ => Err(format!("unknown explanation: {name:?}").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TableFormat;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+",
+        );
+
+        let (dist, sim) = Day1::default().solve(input.reader())?;
+        assert_eq!(dist, 11);
+        assert_eq!(sim, 31);
+        Ok(())
+    }
+
+    #[test]
+    fn test_external_sort() -> Result<(), Box<dyn error::Error>> {
+        let generated = generator::generate(&mut aoc_common::Rng::new(1), 5000);
+        let input = generated.input.as_bytes();
+
+        // 1K holds 32 IDs of each list in memory, so each list is written in 156 runs which are
+        // merged 2 at a time until 2 are left.
+        let day1 = Day1 {
+            memory: "1K".parse()?,
+        };
+        let columns = day1.parse(input)?;
+        assert_eq!((columns[0].runs(), columns[1].runs()), (2, 2));
+        assert_eq!(Some(day1.part1(&columns)?.to_string()), generated.part1);
+        assert_eq!(day1.solve(input)?, Day1::default().solve(input)?);
+
+        let (first, second) = read_lists(input)?;
+        assert_eq!(
+            Some(similarity_score(&first, &second)?.to_string()),
+            generated.part2
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_similarity_score() -> Result<(), Box<dyn error::Error>> {
+        let sorted = |ids: &[i64]| ids.iter().copied().map(Ok).collect::<Vec<_>>().into_iter();
+        let first = [1, 3, 3, 3, 4, 7];
+        let second = [0, 3, 3, 4, 5, 7, 7, 7];
+        assert_eq!(
+            sorted_similarity_score(sorted(&first), sorted(&second))?,
+            similarity_score(&first, &second)?,
+        );
+        assert_eq!(sorted_similarity_score(sorted(&first), sorted(&[]))?, 0);
+
+        let freq: Vec<_> = SortedFrequencies::new(sorted(&second)).collect::<io::Result<_>>()?;
+        assert_eq!(freq, [(0, 1), (3, 2), (4, 1), (5, 1), (7, 3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_columns() -> Result<(), Box<dyn error::Error>> {
+        let input = b"3   4   1\n4   3   1\n2   5   3\n";
+        let day1 = Day1::default();
+        assert_eq!(day1.solve(&input[..])?, (3, 7));
+        assert_eq!(read_columns(&input[..])?, [[3, 4, 2], [4, 3, 5], [1, 1, 3]]);
+
+        let columns = day1.parse(&input[..])?;
+        assert_eq!(
+            distance_matrix(&columns)?,
+            [[0, 3, 4], [3, 0, 7], [4, 7, 0]]
+        );
+        assert_eq!(
+            similarity_matrix(&columns)?,
+            [[9, 7, 3], [7, 12, 3], [3, 3, 7]]
+        );
+
+        let mut text = Vec::new();
+        let tables = day1.explain(&columns, "matrix")?;
+        TableFormat::Text.write(&mut text, &tables)?;
+        assert_eq!(
+            String::from_utf8(text)?,
+            "distance  1  2  3
+       1  0  3  4
+       2  3  0  7
+       3  4  7  0
+
+similarity  1   2  3
+         1  9   7  3
+         2  7  12  3
+         3  3   3  7
+"
+        );
+
+        let err = read_columns(&b"1 2 3\n4 5\n"[..]).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.message(), "expected 3 location IDs, found 2");
+        let err = day1.parse(&b"1 2\n4 5 6\n"[..]).unwrap_err();
+        assert_eq!(err.message(), "expected 2 location IDs, found 3");
+        Ok(())
+    }
+
+    #[test]
+    fn test_params() -> Result<(), Box<dyn error::Error>> {
+        let mut params = Params::new();
+        params.set("memory", "64M");
+        assert_eq!(Day1::from_params(&params)?.memory, Budget(64 << 20));
+        params.set("memory", "lots");
+        assert!(Day1::from_params(&params).is_err());
+        params.set("memory", "0");
+        assert!(Day1::from_params(&params).is_err());
+        params.set("memory", "95");
+        assert!(Day1::from_params(&params).is_err());
+        params.set("memory", "96");
+        assert_eq!(Day1::from_params(&params)?.memory, Budget(96));
+
+        // Inputs with more lists need more memory.
+        let day1 = Day1::from_params(&params)?;
+        let err = day1.parse(&b"1 2 3\n"[..]).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "memory budget 32 is too small to merge runs: at least 48 is needed"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "3   4
+4   3
+
+1   3
+3   9
+3   3
+",
+        );
+
+        match Day1::default().solve(input.reader()) {
+            Ok((_d, _s)) => Err("expected error".to_string()),
+            Err(e) => {
+                assert_eq!(e.to_string(), "no left value\n --> 3:1\n  |\n3 |\n  | ^");
+                Ok(())
+            }
+        }?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_right_value() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "3   4
+4   3
+3
+1   3
+3   9
+3   3
+",
+        );
+
+        match Day1::default().solve(input.reader()) {
+            Ok((_d, _s)) => Err("expected error".to_string()),
+            Err(e) => {
+                assert_eq!(e.to_string(), "no right value\n --> 3:1\n  |\n3 | 3\n  | ^");
+                Ok(())
+            }
+        }?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nan() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "3   4
+4   3
+a   b
+3   9
+3   3
+",
+        );
+
+        match Day1::default().solve(input.reader()) {
+            Ok((_d, _s)) => Err("expected error".to_string()),
+            Err(e) => {
+                assert_eq!(
+                    e.to_string(),
+                    "malformed token: expected location ID, found \"a\"\n --> 3:1\n  |\n3 | a   b\n  | ^"
+                );
+                Ok(())
+            }
+        }?;
+
+        Ok(())
+    }
+}
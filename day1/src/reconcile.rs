@@ -0,0 +1,348 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::mem;
+
+use aoc_common::Table;
+
+use crate::{Budget, Sorted, SortedFrequencies};
+
+// ROW is an upper bound on the memory used by a row of the report's tables, which have at most
+// four cells of up to 20 characters each.
+const ROW: usize = mem::size_of::<Vec<String>>() + 4 * (mem::size_of::<String>() + 20);
+
+// size returns an upper bound on the memory used to reconcile lists of the given lengths: each
+// pair, each ID, the gaps sorted to find the median and a table row for each pair and each ID.
+fn size(first: usize, second: usize) -> usize {
+    let pairs = first.min(second);
+    let ids = first.saturating_add(second);
+    pairs
+        .saturating_mul(mem::size_of::<Pair>() + mem::size_of::<i64>() + ROW)
+        .saturating_add(ids.saturating_mul(mem::size_of::<Count>() + ROW))
+}
+
+/// Pair is a pair of IDs at the same position in the sorted left and right lists.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Pair {
+    pub left: i64,
+    pub right: i64,
+
+    /// distance is the distance between the IDs.
+    pub distance: i64,
+}
+
+/// Count is the number of times an ID appears in each list.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Count {
+    pub id: i64,
+    pub left: i64,
+    pub right: i64,
+}
+
+impl Count {
+    /// similarity returns the ID's contribution to the similarity score, the ID multiplied by the
+    /// number of times it appears in each list. None is returned if it overflows.
+    pub fn similarity(&self) -> Option<i64> {
+        self.id.checked_mul(self.left)?.checked_mul(self.right)
+    }
+}
+
+/// Reconciliation breaks the total distance and similarity score of two lists down into the
+/// pairs and IDs they are summed from, so that answers that disagree can be compared line by
+/// line.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq)]
+pub struct Reconciliation {
+    /// pairs holds the pairs of IDs in sorted order.
+    pub pairs: Vec<Pair>,
+
+    /// counts holds every ID found in either list in sorted order.
+    pub counts: Vec<Count>,
+}
+
+impl Reconciliation {
+    /// new reconciles the sorted left and right lists. The report holds every pair and ID in
+    /// memory, so an error is returned if it could use more than the memory budget. An error is
+    /// also returned if reading a list fails or a distance overflows.
+    pub fn new(
+        first: &Sorted,
+        second: &Sorted,
+        memory: Budget,
+    ) -> Result<Self, Box<dyn error::Error>> {
+        let needed = size(first.len(), second.len());
+        if needed > memory.0 {
+            return Err(format!(
+                "reconciling lists of {} and {} IDs needs up to {} of memory, more than the \
+                 budget of {memory}",
+                first.len(),
+                second.len(),
+                Budget(needed.div_ceil(1 << 10) << 10),
+            )
+            .into());
+        }
+
+        let mut pairs = Vec::new();
+        for (l, r) in first.iter()?.zip(second.iter()?) {
+            let (left, right) = (l?, r?);
+            let distance = left
+                .checked_sub(right)
+                .and_then(i64::checked_abs)
+                .ok_or("distance overflow")?;
+            pairs.push(Pair {
+                left,
+                right,
+                distance,
+            });
+        }
+
+        // The frequencies of the IDs in both lists are joined like in sorted_similarity_score,
+        // keeping the IDs that are only in one of them.
+        let mut counts = Vec::new();
+        let mut left = SortedFrequencies::new(first.iter()?);
+        let mut right = SortedFrequencies::new(second.iter()?);
+        let mut l = left.next().transpose()?;
+        let mut r = right.next().transpose()?;
+        loop {
+            let order = match (l, r) {
+                (None, None) => break,
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (Some((l_id, _)), Some((r_id, _))) => l_id.cmp(&r_id),
+            };
+            let mut count = Count {
+                id: 0,
+                left: 0,
+                right: 0,
+            };
+            if let Some((id, n)) = l.filter(|_| order.is_le()) {
+                (count.id, count.left) = (id, n);
+                l = left.next().transpose()?;
+            }
+            if let Some((id, n)) = r.filter(|_| order.is_ge()) {
+                (count.id, count.right) = (id, n);
+                r = right.next().transpose()?;
+            }
+            counts.push(count);
+        }
+
+        Ok(Reconciliation { pairs, counts })
+    }
+
+    /// distance returns the total distance, or None if it overflows.
+    pub fn distance(&self) -> Option<i64> {
+        self.pairs
+            .iter()
+            .try_fold(0_i64, |acc, p| acc.checked_add(p.distance))
+    }
+
+    /// similarity returns the similarity score, or None if it overflows.
+    pub fn similarity(&self) -> Option<i64> {
+        self.counts
+            .iter()
+            .try_fold(0_i64, |acc, c| acc.checked_add(c.similarity()?))
+    }
+
+    /// max_gap returns the largest distance between a pair, or None if there are no pairs.
+    pub fn max_gap(&self) -> Option<i64> {
+        self.pairs.iter().map(|p| p.distance).max()
+    }
+
+    /// median_gap returns the median distance between a pair, or None if there are no pairs. The
+    /// mean of the two middle distances is returned if there is an even number of pairs.
+    pub fn median_gap(&self) -> Option<f64> {
+        let mut gaps: Vec<_> = self.pairs.iter().map(|p| p.distance).collect();
+        gaps.sort_unstable();
+        let mid = gaps.len() / 2;
+        match gaps.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(gaps[mid] as f64),
+            _ => Some((gaps[mid - 1] as f64 + gaps[mid] as f64) / 2.0),
+        }
+    }
+
+    /// left_only returns the IDs that are in the left list but not the right list.
+    pub fn left_only(&self) -> impl Iterator<Item = &Count> {
+        self.counts.iter().filter(|c| c.right == 0)
+    }
+
+    /// right_only returns the IDs that are in the right list but not the left list.
+    pub fn right_only(&self) -> impl Iterator<Item = &Count> {
+        self.counts.iter().filter(|c| c.left == 0)
+    }
+
+    /// tables returns the report as tables: each pair with its distance, each ID in the left list
+    /// with its count in the right list and its contribution to the similarity score, the IDs
+    /// only in one list, and a summary. An error is returned if a total overflows.
+    pub fn tables(&self) -> Result<Vec<Table>, Box<dyn error::Error>> {
+        let mut pairs = Table::new(["pair", "left", "right", "distance"]);
+        for (i, p) in self.pairs.iter().enumerate() {
+            pairs.push([i as i64 + 1, p.left, p.right, p.distance]);
+        }
+
+        let mut similarity = Table::new(["id", "left count", "right count", "similarity"]);
+        for c in self.counts.iter().filter(|c| c.left > 0) {
+            let score = c.similarity().ok_or("similarity score overflow")?;
+            similarity.push([c.id, c.left, c.right, score]);
+        }
+
+        let mut one_side = Table::new(["only in", "id", "count"]);
+        for c in self.counts.iter().filter(|c| c.left == 0 || c.right == 0) {
+            match c.left {
+                0 => one_side.push(["right".to_string(), c.id.to_string(), c.right.to_string()]),
+                _ => one_side.push(["left".to_string(), c.id.to_string(), c.left.to_string()]),
+            }
+        }
+
+        let none = || "-".to_string();
+        let mut summary = Table::new(["summary", "value"]);
+        summary.push(["pairs".to_string(), self.pairs.len().to_string()]);
+        summary.push([
+            "distance".to_string(),
+            self.distance()
+                .ok_or("total distance overflow")?
+                .to_string(),
+        ]);
+        summary.push([
+            "similarity".to_string(),
+            self.similarity()
+                .ok_or("similarity score overflow")?
+                .to_string(),
+        ]);
+        summary.push([
+            "max gap".to_string(),
+            self.max_gap().map_or_else(none, |g| g.to_string()),
+        ]);
+        summary.push([
+            "median gap".to_string(),
+            self.median_gap().map_or_else(none, |g| g.to_string()),
+        ]);
+        summary.push([
+            "left only".to_string(),
+            self.left_only().count().to_string(),
+        ]);
+        summary.push([
+            "right only".to_string(),
+            self.right_only().count().to_string(),
+        ]);
+
+        Ok(vec![pairs, similarity, one_side, summary])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TableFormat;
+
+    // reconcile returns the reconciliation of the unsorted lists.
+    fn reconcile(first: &[i64], second: &[i64]) -> Reconciliation {
+        Reconciliation::new(
+            &Sorted::from(first.to_vec()),
+            &Sorted::from(second.to_vec()),
+            Budget(1 << 20),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reconcile() -> Result<(), Box<dyn error::Error>> {
+        let report = reconcile(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+        assert_eq!(report.distance(), Some(11));
+        assert_eq!(report.similarity(), Some(31));
+        assert_eq!(report.max_gap(), Some(5));
+        assert_eq!(report.median_gap(), Some(1.5));
+        let ids = |counts: Vec<&Count>| counts.iter().map(|c| c.id).collect::<Vec<_>>();
+        assert_eq!(ids(report.left_only().collect()), [1, 2]);
+        assert_eq!(ids(report.right_only().collect()), [5, 9]);
+
+        let mut text = Vec::new();
+        TableFormat::Text.write(&mut text, &report.tables()?)?;
+        assert_eq!(
+            String::from_utf8(text)?,
+            "pair  left  right  distance
+   1     1      3         2
+   2     2      3         1
+   3     3      3         0
+   4     3      4         1
+   5     3      5         2
+   6     4      9         5
+
+id  left count  right count  similarity
+ 1           1            0           0
+ 2           1            0           0
+ 3           3            3          27
+ 4           1            1           4
+
+only in  id  count
+left      1      1
+left      2      1
+right     5      1
+right     9      1
+
+summary     value
+pairs           6
+distance       11
+similarity     31
+max gap         5
+median gap    1.5
+left only       2
+right only      2
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty() -> Result<(), Box<dyn error::Error>> {
+        let report = reconcile(&[], &[]);
+        assert_eq!(report.max_gap(), None);
+        assert_eq!(report.median_gap(), None);
+
+        let mut csv = Vec::new();
+        TableFormat::Csv.write(&mut csv, &report.tables()?[3..])?;
+        assert_eq!(
+            String::from_utf8(csv)?,
+            "summary,value
+pairs,0
+distance,0
+similarity,0
+max gap,-
+median gap,-
+left only,0
+right only,0
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_budget() -> Result<(), Box<dyn error::Error>> {
+        let first = Sorted::from((0..100).collect::<Vec<_>>());
+        let second = Sorted::from((0..100).rev().collect::<Vec<_>>());
+        let err = Reconciliation::new(&first, &second, Budget(16 << 10)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "reconciling lists of 100 and 100 IDs needs up to {} of memory, more than the \
+                 budget of 16K",
+                Budget(size(100, 100).div_ceil(1 << 10) << 10)
+            )
+        );
+
+        let report = Reconciliation::new(&first, &second, Budget(size(100, 100)))?;
+        assert_eq!(report.distance(), Some(0));
+        assert_eq!(report.counts.len(), 100);
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+/// Reference is a slow but simple solution for day 1 that Day1 is checked against.
+pub struct Reference;
+
+impl Solution for Reference {
+    // The lists are kept in input order so that sorting is checked too.
+    type Input = (Vec<i64>, Vec<i64>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_lists(r)
+    }
+
+    fn part1(&self, (left, right): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+        left.sort();
+        right.sort();
+
+        let total: i128 = left
+            .iter()
+            .zip(&right)
+            .map(|(l, r)| (i128::from(*l) - i128::from(*r)).abs())
+            .sum();
+        Ok(i64::try_from(total).map_err(|_| "total distance overflow")?)
+    }
+
+    fn part2(&self, (left, right): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut total: i128 = 0;
+        for l in left {
+            let count = right.iter().filter(|r| *r == l).count();
+            total += i128::from(*l) * count as i128;
+        }
+        Ok(i64::try_from(total).map_err(|_| "similarity score overflow")?)
+    }
+}
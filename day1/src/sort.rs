@@ -0,0 +1,411 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process;
+use std::slice;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// WORD is the number of bytes an ID takes in memory and in a run file.
+const WORD: usize = std::mem::size_of::<i64>();
+
+/// Budget is an amount of memory in bytes. It is parsed from a number of bytes with an optional
+/// K, M or G suffix for kibibytes, mebibytes or gibibytes, e.g. "512M".
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Budget(pub usize);
+
+impl FromStr for Budget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (n, shift) = match s.char_indices().last() {
+            Some((i, 'K' | 'k')) => (&s[..i], 10),
+            Some((i, 'M' | 'm')) => (&s[..i], 20),
+            Some((i, 'G' | 'g')) => (&s[..i], 30),
+            _ => (s, 0),
+        };
+        n.parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_mul(1 << shift))
+            .map(Budget)
+            .ok_or_else(|| format!("invalid memory budget: {s:?}"))
+    }
+}
+
+impl fmt::Display for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (suffix, shift) in [("G", 30), ("M", 20), ("K", 10)] {
+            if self.0 >= 1 << shift && self.0.is_multiple_of(1 << shift) {
+                return write!(f, "{}{suffix}", self.0 >> shift);
+            }
+        }
+        write!(f, "{}", self.0)
+    }
+}
+
+// MIN_FAN_IN is the fewest runs merged at once. Merging fewer would never finish.
+const MIN_FAN_IN: usize = 2;
+
+// BLOCK is the largest buffer used to read or write a run file.
+const BLOCK: usize = 8 << 10;
+
+// DEFAULT_OPEN_FILES is the limit on open files assumed where it can't be read. It is the lowest
+// default of common systems.
+const DEFAULT_OPEN_FILES: usize = 256;
+
+/// MIN_BUDGET is the smallest memory budget of a Sorter. Half of the budget holds the IDs sorted
+/// in memory and the other half holds the buffers of a merge step: at least two runs being read
+/// and the run being written, each buffering at least one ID.
+pub const MIN_BUDGET: Budget = Budget(2 * (MIN_FAN_IN + 1) * WORD);
+
+/// Sorter sorts a list of IDs that may not fit in memory. IDs are kept in a buffer until it uses
+/// half of the memory budget, then the buffer is sorted and written to a temporary file as a run.
+/// When the list is finished, runs are merged into longer runs in passes until few enough are
+/// left to be merged as the sorted list is read. The buffers of the run files being read and
+/// written come out of the other half of the budget, which limits how many runs are merged at
+/// once along with the limit on open files.
+pub struct Sorter {
+    // capacity is the number of IDs kept in memory.
+    capacity: usize,
+
+    // block is the size in bytes of the buffer of each run file being read or written.
+    block: usize,
+
+    // fan_in is the number of runs merged at once.
+    fan_in: usize,
+
+    buf: Vec<i64>,
+    runs: Vec<Run>,
+}
+
+impl Sorter {
+    /// new returns a sorter that uses up to budget bytes of memory. An error is returned if the
+    /// budget is less than MIN_BUDGET.
+    pub fn new(budget: Budget) -> Result<Self, String> {
+        if budget < MIN_BUDGET {
+            return Err(format!(
+                "memory budget {budget} is too small to merge runs: at least {MIN_BUDGET} is needed"
+            ));
+        }
+        let half = budget.0 / 2;
+        let block = (half / (MIN_FAN_IN + 1)).min(BLOCK) / WORD * WORD;
+        Ok(Sorter {
+            capacity: half / WORD,
+            block,
+            fan_in: (half / block - 1).min(max_fan_in()).max(MIN_FAN_IN),
+            buf: Vec::new(),
+            runs: Vec::new(),
+        })
+    }
+
+    /// push adds an ID to the list. An error is returned if a run couldn't be written.
+    pub fn push(&mut self, id: i64) -> io::Result<()> {
+        if self.buf.len() == self.buf.capacity() {
+            // Grow the buffer no further than the budget rather than doubling it.
+            let len = self.buf.len();
+            self.buf.reserve_exact(len.clamp(1, self.capacity - len));
+        }
+        self.buf.push(id);
+        if self.buf.len() >= self.capacity {
+            self.buf.sort_unstable();
+            let ids = self.buf.iter().copied().map(Ok);
+            self.runs.push(Run::write(ids, self.buf.len(), self.block)?);
+            self.buf.clear();
+        }
+        Ok(())
+    }
+
+    /// finish returns the sorted list. A list that fits in the budget is never written to disk.
+    /// An error is returned if the runs couldn't be merged.
+    pub fn finish(mut self) -> io::Result<Sorted> {
+        self.buf.sort_unstable();
+        while self.runs.len() > self.fan_in {
+            let mut merged = Vec::with_capacity(self.runs.len().div_ceil(self.fan_in));
+            for runs in self.runs.chunks(self.fan_in) {
+                let len = runs.iter().map(|r| r.len).sum();
+                let sources = runs
+                    .iter()
+                    .map(|r| r.open(self.block))
+                    .collect::<io::Result<_>>()?;
+                merged.push(Run::write(Merge::new(sources)?, len, self.block)?);
+            }
+            // The merged runs are removed as they are replaced.
+            self.runs = merged;
+        }
+        Ok(Sorted {
+            len: self.buf.len() + self.runs.iter().map(|r| r.len).sum::<usize>(),
+            memory: self.buf,
+            runs: self.runs,
+            block: self.block,
+        })
+    }
+}
+
+// max_fan_in returns the most runs that may be merged at once given the limit on open files. Two
+// lists are merged at the same time, and the rest of the limit is left for the process's other
+// files.
+fn max_fan_in() -> usize {
+    open_files_limit() / 4
+}
+
+// open_files_limit returns the soft limit on the number of files the process may have open. It
+// is read from /proc/self/limits where it exists.
+fn open_files_limit() -> usize {
+    fs::read_to_string("/proc/self/limits")
+        .ok()
+        .and_then(|limits| {
+            let line = limits.lines().find(|l| l.starts_with("Max open files"))?;
+            line.split_whitespace().nth(3)?.parse().ok()
+        })
+        .unwrap_or(DEFAULT_OPEN_FILES)
+}
+
+/// Sorted is a sorted list of IDs held in memory and, if it didn't fit in the memory budget, in
+/// sorted runs in temporary files. The files are removed when it is dropped.
+#[derive(Debug)]
+pub struct Sorted {
+    memory: Vec<i64>,
+    runs: Vec<Run>,
+    len: usize,
+
+    // block is the size in bytes of the buffer of each run file being read.
+    block: usize,
+}
+
+impl Sorted {
+    /// len returns the number of IDs in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// is_empty returns true if the list has no IDs.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// runs returns the number of runs in temporary files that are merged as the list is read.
+    pub fn runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// iter returns the IDs in sorted order. An error is returned if a run can't be read.
+    pub fn iter(&self) -> io::Result<Merge<'_>> {
+        let mut sources = vec![Source::Memory(self.memory.iter())];
+        for run in &self.runs {
+            sources.push(run.open(self.block)?);
+        }
+        Merge::new(sources)
+    }
+}
+
+impl From<Vec<i64>> for Sorted {
+    fn from(mut ids: Vec<i64>) -> Self {
+        ids.sort_unstable();
+        Sorted {
+            len: ids.len(),
+            memory: ids,
+            runs: Vec::new(),
+            block: BLOCK,
+        }
+    }
+}
+
+// NEXT_RUN numbers the run files written by the process so that their names are unique.
+static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+
+// Run is a sorted run of IDs in a temporary file. The file is removed when the run is dropped.
+#[derive(Debug)]
+struct Run {
+    path: PathBuf,
+    len: usize,
+}
+
+impl Run {
+    // write writes len sorted IDs to a new temporary file through a buffer of block bytes.
+    fn write(
+        ids: impl Iterator<Item = io::Result<i64>>,
+        len: usize,
+        block: usize,
+    ) -> io::Result<Run> {
+        let n = NEXT_RUN.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("day1-run-{}-{n}", process::id()));
+        let f = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        // The run is created before writing so that the file is removed if writing fails.
+        let run = Run { path, len };
+        let mut w = BufWriter::with_capacity(block, f);
+        for id in ids {
+            w.write_all(&id?.to_le_bytes())?;
+        }
+        w.flush()?;
+        Ok(run)
+    }
+
+    // open returns a source reading the run through a buffer of block bytes.
+    fn open(&self, block: usize) -> io::Result<Source<'static>> {
+        Ok(Source::File {
+            r: BufReader::with_capacity(block, fs::File::open(&self.path)?),
+            remaining: self.len,
+        })
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// Source is a sorted list of IDs being merged.
+enum Source<'a> {
+    Memory(slice::Iter<'a, i64>),
+    File {
+        r: BufReader<fs::File>,
+        remaining: usize,
+    },
+}
+
+impl Source<'_> {
+    // next returns the next ID or None at the end of the list.
+    fn next(&mut self) -> io::Result<Option<i64>> {
+        match self {
+            Source::Memory(ids) => Ok(ids.next().copied()),
+            Source::File { remaining: 0, .. } => Ok(None),
+            Source::File { r, remaining } => {
+                let mut buf = [0; WORD];
+                r.read_exact(&mut buf)?;
+                *remaining -= 1;
+                Ok(Some(i64::from_le_bytes(buf)))
+            }
+        }
+    }
+}
+
+/// Merge returns the IDs of a Sorted list in order by merging its runs.
+pub struct Merge<'a> {
+    sources: Vec<Source<'a>>,
+    // heap holds the next ID of each source that has one along with the source's index.
+    heap: BinaryHeap<Reverse<(i64, usize)>>,
+    failed: bool,
+}
+
+impl<'a> Merge<'a> {
+    // new returns a merge of the sources. An error is returned if a source can't be read.
+    fn new(mut sources: Vec<Source<'a>>) -> io::Result<Self> {
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (i, source) in sources.iter_mut().enumerate() {
+            if let Some(id) = source.next()? {
+                heap.push(Reverse((id, i)));
+            }
+        }
+        Ok(Merge {
+            sources,
+            heap,
+            failed: false,
+        })
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let Reverse((id, i)) = self.heap.pop()?;
+        match self.sources[i].next() {
+            Ok(Some(next)) => self.heap.push(Reverse((next, i))),
+            Ok(None) => {}
+            Err(e) => {
+                self.failed = true;
+                return Some(Err(e));
+            }
+        }
+        Some(Ok(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget() {
+        assert_eq!("4096".parse(), Ok(Budget(4096)));
+        assert_eq!("64K".parse(), Ok(Budget(64 << 10)));
+        assert_eq!("256M".parse(), Ok(Budget(256 << 20)));
+        assert_eq!("2g".parse(), Ok(Budget(2 << 30)));
+        assert!("M".parse::<Budget>().is_err());
+        assert!("-1K".parse::<Budget>().is_err());
+        assert_eq!(Budget(256 << 20).to_string(), "256M");
+        assert_eq!(Budget(1000).to_string(), "1000");
+    }
+
+    #[test]
+    fn test_sort() -> io::Result<()> {
+        let ids: Vec<i64> = (0..1000).map(|i| (i * 7919) % 1009 - 500).collect();
+        let mut expected = ids.clone();
+        expected.sort();
+
+        // Half of 64 bytes holds 4 IDs in memory and the buffers of a merge of 3 runs, so the
+        // 250 runs written are merged in 5 passes into 84, 28, 10, 4 and finally 2 runs.
+        let mut sorter = Sorter::new(Budget(64)).map_err(io::Error::other)?;
+        assert_eq!((sorter.capacity, sorter.block, sorter.fan_in), (4, 8, 3));
+        for id in &ids {
+            sorter.push(*id)?;
+        }
+        assert_eq!(sorter.runs.len(), 250);
+        let sorted = sorter.finish()?;
+        assert_eq!(sorted.len(), 1000);
+        assert_eq!(sorted.runs(), 2);
+        assert_eq!(sorted.iter()?.collect::<io::Result<Vec<_>>>()?, expected);
+        // The list can be read again.
+        assert_eq!(sorted.iter()?.count(), 1000);
+
+        let paths: Vec<_> = sorted.runs.iter().map(|r| r.path.clone()).collect();
+        assert!(paths.iter().all(|p| p.exists()));
+        drop(sorted);
+        assert!(paths.iter().all(|p| !p.exists()));
+
+        let sorted = Sorted::from(ids);
+        assert_eq!(sorted.runs(), 0);
+        assert_eq!(sorted.iter()?.collect::<io::Result<Vec<_>>>()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_budget_limits() -> Result<(), String> {
+        assert!(Sorter::new(Budget(0)).is_err());
+        assert!(Sorter::new(Budget(MIN_BUDGET.0 - 1)).is_err());
+        let sorter = Sorter::new(MIN_BUDGET)?;
+        assert_eq!((sorter.capacity, sorter.fan_in), (3, 2));
+
+        // Large budgets use the largest buffers and are limited by the open files.
+        let sorter = Sorter::new("256M".parse()?)?;
+        assert_eq!(sorter.block, BLOCK);
+        assert_eq!(sorter.fan_in, max_fan_in());
+        assert!(max_fan_in() >= MIN_FAN_IN);
+        Ok(())
+    }
+}
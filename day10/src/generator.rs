@@ -0,0 +1,51 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Grid, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 50;
+
+/// generate returns a size by size topographic map. Heights spread out from a few random peaks
+/// and valleys, with each new location one higher or lower than, or level with, a neighbor, so
+/// the map has long slopes that form hiking trails. The answers are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    if size == 0 {
+        return Generated::default();
+    }
+
+    let mut map: Grid<Option<i64>> = Grid::new(size, size, None);
+    let mut frontier = Vec::new();
+    for _ in 0..size / 5 + 1 {
+        let p = Point::new(rng.below(size), rng.below(size));
+        map[p] = Some(if rng.chance(1, 2) { 0 } else { 9 });
+        frontier.push(p);
+    }
+
+    while !frontier.is_empty() {
+        let p = frontier.swap_remove(rng.below(frontier.len()));
+        let h = map[p].unwrap_or_default();
+        for (_, n) in map.neighbors4(p).collect::<Vec<_>>() {
+            if map[n].is_none() {
+                map[n] = Some((h + rng.range(-1..2)).clamp(0, 9));
+                frontier.push(n);
+            }
+        }
+    }
+
+    Generated {
+        input: map.map(|h| h.unwrap_or_default().to_string()).to_string(),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Point, Solution};
+
+/// read_map reads the topographic map. Each cell holds its height, or None for impassable tiles
+/// marked with '.'.
+pub fn read_map(r: impl BufRead) -> Result<Grid<Option<i64>>, Error> {
+    // Impassable tiles are marked with '.' in the examples.
+    Grid::read_with(r, |c, t| match c {
+        '.' => Ok(None),
+        _ => c
+            .to_digit(10)
+            .map(|h| Some(i64::from(h)))
+            .ok_or_else(|| t.invalid_char("height or '.'")),
+    })
+}
+
+/// find_trails returns the number of distinct heights of 9 reachable from start by hiking trails
+/// that go up by one at every step, and the number of distinct such trails. Both are 0 unless
+/// start has a height of 0.
+pub fn find_trails(map: &Grid<Option<i64>>, start: Point) -> (usize, usize) {
+    let mut distinct_trails = 0;
+    let mut unique_trailends = collections::HashSet::new();
+
+    let mut stack: collections::VecDeque<(Point, i64)> = collections::VecDeque::new();
+    stack.push_back((start, 0));
+
+    while !stack.is_empty() {
+        let (cur, expected_num) = stack.pop_back().unwrap();
+        if map[cur] != Some(expected_num) {
+            continue;
+        }
+        if expected_num == 9 {
+            distinct_trails += 1;
+            unique_trailends.insert(cur);
+            continue;
+        }
+
+        for (_dir, next) in map.neighbors4(cur) {
+            stack.push_back((next, expected_num + 1));
+        }
+    }
+
+    (unique_trailends.len(), distinct_trails)
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day10 is the solution for day 10.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<Option<i64>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_map(r)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        // The score of a trailhead is the number of unique trail ends reachable from it.
+        Ok(map.points().map(|p| find_trails(map, p).0).sum())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        // The rating of a trailhead is the number of distinct trails starting from it.
+        Ok(map.points().map(|p| find_trails(map, p).1).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+",
+        );
+
+        let (n, n2) = Day10.solve(input.reader())?;
+        assert_eq!(n, 36);
+        assert_eq!(n2, 81);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Point, Solution};
+
+/// Reference is a slow but simple solution for day 10 that Day10 is checked against. It lists every
+/// trail from every trailhead.
+pub struct Reference;
+
+// trails returns the end of every trail that starts at p with height h.
+fn trails(map: &Grid<Option<i64>>, p: Point, h: i64) -> Vec<Point> {
+    if map[p] != Some(h) {
+        return Vec::new();
+    }
+    if h == 9 {
+        return vec![p];
+    }
+    map.neighbors4(p)
+        .flat_map(|(_, next)| trails(map, next, h + 1))
+        .collect()
+}
+
+impl Solution for Reference {
+    type Input = Grid<Option<i64>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_map(r)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(map
+            .points()
+            .map(|p| {
+                trails(map, p, 0)
+                    .into_iter()
+                    .collect::<collections::HashSet<_>>()
+                    .len()
+            })
+            .sum())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(map.points().map(|p| trails(map, p, 0).len()).sum())
+    }
+}
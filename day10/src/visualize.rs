@@ -0,0 +1,99 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+
+use aoc_common::{Color, Frame, Grid, Player, Point, Visualize};
+
+use crate::{find_trails, Day10};
+
+// trail_cells returns the cells on the hiking trails from start to a height of 9.
+fn trail_cells(map: &Grid<Option<i64>>, start: Point) -> Vec<Point> {
+    // Walk up from start to find every cell it reaches, grouped by height.
+    let mut reached = vec![vec![start]];
+    for h in 1..=9 {
+        let next: collections::BTreeSet<_> = reached[h - 1]
+            .iter()
+            .flat_map(|p| map.neighbors4(*p))
+            .map(|(_, n)| n)
+            .filter(|n| map[*n] == Some(h as i64))
+            .collect();
+        reached.push(next.into_iter().collect());
+    }
+
+    // Walk back down from the summits keeping only the cells that lead to one.
+    let mut cells = reached[9].clone();
+    let mut above = reached[9].clone();
+    for h in (0..9).rev() {
+        let on_trail: Vec<_> = reached[h]
+            .iter()
+            .filter(|p| map.neighbors4(**p).any(|(_, n)| above.contains(&n)))
+            .copied()
+            .collect();
+        cells.extend(&on_trail);
+        above = on_trail;
+    }
+    cells
+}
+
+impl Visualize for Day10 {
+    // visualize shows the trails from each trailhead in turn along with its score and rating.
+    fn visualize(
+        &self,
+        map: &Grid<Option<i64>>,
+        player: &mut Player,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let base = Frame::from_grid(map, |h| match h {
+            Some(h) => char::from_digit(*h as u32, 10).unwrap_or('?'),
+            None => '.',
+        });
+        let (mut score, mut rating) = (0, 0);
+        for start in map.points().filter(|p| map[*p] == Some(0)) {
+            if player.stopped() {
+                break;
+            }
+            let (s, r) = find_trails(map, start);
+            score += s;
+            rating += r;
+
+            let cells = trail_cells(map, start);
+            let mut f = base.clone();
+            f.highlight(cells.iter().copied(), Color::Green);
+            for p in cells.iter().filter(|p| map[**p] == Some(9)) {
+                f.actor(*p, '9', Color::Yellow);
+            }
+            f.actor(start, '0', Color::Red)
+                .caption(format!("trailhead {start}: score {s}, rating {r}"))
+                .caption(format!("total: score {score}, rating {rating}"));
+            player.show(&f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trail_cells() -> Result<(), Box<dyn error::Error>> {
+        let map = crate::read_map("0123456\n1....87\n.....9.\n".as_bytes())?;
+        let cells = trail_cells(&map, Point::new(0, 0));
+        // The 1 below the start is a dead end.
+        assert_eq!(cells.len(), 10);
+        assert!(!cells.contains(&Point::new(0, 1)));
+        Ok(())
+    }
+}
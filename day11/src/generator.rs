@@ -0,0 +1,34 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of stones in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 8;
+
+/// generate returns an input with size stones engraved with numbers of 1 to 7 digits. The answers
+/// are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let stones: Vec<_> = (0..size)
+        .map(|_| {
+            let digits = 1 + rng.below(7) as u32;
+            rng.range(0..10_i64.pow(digits)).to_string()
+        })
+        .collect();
+
+    Generated {
+        input: format!("{}\n", stones.join(" ")),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Lines, Param, Params, Solution};
+
+/// Stones maps the number engraved on stones to the number of stones with that number. The order
+/// of the stones doesn't affect how many there are so it is not kept.
+pub type Stones = collections::HashMap<i64, i64>;
+
+/// read_stones reads the numbers engraved on the stones.
+pub fn read_stones(r: impl BufRead) -> Result<Stones, Error> {
+    let mut stones: Stones = collections::HashMap::new();
+    for line in Lines::new(r) {
+        for n_str in line?.fields() {
+            let n = n_str.parse::<i64>("stone number")?;
+            if n < 0 {
+                return Err(n_str.malformed("non-negative stone number"));
+            }
+            stones.entry(n).and_modify(|a| *a += 1).or_insert(1);
+        }
+    }
+    Ok(stones)
+}
+
+/// blink returns the stones after blinking once. An error is returned if a stone's number
+/// overflows.
+pub fn blink(stones: &Stones) -> Result<Stones, Box<dyn error::Error>> {
+    let mut new_stones: Stones = collections::HashMap::new();
+    for (stone, n) in stones {
+        // If the stone is engraved with the number 0, it is replaced by a stone engraved with
+        // the number 1.
+        if *stone == 0 {
+            new_stones.entry(1).and_modify(|a| *a += *n).or_insert(*n);
+            continue;
+        }
+
+        // If the stone is engraved with a number that has an even number of digits, it is
+        // replaced by two stones. The left half of the digits are engraved on the new left stone,
+        // and the right half of the digits are engraved on the new right stone. (The new numbers
+        // don't keep extra leading zeroes: 1000 would become stones 10 and 0.)
+        // NOTE: Integer logarithms are used since floating point ones are inexact for some powers
+        // of 10.
+        let num_digits = stone.checked_ilog10().unwrap_or(0) + 1;
+        if num_digits.is_multiple_of(2) {
+            let exp = 10_i64.pow(num_digits / 2);
+            let left = stone / exp; // NOTE: integer drops lower order digits.
+            let right = stone - (left * exp);
+
+            new_stones
+                .entry(left)
+                .and_modify(|a| *a += *n)
+                .or_insert(*n);
+            new_stones
+                .entry(right)
+                .and_modify(|a| *a += *n)
+                .or_insert(*n);
+
+            continue;
+        }
+
+        // If none of the other rules apply, the stone is replaced by a new stone; the old
+        // stone's number multiplied by 2024 is engraved on the new stone.
+        new_stones
+            .entry(stone.checked_mul(2024).ok_or("stone number overflow")?)
+            .and_modify(|a| *a += *n)
+            .or_insert(*n);
+    }
+
+    Ok(new_stones)
+}
+
+/// blink_n returns the number of stones after blinking n times.
+pub fn blink_n(stones: &Stones, n: usize) -> Result<i64, Box<dyn error::Error>> {
+    let mut stones = stones.clone();
+    for _i in 0..n {
+        stones = blink(&stones)?;
+    }
+    Ok(stones.values().sum())
+}
+
+/// PARAMS declares the parameters of Day11 and their defaults.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "blinks",
+        default: "25",
+        help: "number of times the stones blink for part 1",
+    },
+    Param {
+        name: "blinks2",
+        default: "75",
+        help: "number of times the stones blink for part 2",
+    },
+];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day11 is the solution for day 11.
+pub struct Day11 {
+    /// blinks is the number of times the stones blink for part 1.
+    pub blinks: usize,
+    /// blinks2 is the number of times the stones blink for part 2.
+    pub blinks2: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day11 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day11 {
+            blinks: params.value(PARAMS, "blinks")?,
+            blinks2: params.value(PARAMS, "blinks2")?,
+        })
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Stones;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_stones(r)
+    }
+
+    fn part1(&self, stones: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        blink_n(stones, self.blinks)
+    }
+
+    fn part2(&self, stones: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        blink_n(stones, self.blinks2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_blink() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("0 1 10 99 999");
+        let stones = blink(&read_stones(input.reader())?)?;
+        assert_eq!(stones.values().sum::<i64>(), 7);
+
+        // 1000 has four digits and is split into 10 and 0.
+        let stones = blink(&read_stones(Bytes::from("1000").reader())?)?;
+        assert_eq!(stones.get(&10), Some(&1));
+        assert_eq!(stones.get(&0), Some(&1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("125 17");
+
+        let (n, n2) = Day11::default().solve(input.reader())?;
+        assert_eq!(n, 55312);
+        assert_eq!(n2, 65601038650482);
+
+        let day = Day11 {
+            blinks: 6,
+            blinks2: 25,
+        };
+        assert_eq!(day.solve(Bytes::from("125 17").reader())?, (22, 55312));
+        Ok(())
+    }
+}
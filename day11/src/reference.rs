@@ -0,0 +1,83 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+use crate::Day11;
+
+/// Reference is a slow but simple solution for day 11 that Day11 is checked against. It follows
+/// each stone separately and remembers how many stones it becomes.
+pub struct Reference(pub Day11);
+
+// Cache holds the number of stones a stone becomes after a number of blinks.
+type Cache = collections::HashMap<(i64, usize), i64>;
+
+// count returns the number of stones the stone becomes after blinking the given number of times.
+fn count(stone: i64, blinks: usize, cache: &mut Cache) -> Result<i64, Box<dyn error::Error>> {
+    if blinks == 0 {
+        return Ok(1);
+    }
+    if let Some(n) = cache.get(&(stone, blinks)) {
+        return Ok(*n);
+    }
+
+    let digits = stone.to_string();
+    let n = if stone == 0 {
+        count(1, blinks - 1, cache)?
+    } else if digits.len().is_multiple_of(2) {
+        let (left, right) = digits.split_at(digits.len() / 2);
+        count(left.parse()?, blinks - 1, cache)? + count(right.parse()?, blinks - 1, cache)?
+    } else {
+        let next = stone.checked_mul(2024).ok_or("stone number overflow")?;
+        count(next, blinks - 1, cache)?
+    };
+
+    cache.insert((stone, blinks), n);
+    Ok(n)
+}
+
+// count_all returns the number of stones after blinking the given number of times.
+fn count_all(
+    stones: &collections::HashMap<i64, i64>,
+    blinks: usize,
+) -> Result<i64, Box<dyn error::Error>> {
+    let mut cache = Cache::new();
+    let mut total = 0;
+    for (stone, n) in stones {
+        total += n * count(*stone, blinks, &mut cache)?;
+    }
+    Ok(total)
+}
+
+impl Solution for Reference {
+    type Input = collections::HashMap<i64, i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_stones(r)
+    }
+
+    fn part1(&self, stones: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        count_all(stones, self.0.blinks)
+    }
+
+    fn part2(&self, stones: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        count_all(stones, self.0.blinks2)
+    }
+}
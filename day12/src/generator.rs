@@ -0,0 +1,118 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Grid, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 140;
+
+// MAX_SIDE is the longest side of a plot. Plots are at least 2 wide and tall so a plot has at
+// most 2 * MAX_SIDE + 4 neighbors, which leaves a free letter for every plot.
+const MAX_SIDE: usize = 8;
+
+// Plot is a rectangular garden plot.
+struct Plot {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+// split divides the plot into plots no longer than MAX_SIDE on either side. Sides that can be
+// split are sometimes split even if they are short enough.
+fn split(rng: &mut Rng, plot: Plot, plots: &mut Vec<Plot>) {
+    let split_x = plot.width > MAX_SIDE || (plot.width >= 4 && rng.chance(1, 3));
+    let split_y = plot.height > MAX_SIDE || (plot.height >= 4 && rng.chance(1, 3));
+    if split_x && (!split_y || plot.width >= plot.height) {
+        let w = 2 + rng.below(plot.width - 3);
+        split(rng, Plot { width: w, ..plot }, plots);
+        split(
+            rng,
+            Plot {
+                x: plot.x + w,
+                width: plot.width - w,
+                ..plot
+            },
+            plots,
+        );
+    } else if split_y {
+        let h = 2 + rng.below(plot.height - 3);
+        split(rng, Plot { height: h, ..plot }, plots);
+        split(
+            rng,
+            Plot {
+                y: plot.y + h,
+                height: plot.height - h,
+                ..plot
+            },
+            plots,
+        );
+    } else {
+        plots.push(plot);
+    }
+}
+
+/// generate returns a size by size map divided into rectangular plots. Each plot is given a
+/// random letter that differs from its neighbors, so every plot is its own region and the answers
+/// follow from the plot sizes. The map is at least 2 by 2.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(2);
+    let mut plots = Vec::new();
+    split(
+        rng,
+        Plot {
+            x: 0,
+            y: 0,
+            width: size,
+            height: size,
+        },
+        &mut plots,
+    );
+
+    let mut ids = Grid::new(size, size, 0);
+    for (i, plot) in plots.iter().enumerate() {
+        for y in plot.y..plot.y + plot.height {
+            for x in plot.x..plot.x + plot.width {
+                ids[Point::new(x, y)] = i;
+            }
+        }
+    }
+
+    let mut letters = vec![None; plots.len()];
+    for (i, plot) in plots.iter().enumerate() {
+        let mut used = Vec::new();
+        for y in plot.y..plot.y + plot.height {
+            for x in plot.x..plot.x + plot.width {
+                for (_, n) in ids.neighbors4(Point::new(x, y)) {
+                    used.extend(letters[ids[n]]);
+                }
+            }
+        }
+        let free: Vec<_> = ('A'..='Z').filter(|c| !used.contains(c)).collect();
+        letters[i] = Some(*rng.choose(&free));
+    }
+
+    let price: usize = plots
+        .iter()
+        .map(|p| p.width * p.height * 2 * (p.width + p.height))
+        .sum();
+    let discount: usize = plots.iter().map(|p| p.width * p.height * 4).sum();
+
+    Generated {
+        input: ids.map(|i| letters[*i].unwrap_or_default()).to_string(),
+        part1: Some(price.to_string()),
+        part2: Some(discount.to_string()),
+        ..Default::default()
+    }
+}
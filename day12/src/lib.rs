@@ -0,0 +1,173 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Direction, Error, Grid, Point, Solution};
+
+/// Region is a group of adjacent garden plots growing the same type of plant.
+#[derive(Debug, Clone)]
+pub struct Region {
+    /// plant_type is the plant grown in the region.
+    pub plant_type: char,
+
+    /// area is the number of plots in the region.
+    pub area: usize,
+
+    /// perimeter is the number of plot edges that border a different region or the edge of the
+    /// map.
+    pub perimeter: usize,
+
+    /// sides is the number of straight fence sections around the region.
+    pub sides: usize,
+
+    /// locations holds the plots in the region.
+    pub locations: collections::HashSet<Point>,
+}
+
+/// find_regions returns every region of the map.
+pub fn find_regions(map: &Grid<char>) -> Vec<Region> {
+    let mut regions = Vec::new();
+    let mut visited = collections::HashSet::new();
+
+    for (p, plant_type) in map.iter() {
+        if visited.contains(&p) {
+            continue;
+        }
+
+        // Process the current region.
+        let mut stack: collections::VecDeque<Point> = collections::VecDeque::new();
+        stack.push_back(p);
+
+        let mut region = Region {
+            plant_type: *plant_type,
+            area: 0,
+            perimeter: 0,
+            sides: 0,
+            locations: collections::HashSet::new(),
+        };
+
+        while !stack.is_empty() {
+            let cur = stack.pop_back().unwrap();
+            if visited.contains(&cur) {
+                continue;
+            }
+
+            region.area += 1;
+
+            for dir in Direction::CARDINAL {
+                match map.step(cur, dir, 1) {
+                    Some(next) if map[next] == region.plant_type => stack.push_back(next),
+                    _ => region.perimeter += 1,
+                }
+            }
+
+            region.locations.insert(cur);
+            visited.insert(cur);
+        }
+
+        regions.push(region);
+    }
+
+    for region in &mut regions {
+        for dir in Direction::CARDINAL {
+            // Find locations in the current region with an adjacent location in the
+            // current direction that is not in the region.
+            let edge_locations: collections::HashSet<Point> = region
+                .locations
+                .iter()
+                .filter(|p| match map.step(**p, dir, 1) {
+                    Some(next) => !region.locations.contains(&next),
+                    None => true,
+                })
+                .copied()
+                .collect();
+
+            // Each side is made up of a run of edge locations in the perpendicular direction.
+            // Count the edge locations that begin a run.
+            region.sides += edge_locations
+                .iter()
+                .filter(|p| match map.step(**p, dir.clockwise(), 1) {
+                    Some(next) => !edge_locations.contains(&next),
+                    None => true,
+                })
+                .count();
+        }
+    }
+
+    regions
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day12 is the solution for day 12.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        Grid::read(r)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(find_regions(map)
+            .iter()
+            .fold(0, |acc, r| acc + (r.area * r.perimeter)))
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(find_regions(map)
+            .iter()
+            .fold(0, |acc, r| acc + (r.area * r.sides)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+",
+        );
+
+        let (n, n2) = Day12.solve(input.reader())?;
+        assert_eq!(n, 1930);
+        assert_eq!(n2, 1206);
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Direction, Error, Grid, Point, Solution};
+
+/// Reference is a slow but simple solution for day 12 that Day12 is checked against. It counts
+/// the sides of a region by counting its corners.
+pub struct Reference;
+
+// regions returns the locations of each region.
+fn regions(map: &Grid<char>) -> Vec<collections::HashSet<Point>> {
+    let mut regions: Vec<collections::HashSet<Point>> = Vec::new();
+    for p in map.points() {
+        if regions.iter().any(|r| r.contains(&p)) {
+            continue;
+        }
+        let mut region = collections::HashSet::from([p]);
+        let mut todo = vec![p];
+        while let Some(cur) = todo.pop() {
+            for (_, next) in map.neighbors4(cur) {
+                if map[next] == map[p] && region.insert(next) {
+                    todo.push(next);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+// inside returns true if the location one step from p in the direction is in the region.
+fn inside(region: &collections::HashSet<Point>, p: Point, dir: Direction) -> bool {
+    p.step(dir, 1).is_some_and(|next| region.contains(&next))
+}
+
+// perimeter returns the number of edges between the region and other locations.
+fn perimeter(region: &collections::HashSet<Point>) -> usize {
+    region
+        .iter()
+        .map(|p| {
+            Direction::CARDINAL
+                .into_iter()
+                .filter(|dir| !inside(region, *p, *dir))
+                .count()
+        })
+        .sum()
+}
+
+// corners returns the number of corners of the region, which is the same as its number of sides.
+fn corners(region: &collections::HashSet<Point>) -> usize {
+    let mut total = 0;
+    for p in region {
+        for dir in Direction::CARDINAL {
+            let side = dir.clockwise();
+            let diagonal = p.step(dir, 1).and_then(|next| next.step(side, 1));
+            let (a, b) = (inside(region, *p, dir), inside(region, *p, side));
+            let c = diagonal.is_some_and(|d| region.contains(&d));
+
+            // The corner is either outside or inside.
+            if (!a && !b) || (a && b && !c) {
+                total += 1;
+            }
+        }
+    }
+    total
+}
+
+impl Solution for Reference {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        Grid::read(r)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(regions(map).iter().map(|r| r.len() * perimeter(r)).sum())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(regions(map).iter().map(|r| r.len() * corners(r)).sum())
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+
+use aoc_common::{Color, Frame, Grid, Images, Player, Rgb, Svg, Visualize};
+
+use crate::{find_regions, Day12, Region};
+
+// colors returns a colour for each region. Neighbouring regions get different colours where the
+// palette allows it.
+fn colors(map: &Grid<char>, regions: &[Region]) -> Vec<Color> {
+    let mut index = Grid::new(map.width(), map.height(), 0);
+    for (i, region) in regions.iter().enumerate() {
+        for p in &region.locations {
+            index[*p] = i;
+        }
+    }
+
+    let mut colors: Vec<Color> = Vec::new();
+    for (i, region) in regions.iter().enumerate() {
+        let taken: Vec<_> = region
+            .locations
+            .iter()
+            .flat_map(|p| map.neighbors4(*p))
+            .map(|(_, n)| index[n])
+            .filter(|j| *j < i)
+            .map(|j| colors[j])
+            .collect();
+        let color = Color::PALETTE
+            .into_iter()
+            .find(|c| !taken.contains(c))
+            .unwrap_or(Color::PALETTE[i % Color::PALETTE.len()]);
+        colors.push(color);
+    }
+    colors
+}
+
+impl Visualize for Day12 {
+    // visualize fills in the regions one at a time along with the running fence prices.
+    fn visualize(
+        &self,
+        map: &Grid<char>,
+        player: &mut Player,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let regions = find_regions(map);
+        let colors = colors(map, &regions);
+        let mut f = Frame::from_chars(map);
+        let (mut price, mut discounted) = (0, 0);
+        for (i, region) in regions.iter().enumerate() {
+            if player.stopped() {
+                break;
+            }
+            price += region.area * region.perimeter;
+            discounted += region.area * region.sides;
+
+            f.highlight(region.locations.iter().copied(), colors[i]);
+            let mut current = f.clone();
+            current.color(region.locations.iter().copied(), Color::White);
+            for p in &region.locations {
+                if let Some(cell) = current.cell(*p) {
+                    cell.bold = true;
+                }
+            }
+            current
+                .caption(format!(
+                    "region {} of {}: {}, area {}, perimeter {}, sides {}",
+                    i + 1,
+                    regions.len(),
+                    region.plant_type,
+                    region.area,
+                    region.perimeter,
+                    region.sides
+                ))
+                .caption(format!("price {price}, discounted price {discounted}"));
+            player.show(&current)?;
+        }
+        Ok(())
+    }
+
+    // export draws the map with each region coloured by its type of plant, as a PPM image and as
+    // an SVG image with each region labelled by its plant.
+    fn export(&self, map: &Grid<char>, images: &mut Images) -> Result<(), Box<dyn error::Error>> {
+        let image = map.map(|c| Rgb::for_key(*c));
+        images.ppm("regions", &image)?;
+
+        let mut svg = Svg::from_image(&image, 10);
+        for region in find_regions(map) {
+            if let Some(p) = region.locations.iter().min_by_key(|p| (p.y, p.x)) {
+                svg.text(*p, &region.plant_type.to_string(), Rgb::BLACK);
+            }
+        }
+        images.svg("regions", &svg)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colors() -> Result<(), aoc_common::Error> {
+        let map = Grid::read("AAB\nCAB\nCCB\n".as_bytes())?;
+        let regions = find_regions(&map);
+        let colors = colors(&map, &regions);
+        // Every region touches the others.
+        assert_eq!(colors, &Color::PALETTE[..3]);
+        Ok(())
+    }
+}
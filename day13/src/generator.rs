@@ -0,0 +1,96 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+use crate::Day13;
+
+/// DEFAULT_SIZE is the number of claw machines in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 320;
+
+// presses returns the number of presses of each button needed to reach the prize if it can be
+// reached with a whole, non-negative number of presses. The buttons must not be parallel.
+fn presses(a: (i128, i128), b: (i128, i128), prize: (i128, i128)) -> Option<(i128, i128)> {
+    // Cramer's rule in integer arithmetic.
+    let det = a.0 * b.1 - a.1 * b.0;
+    let na = prize.0 * b.1 - prize.1 * b.0;
+    let nb = a.0 * prize.1 - a.1 * prize.0;
+    if na % det != 0 || nb % det != 0 || na / det < 0 || nb / det < 0 {
+        return None;
+    }
+    Some((na / det, nb / det))
+}
+
+// tokens returns the tokens needed to win the prize, or zero if it cannot be won.
+fn tokens(a: (i128, i128), b: (i128, i128), prize: (i128, i128)) -> i128 {
+    presses(a, b, prize).map_or(0, |(na, nb)| 3 * na + nb)
+}
+
+/// generate returns an input with size claw machines. A third of the prizes are reached by up to
+/// 100 presses of each button, a third are reached after adding the part 2 offset by pressing
+/// each button billions of times, and the rest are at random positions. The answers are found
+/// exactly with integer arithmetic.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let offset = i128::from(Day13::default().offset);
+    let mut part1 = 0;
+    let mut part2 = 0;
+    let mut machines = Vec::new();
+    while machines.len() < size {
+        let a = (rng.range(10..100) as i128, rng.range(10..100) as i128);
+        let b = (rng.range(10..100) as i128, rng.range(10..100) as i128);
+        if a.0 * b.1 == a.1 * b.0 {
+            continue;
+        }
+
+        let prize = match rng.below(3) {
+            0 => {
+                let (na, nb) = (rng.range(0..101) as i128, rng.range(0..101) as i128);
+                (na * a.0 + nb * b.0, na * a.1 + nb * b.1)
+            }
+            1 => {
+                // Aim for a prize near a random position after the offset and round the presses
+                // needed to whole numbers. The prize moves by less than the sum of the buttons.
+                let target = (
+                    offset + rng.range(1000..20000) as i128,
+                    offset + rng.range(1000..20000) as i128,
+                );
+                let det = (a.0 * b.1 - a.1 * b.0) as f64;
+                let na = ((target.0 * b.1 - target.1 * b.0) as f64 / det).round() as i128;
+                let nb = ((a.0 * target.1 - a.1 * target.0) as f64 / det).round() as i128;
+                if na < 0 || nb < 0 {
+                    continue;
+                }
+                (na * a.0 + nb * b.0 - offset, na * a.1 + nb * b.1 - offset)
+            }
+            _ => (
+                rng.range(1000..20000) as i128,
+                rng.range(1000..20000) as i128,
+            ),
+        };
+
+        part1 += tokens(a, b, prize);
+        part2 += tokens(a, b, (prize.0 + offset, prize.1 + offset));
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    Generated {
+        input: machines.join("\n"),
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Line, Lines, Param, Params, Solution};
+
+/// Prize is a claw machine: how far its A and B buttons move the claw and where its prize is.
+#[derive(Debug)]
+pub struct Prize {
+    a_x: f64,
+    a_y: f64,
+    b_x: f64,
+    b_y: f64,
+    x: f64,
+    y: f64,
+}
+
+impl Prize {
+    /// new returns a claw machine whose buttons move the claw by a and b and whose prize is at
+    /// prize.
+    pub fn new(a: (u64, u64), b: (u64, u64), prize: (u64, u64)) -> Self {
+        Prize {
+            a_x: a.0 as f64,
+            a_y: a.1 as f64,
+            b_x: b.0 as f64,
+            b_y: b.1 as f64,
+            x: prize.0 as f64,
+            y: prize.1 as f64,
+        }
+    }
+
+    /// button_a returns how far the A button moves the claw along the X and Y axes.
+    pub fn button_a(&self) -> (u64, u64) {
+        (self.a_x as u64, self.a_y as u64)
+    }
+
+    /// button_b returns how far the B button moves the claw along the X and Y axes.
+    pub fn button_b(&self) -> (u64, u64) {
+        (self.b_x as u64, self.b_y as u64)
+    }
+
+    /// prize returns the location of the prize.
+    pub fn prize(&self) -> (u64, u64) {
+        (self.x as u64, self.y as u64)
+    }
+}
+
+// parse_xy parses a line of the form "<prefix>X<sep>1, Y<sep>2".
+fn parse_xy(line: &Line, prefix: &str, sep: char) -> Result<(f64, f64), Error> {
+    let (x, y) = line.token().strip_prefix(prefix)?.split_once(", ")?;
+    let x = x
+        .strip_prefix(&format!("X{sep}"))?
+        .parse::<u64>("integer")?;
+    let y = y
+        .strip_prefix(&format!("Y{sep}"))?
+        .parse::<u64>("integer")?;
+
+    Ok((x as f64, y as f64))
+}
+
+/// read_prizes reads the claw machines. Each is described by three lines and they are separated
+/// by blank lines.
+pub fn read_prizes(r: impl BufRead) -> Result<Vec<Prize>, Error> {
+    let mut prizes = Vec::new();
+    let mut lines = Lines::new(r);
+    while let Some(a_line) = lines.next() {
+        let a_line = a_line?;
+        let (a_x, a_y) = parse_xy(&a_line, "Button A: ", '+')?;
+
+        let b_line = lines.expect("button B")?;
+        let (b_x, b_y) = parse_xy(&b_line, "Button B: ", '+')?;
+
+        let p_line = lines.expect("prize")?;
+        let (p_x, p_y) = parse_xy(&p_line, "Prize: ", '=')?;
+
+        prizes.push(Prize {
+            a_x,
+            a_y,
+            b_x,
+            b_y,
+            x: p_x,
+            y: p_y,
+        });
+
+        match lines.next() {
+            Some(line) => {
+                let line = line?;
+                if !line.is_empty() {
+                    return Err(line.semantic("expected empty line between machines"));
+                }
+            }
+            // last empty line may be omitted at EOF.
+            None => break,
+        }
+    }
+
+    Ok(prizes)
+}
+
+/// tokens returns the number of tokens needed to win every winnable prize after adding offset to
+/// each prize's position. Pressing A costs 3 tokens and pressing B costs 1. An error is returned
+/// if the number of tokens overflows.
+pub fn tokens(prizes: &[Prize], offset: f64) -> Result<u64, Box<dyn error::Error>> {
+    // The problem can be described as two linear equations the intersection of which is the
+    // solution to the problem.
+    // a_x*a + b_x*b - x = 0
+    // a_y*a + b_y*b - y = 0
+
+    // Standard form for the linear equations is as follows:
+    // a1*x + b1*y + c1 = 0
+    // a2*x + b2*y + c2 = 0
+
+    // ... and the variables map as follows:
+    // a = x
+    // b = y
+    // a1 = a_x
+    // b1 = b_x
+    // c1 = -x
+    // a2 = a_y
+    // b2 = b_y
+    // c2 = -y
+
+    // The cross multiplication method allows the solution to be solved via the equations:
+    // https://www.cuemath.com/algebra/linear-equations-in-two-variables/
+    // x = (b1*c2 - b2*c1) / (a1*b2 - a2*b1)
+    // y = (a2*c1 - a1*c2) / (a1*b2 - a2*b1)
+
+    let mut tokens: u64 = 0;
+    for p in prizes {
+        let x = p.x + offset;
+        let y = p.y + offset;
+        let a = ((p.b_x * (-y)) - (p.b_y * (-x))) / ((p.a_x * p.b_y) - (p.a_y * p.b_x));
+        let b = ((p.a_y * (-x)) - (p.a_x * (-y))) / ((p.a_x * p.b_y) - (p.a_y * p.b_x));
+
+        // If the resulting numbers are real numbers then we found a good solution.
+        let a32 = a as u64;
+        let b32 = b as u64;
+        if a == a32 as f64 && b == b32 as f64 {
+            tokens = a32
+                .checked_mul(3)
+                .and_then(|t| t.checked_add(b32))
+                .and_then(|t| t.checked_add(tokens))
+                .ok_or("token count overflow")?;
+        };
+    }
+
+    Ok(tokens)
+}
+
+/// PARAMS declares the parameters of Day13 and their defaults.
+pub const PARAMS: &[Param] = &[Param {
+    name: "offset",
+    default: "10000000000000",
+    help: "offset added to the prize positions for part 2",
+}];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day13 is the solution for day 13.
+pub struct Day13 {
+    /// offset is added to the X and Y positions of each prize for part 2.
+    pub offset: u64,
+}
+
+impl Default for Day13 {
+    fn default() -> Self {
+        Day13::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day13 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day13 {
+            offset: params.value(PARAMS, "offset")?,
+        })
+    }
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Prize>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_prizes(r)
+    }
+
+    fn part1(&self, prizes: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        tokens(prizes, 0.0)
+    }
+
+    fn part2(&self, prizes: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        // Part 2 is just the same but with the offset added to x and y.
+        tokens(prizes, self.offset as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+",
+        );
+
+        let (n, n2) = Day13::default().solve(input.reader())?;
+        assert_eq!(n, 480);
+        assert_eq!(n2, 875318608908);
+        Ok(())
+    }
+    #[test]
+    fn test_malformed() {
+        let input = Bytes::from(
+            "Button A: X+94, Y+34
+Button B: X+22, Y=67
+Prize: X=8400, Y=5400
+",
+        );
+
+        let err = read_prizes(input.reader()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "malformed token: expected \"Y+\", found \"Y=67\"
+ --> 2:17
+  |
+2 | Button B: X+22, Y=67
+  |                 ^^^^"
+        );
+
+        let input = Bytes::from("Button A: X+94, Y+34\nButton B: X+22, Y+67\n");
+        let err = read_prizes(input.reader()).unwrap_err();
+        assert_eq!(err.line(), Some(3));
+    }
+}
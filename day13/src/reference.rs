@@ -0,0 +1,79 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+use crate::{Day13, Prize};
+
+/// Reference is a slow but simple solution for day 13 that Day13 is checked against. Part 1 tries
+/// every number of presses up to 100 and part 2 solves the equations with exact integers.
+pub struct Reference(pub Day13);
+
+// exact returns the only numbers of presses of A and B that reach (x, y), if they are whole and
+// not negative.
+fn exact(p: &Prize, x: i128, y: i128) -> Option<(i128, i128)> {
+    let (ax, ay, bx, by) = (p.a_x as i128, p.a_y as i128, p.b_x as i128, p.b_y as i128);
+    let det = ax * by - ay * bx;
+    if det == 0 {
+        return None;
+    }
+    let a = (x * by - y * bx) / det;
+    let b = (ax * y - ay * x) / det;
+    (a >= 0 && b >= 0 && a * ax + b * bx == x && a * ay + b * by == y).then_some((a, b))
+}
+
+impl Solution for Reference {
+    type Input = Vec<Prize>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_prizes(r)
+    }
+
+    fn part1(&self, prizes: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let mut total = 0;
+        for p in prizes {
+            let mut cheapest = None;
+            for a in 0..=100 {
+                for b in 0..=100 {
+                    if a as f64 * p.a_x + b as f64 * p.b_x == p.x
+                        && a as f64 * p.a_y + b as f64 * p.b_y == p.y
+                    {
+                        let cost = 3 * a + b;
+                        if cheapest.is_none_or(|c| cost < c) {
+                            cheapest = Some(cost);
+                        }
+                    }
+                }
+            }
+            total += cheapest.unwrap_or(0);
+        }
+        Ok(total)
+    }
+
+    fn part2(&self, prizes: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let offset = i128::from(self.0.offset);
+        let mut total: i128 = 0;
+        for p in prizes {
+            if let Some((a, b)) = exact(p, p.x as i128 + offset, p.y as i128 + offset) {
+                total += 3 * a + b;
+            }
+        }
+        Ok(u64::try_from(total).map_err(|_| "token count overflow")?)
+    }
+}
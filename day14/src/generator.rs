@@ -0,0 +1,95 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+use crate::Day14;
+
+/// DEFAULT_SIZE is the number of robots outside the tree. Real puzzle inputs have 500 robots in
+/// total.
+pub const DEFAULT_SIZE: usize = 100;
+
+// TREE_ROWS is the number of rows in the tree's triangle. A triangle with rows of 1, 3, 5, ...
+// locations has more than 200 robots next to each other once it is 15 rows tall.
+const TREE_ROWS: i64 = 20;
+
+// tree returns the locations of a Christmas tree, a triangle with a trunk, with its top at the
+// origin.
+fn tree() -> Vec<(i64, i64)> {
+    let mut locations = Vec::new();
+    for y in 0..TREE_ROWS {
+        for x in -y..=y {
+            locations.push((x, y));
+        }
+    }
+    for y in TREE_ROWS..TREE_ROWS + 3 {
+        for x in -1..=1 {
+            locations.push((x, y));
+        }
+    }
+    locations
+}
+
+/// generate returns an input with a robot for every location of a Christmas tree and size more
+/// robots at random locations. Each robot moves with a random velocity, and the tree robots'
+/// starting positions are chosen so that they form the tree at a random time, which is the
+/// answer to part 2. The robots are almost certainly too spread out to be mistaken for the tree
+/// at any earlier time. The answer to part 1 follows from each robot's velocity.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let Day14 {
+        width: w,
+        height: h,
+        seconds,
+    } = Day14::default();
+    let t = rng.range(1..10000);
+    let top = (
+        rng.range(TREE_ROWS..w - TREE_ROWS),
+        rng.range(0..h - TREE_ROWS - 3),
+    );
+
+    let mut robots = Vec::new();
+    for (x, y) in tree() {
+        let v = (rng.range(1 - w..w), rng.range(1 - h..h));
+        let p = (
+            (top.0 + x - v.0 * t).rem_euclid(w),
+            (top.1 + y - v.1 * t).rem_euclid(h),
+        );
+        robots.push((p, v));
+    }
+    for _ in 0..size {
+        let p = (rng.range(0..w), rng.range(0..h));
+        let v = (rng.range(1 - w..w), rng.range(1 - h..h));
+        robots.push((p, v));
+    }
+    rng.shuffle(&mut robots);
+
+    let mut quadrants = [0; 4];
+    for ((px, py), (vx, vy)) in &robots {
+        let x = (px + vx * seconds).rem_euclid(w);
+        let y = (py + vy * seconds).rem_euclid(h);
+        if x != w / 2 && y != h / 2 {
+            quadrants[usize::from(x > w / 2) + 2 * usize::from(y > h / 2)] += 1;
+        }
+    }
+
+    Generated {
+        input: robots
+            .iter()
+            .map(|((px, py), (vx, vy))| format!("p={px},{py} v={vx},{vy}\n"))
+            .collect(),
+        part1: Some(quadrants.iter().product::<i64>().to_string()),
+        part2: Some(t.to_string()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Lines, Param, Params, Solution, Token};
+
+/// Robot is a robot moving at a constant velocity in an area that wraps around at its edges.
+#[derive(Clone)]
+pub struct Robot {
+    p_x: i64,
+    p_y: i64,
+    v_x: i64,
+    v_y: i64,
+
+    w: i64,
+    h: i64,
+}
+
+impl Robot {
+    /// position returns the robot's current position.
+    pub fn position(&self) -> (i64, i64) {
+        (self.p_x, self.p_y)
+    }
+
+    /// velocity returns the number of tiles the robot moves along the X and Y axes each second.
+    pub fn velocity(&self) -> (i64, i64) {
+        (self.v_x, self.v_y)
+    }
+
+    /// advance moves the robot to where it is after t seconds.
+    pub fn advance(&mut self, t: i64) {
+        // NOTE: The velocity and time are reduced modulo the grid size first so that large values
+        //       do not overflow.
+        self.p_x =
+            (self.p_x + self.v_x.rem_euclid(self.w) * t.rem_euclid(self.w)).rem_euclid(self.w);
+        self.p_y =
+            (self.p_y + self.v_y.rem_euclid(self.h) * t.rem_euclid(self.h)).rem_euclid(self.h);
+    }
+}
+
+// parse_xy parses a "<name>=x,y" pair.
+fn parse_xy(t: Token, name: &str) -> Result<(i64, i64), Error> {
+    let (x, y) = t.strip_prefix(name)?.strip_prefix("=")?.split_once(",")?;
+    Ok((x.parse::<i64>("integer")?, y.parse::<i64>("integer")?))
+}
+
+/// read_robots reads a robot from each line. The robots move in an area of w by h tiles. A
+/// Semantic error is returned for a robot starting outside the area.
+pub fn read_robots(r: impl BufRead, w: i64, h: i64) -> Result<Vec<Robot>, Error> {
+    let mut robots = Vec::new();
+
+    for line in Lines::new(r) {
+        let line = line?;
+        let (pos, vel) = line.token().split_once(" ")?;
+
+        // Parse out the position.
+        let (p_x, p_y) = parse_xy(pos, "p")?;
+        if !(0..w).contains(&p_x) || !(0..h).contains(&p_y) {
+            return Err(pos.semantic(format!("position is outside the {w}x{h} area")));
+        }
+
+        let (v_x, v_y) = parse_xy(vel, "v")?;
+
+        robots.push(Robot {
+            p_x,
+            p_y,
+            v_x,
+            v_y,
+            w,
+            h,
+        });
+    }
+
+    Ok(robots)
+}
+
+// TREE_NEIGHBORS is the number of robots with another robot to their right above which the robots
+// are taken to be arranged into the tree.
+const TREE_NEIGHBORS: usize = 200;
+
+// TREE_SECONDS is the number of seconds searched for the tree.
+const TREE_SECONDS: i64 = 10000;
+
+/// safety_factor returns the product of the number of robots in each quadrant of the w by h area.
+/// Robots in the middle row or column are in no quadrant.
+pub fn safety_factor(robots: &[Robot], w: i64, h: i64) -> i64 {
+    let mut quad_robots: [i64; 4] = [0; 4];
+    let mid_x_left = w / 2;
+    let mut mid_x_right = mid_x_left;
+    if w % 2 != 0 {
+        mid_x_right += 1;
+    }
+    let mid_y_top = h / 2;
+    let mut mid_y_bottom = mid_y_top;
+    if h % 2 != 0 {
+        mid_y_bottom += 1;
+    }
+
+    for robot in robots {
+        if robot.p_x < mid_x_left && robot.p_y < mid_y_top {
+            // top left
+            quad_robots[0] += 1;
+        } else if robot.p_x >= mid_x_right && robot.p_y < mid_y_top {
+            // top right
+            quad_robots[1] += 1;
+        } else if robot.p_x < mid_x_left && robot.p_y >= mid_y_bottom {
+            // bottom left
+            quad_robots[2] += 1;
+        } else if robot.p_x >= mid_x_right && robot.p_y >= mid_y_bottom {
+            quad_robots[3] += 1;
+        }
+    }
+
+    let mut safety_factor = 1;
+    for num_robots in quad_robots {
+        safety_factor *= num_robots;
+    }
+
+    safety_factor
+}
+
+/// find_tree returns the first second at which the robots arrange themselves into a picture of a
+/// Christmas tree, or None if they don't within 10000 seconds. The picture is recognized by many
+/// robots being next to each other.
+pub fn find_tree(robots: &[Robot]) -> Option<i64> {
+    let mut robots2 = robots.to_vec();
+
+    // The robots arrange themselves in a pattern where many of them are next to each other.
+    // We just look for this pattern...
+    let mut xmas_t = None;
+    for t in 0..TREE_SECONDS {
+        let mut positions = collections::HashSet::new();
+        for robot in &mut robots2 {
+            positions.insert((robot.p_x, robot.p_y));
+        }
+
+        if robots2
+            .iter()
+            .filter(|r| positions.contains(&(r.p_x + 1, r.p_y)))
+            .count()
+            > TREE_NEIGHBORS
+        {
+            // The arrangement can be checked with --visualize.
+            xmas_t = Some(t);
+            break;
+        }
+
+        for robot in &mut robots2 {
+            robot.advance(1);
+        }
+    }
+
+    xmas_t
+}
+
+/// PARAMS declares the parameters of Day14 and their defaults.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        default: "101",
+        help: "width of the area the robots move in",
+    },
+    Param {
+        name: "height",
+        default: "103",
+        help: "height of the area the robots move in",
+    },
+    Param {
+        name: "seconds",
+        default: "100",
+        help: "number of seconds to simulate for part 1",
+    },
+];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day14 is the solution for day 14.
+pub struct Day14 {
+    /// width is the width of the area the robots move in.
+    pub width: i64,
+    /// height is the height of the area the robots move in.
+    pub height: i64,
+    /// seconds is the number of seconds to simulate for part 1.
+    pub seconds: i64,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day14 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day14 {
+            width: params.value(PARAMS, "width")?,
+            height: params.value(PARAMS, "height")?,
+            seconds: params.value(PARAMS, "seconds")?,
+        })
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        if self.width <= 0 || self.height <= 0 {
+            return Err(Error::semantic("the area must not be empty"));
+        }
+        read_robots(r, self.width, self.height)
+    }
+
+    fn part1(&self, robots: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let (w, h) = (self.width, self.height);
+        let mut robots = robots.clone();
+
+        // Advance the robots.
+        for robot in &mut robots {
+            robot.advance(self.seconds);
+        }
+
+        Ok(safety_factor(&robots, w, h))
+    }
+
+    fn part2(&self, robots: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(find_tree(robots)
+            .ok_or_else(|| Error::no_solution("the robots never arrange into a tree"))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+",
+        );
+
+        let day = Day14 {
+            width: 11,
+            height: 7,
+            seconds: 100,
+        };
+        let robots = day.parse(input.reader())?;
+        assert_eq!(day.part1(&robots)?, 12);
+
+        // The example robots never form a tree.
+        let err = day.part2(&robots).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NoSolution { .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+use crate::{Day14, Robot, TREE_NEIGHBORS, TREE_SECONDS};
+
+/// Reference is a slow but simple solution for day 14 that Day14 is checked against. It works out
+/// each robot's position at a given time directly rather than moving the robots step by step.
+pub struct Reference(pub Day14);
+
+impl Reference {
+    // positions returns the position of each robot after t seconds.
+    fn positions(&self, robots: &[Robot], t: i64) -> Vec<(i64, i64)> {
+        let (w, h) = (i128::from(self.0.width), i128::from(self.0.height));
+        robots
+            .iter()
+            .map(|r| {
+                let x = (i128::from(r.p_x) + i128::from(r.v_x) * i128::from(t)).rem_euclid(w);
+                let y = (i128::from(r.p_y) + i128::from(r.v_y) * i128::from(t)).rem_euclid(h);
+                (x as i64, y as i64)
+            })
+            .collect()
+    }
+}
+
+impl Solution for Reference {
+    type Input = Vec<Robot>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        self.0.parse(r)
+    }
+
+    fn part1(&self, robots: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let (w, h) = (self.0.width, self.0.height);
+
+        // Robots in the middle row or column of an odd sized area are in no quadrant.
+        let mut quadrants = [0; 4];
+        for (x, y) in self.positions(robots, self.0.seconds) {
+            let left = 2 * x + 1 < w;
+            let right = 2 * x + 1 > w;
+            let top = 2 * y + 1 < h;
+            let bottom = 2 * y + 1 > h;
+            match (left, right, top, bottom) {
+                (true, _, true, _) => quadrants[0] += 1,
+                (_, true, true, _) => quadrants[1] += 1,
+                (true, _, _, true) => quadrants[2] += 1,
+                (_, true, _, true) => quadrants[3] += 1,
+                _ => {}
+            }
+        }
+        Ok(quadrants.iter().product())
+    }
+
+    fn part2(&self, robots: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let (w, h) = (self.0.width, self.0.height);
+        for t in 0..TREE_SECONDS {
+            let positions = self.positions(robots, t);
+            let mut occupied = vec![false; (w * h) as usize];
+            for (x, y) in &positions {
+                occupied[(y * w + x) as usize] = true;
+            }
+            let neighbors = positions
+                .iter()
+                .filter(|(x, y)| x + 1 < w && occupied[(y * w + x + 1) as usize])
+                .count();
+            if neighbors > TREE_NEIGHBORS {
+                return Ok(t);
+            }
+        }
+        Err(Error::no_solution("the robots never arrange into a tree").into())
+    }
+}
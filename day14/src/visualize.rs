@@ -0,0 +1,132 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+
+use aoc_common::{Color, Frame, Grid, Images, Player, Point, Visualize};
+
+use crate::{find_tree, safety_factor, Day14, Robot};
+
+impl Day14 {
+    // counts returns the number of robots on each tile.
+    fn counts(&self, robots: &[Robot]) -> Grid<u32> {
+        let mut counts = Grid::new(self.width as usize, self.height as usize, 0);
+        for robot in robots {
+            let (x, y) = robot.position();
+            counts[Point::new(x as usize, y as usize)] += 1;
+        }
+        counts
+    }
+
+    // density returns a grayscale image of the number of robots on each tile. The most crowded
+    // tiles are white.
+    fn density(&self, robots: &[Robot]) -> Grid<u8> {
+        let counts = self.counts(robots);
+        let max = counts.iter().map(|(_, n)| *n).max().unwrap_or(0).max(1);
+        counts.map(|n| (n * 255 / max) as u8)
+    }
+
+    // frame returns a frame showing the number of robots on each tile. The middle row and column,
+    // which are in no quadrant, are shaded.
+    fn frame(&self, robots: &[Robot]) -> Frame {
+        let (w, h) = (self.width as usize, self.height as usize);
+        let counts = self.counts(robots);
+        let mut f = Frame::from_grid(&counts, |n| match n {
+            0 => '.',
+            1..=9 => char::from_digit(*n, 10).unwrap_or('*'),
+            _ => '*',
+        });
+        let robots: Vec<_> = counts.points().filter(|p| counts[*p] > 0).collect();
+        f.color(robots, Color::Green);
+        if w % 2 == 1 {
+            f.highlight((0..h).map(|y| Point::new(w / 2, y)), Color::Blue);
+        }
+        if h % 2 == 1 {
+            f.highlight((0..w).map(|x| Point::new(x, h / 2)), Color::Blue);
+        }
+        f
+    }
+}
+
+impl Visualize for Day14 {
+    // visualize shows the robots moving one second at a time for part 1 and then the picture of
+    // the tree they form for part 2.
+    fn visualize(
+        &self,
+        robots: &Vec<Robot>,
+        player: &mut Player,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let mut moving = robots.clone();
+        for t in 0..=self.seconds {
+            if player.stopped() {
+                return Ok(());
+            }
+            let mut f = self.frame(&moving);
+            f.caption(format!("second {t}"));
+            if t == self.seconds {
+                f.caption(format!(
+                    "safety factor {}",
+                    safety_factor(&moving, self.width, self.height)
+                ));
+            }
+            player.show(&f)?;
+            for robot in &mut moving {
+                robot.advance(1);
+            }
+        }
+
+        let (tree, t) = tree(robots);
+        let caption = match t {
+            Some(t) => format!("the robots form a tree at second {t}"),
+            None => "the robots don't form a tree".to_string(),
+        };
+        let mut f = self.frame(&tree);
+        f.caption(caption);
+        player.show(&f)?;
+        Ok(())
+    }
+
+    // export writes the density of robots at each second of part 1 as a sequence of PGM images
+    // followed by the density when they form the tree.
+    fn export(
+        &self,
+        robots: &Vec<Robot>,
+        images: &mut Images,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let mut moving = robots.clone();
+        let mut seq = images.sequence("density");
+        for _ in 0..=self.seconds {
+            seq.pgm(&self.density(&moving))?;
+            for robot in &mut moving {
+                robot.advance(1);
+            }
+        }
+        let (tree, _) = tree(robots);
+        images.pgm("tree", &self.density(&tree))?;
+        Ok(())
+    }
+}
+
+// tree returns the robots at the second they form a tree along with that second. The robots
+// are returned as they are if they never form one.
+fn tree(robots: &[Robot]) -> (Vec<Robot>, Option<i64>) {
+    let mut tree = robots.to_vec();
+    let t = find_tree(robots);
+    if let Some(t) = t {
+        for robot in &mut tree {
+            robot.advance(t);
+        }
+    }
+    (tree, t)
+}
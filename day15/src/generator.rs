@@ -0,0 +1,55 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Direction, Generated, Grid, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of the warehouse in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 50;
+
+// MOVES_PER_LINE is the number of moves on each line of the move list.
+const MOVES_PER_LINE: usize = 1000;
+
+/// generate returns a size by size warehouse surrounded by walls with walls on about one in ten
+/// locations and boxes on about one in three, followed by 8 * size * size random moves for the
+/// robot. The answers are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(3);
+    let mut map = Grid::new(size, size, '#');
+    for y in 1..size - 1 {
+        for x in 1..size - 1 {
+            map[Point::new(x, y)] = match rng.below(30) {
+                0..=2 => '#',
+                3..=12 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    let center = Point::new(size / 2, size / 2);
+    map[center] = '@';
+
+    let moves: Vec<_> = (0..8 * size * size)
+        .map(|_| rng.choose(&Direction::CARDINAL).arrow().unwrap_or('^'))
+        .collect();
+    let mut input = map.to_string();
+    input.push('\n');
+    for line in moves.chunks(MOVES_PER_LINE) {
+        input.extend(line);
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        ..Default::default()
+    }
+}
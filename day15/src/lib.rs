@@ -0,0 +1,362 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Direction, Error, Grid, Lines, Point, Solution};
+
+// push_line moves the robot at pos one step in the given direction, pushing any boxes in a line
+// in front of it. Boxes are made up of the given box characters. Nothing moves if the line of
+// boxes is blocked. The new position of the robot is returned.
+fn push_line(map: &mut Grid<char>, pos: Point, dir: Direction, boxes: &[char]) -> Point {
+    // Collect the robot's position and the positions of the boxes in front of it followed by
+    // the first location that is not a box.
+    let mut line = vec![pos];
+    while let Some(next) = map.step(*line.last().unwrap(), dir, 1) {
+        line.push(next);
+        if !boxes.contains(&map[next]) {
+            break;
+        }
+    }
+
+    match line.last() {
+        Some(end) if line.len() > 1 && map[*end] == '.' => {
+            // Shift everything forward by one location.
+            for i in (1..line.len()).rev() {
+                map[line[i]] = map[line[i - 1]];
+            }
+            map[pos] = '.';
+            line[1]
+        }
+        _ => pos,
+    }
+}
+
+/// Robot is the robot moving around the warehouse, pushing single width boxes marked 'O'.
+#[derive(Clone)]
+pub struct Robot {
+    map: Grid<char>,
+    moves: collections::VecDeque<Direction>,
+    pos: Point,
+}
+
+impl Robot {
+    /// map returns the warehouse map. The robot is '@' and walls are '#'.
+    pub fn map(&self) -> &Grid<char> {
+        &self.map
+    }
+
+    /// position returns the robot's position.
+    pub fn position(&self) -> Point {
+        self.pos
+    }
+
+    /// moves returns the moves the robot has yet to make.
+    pub fn moves(&self) -> &collections::VecDeque<Direction> {
+        &self.moves
+    }
+
+    /// advance makes the robot's next move, pushing any boxes in front of it. False is returned
+    /// if the robot has no moves left.
+    pub fn advance(&mut self) -> bool {
+        let dir = match self.moves.pop_front() {
+            Some(dir) => dir,
+            None => return false,
+        };
+
+        self.pos = push_line(&mut self.map, self.pos, dir, &['O']);
+
+        true
+    }
+}
+
+/// Robot2 is the robot moving around the scaled up warehouse, where everything except the robot
+/// is twice as wide and boxes are marked '[]'.
+#[derive(Clone)]
+pub struct Robot2 {
+    map: Grid<char>,
+    moves: collections::VecDeque<Direction>,
+    pos: Point,
+}
+
+impl Robot2 {
+    /// map returns the warehouse map. The robot is '@' and walls are '#'.
+    pub fn map(&self) -> &Grid<char> {
+        &self.map
+    }
+
+    /// position returns the robot's position.
+    pub fn position(&self) -> Point {
+        self.pos
+    }
+
+    /// moves returns the moves the robot has yet to make.
+    pub fn moves(&self) -> &collections::VecDeque<Direction> {
+        &self.moves
+    }
+
+    /// advance makes the robot's next move, pushing any boxes in front of it, including boxes
+    /// pushed by other boxes' halves. False is returned if the robot has no moves left.
+    pub fn advance(&mut self) -> bool {
+        let dir = match self.moves.pop_front() {
+            Some(dir) => dir,
+            None => return false,
+        };
+
+        if dir.is_horizontal() {
+            self.pos = push_line(&mut self.map, self.pos, dir, &['[', ']']);
+            return true;
+        }
+
+        self.advance_vert(dir);
+        true
+    }
+
+    // advance_vert moves the robot up or down, pushing the tree of boxes in front of it if none
+    // of them is blocked.
+    fn advance_vert(&mut self, dir: Direction) {
+        // Create a set for each row we will push containing the locations that will get pushed.
+        let mut push_stack: Vec<collections::HashSet<Point>> =
+            vec![collections::HashSet::from([self.pos])];
+
+        loop {
+            let prev_push_set = push_stack.last().unwrap();
+            let mut push_set = collections::HashSet::new();
+
+            for p in prev_push_set {
+                let next = match self.map.step(*p, dir, 1) {
+                    Some(next) => next,
+                    None => return,
+                };
+
+                match self.map[next] {
+                    // We are blocked.
+                    '#' => return,
+                    ']' => {
+                        push_set.insert(next);
+                        push_set.extend(self.map.step(next, Direction::Left, 1));
+                    }
+                    '[' => {
+                        push_set.insert(next);
+                        push_set.extend(self.map.step(next, Direction::Right, 1));
+                    }
+                    _ => {}
+                }
+            }
+
+            // Add the current push set or stop.
+            if push_set.is_empty() {
+                break;
+            }
+            push_stack.push(push_set);
+        }
+
+        // push each row starting from the furthest.
+        while let Some(push_set) = push_stack.pop() {
+            for p in push_set {
+                if let Some(next) = self.map.step(p, dir, 1) {
+                    self.map[next] = self.map[p];
+                    self.map[p] = '.';
+                }
+            }
+        }
+
+        if let Some(next) = self.map.step(self.pos, dir, 1) {
+            self.pos = next;
+        }
+    }
+}
+
+/// read_input reads the warehouse map and, after a blank line, the robot's moves. The robot is
+/// returned for both the original and the scaled up warehouse.
+pub fn read_input(r: impl BufRead) -> Result<(Robot, Robot2), Error> {
+    let mut map_done = false;
+    let mut rows: Vec<Vec<char>> = Vec::new();
+    let mut rows2 = Vec::new();
+    let mut moves = collections::VecDeque::new();
+
+    for line in Lines::new(r) {
+        let line = line?;
+        if line.is_empty() {
+            map_done = true;
+            continue;
+        }
+
+        if !map_done {
+            let mut col = Vec::new();
+            let mut col2 = Vec::new();
+            for (c, t) in line.chars() {
+                if !"#.O@".contains(c) {
+                    return Err(t.invalid_char("one of \"#.O@\""));
+                }
+                col.push(c);
+
+                match c {
+                    '@' => {
+                        col2.push('@');
+                        col2.push('.');
+                    }
+                    '#' => {
+                        col2.push('#');
+                        col2.push('#');
+                    }
+
+                    'O' => {
+                        col2.push('[');
+                        col2.push(']');
+                    }
+                    _ => {
+                        col2.push('.');
+                        col2.push('.');
+                    }
+                }
+            }
+
+            if let Some(first) = rows.first() {
+                if col.len() != first.len() {
+                    return Err(line.semantic(format!(
+                        "expected {} columns, found {}",
+                        first.len(),
+                        col.len()
+                    )));
+                }
+            }
+            rows.push(col);
+            rows2.push(col2);
+        } else {
+            for (c, t) in line.chars() {
+                moves.push_back(Direction::from_arrow(c).ok_or_else(|| t.invalid_char("move"))?);
+            }
+        }
+    }
+
+    let map = Grid::from_rows(rows)?;
+    let map2 = Grid::from_rows(rows2)?;
+    let robot = Robot {
+        pos: map.find('@').unwrap_or_default(),
+        map,
+        moves: moves.clone(),
+    };
+    let robot2 = Robot2 {
+        pos: map2.find('@').unwrap_or_default(),
+        map: map2,
+        moves,
+    };
+
+    Ok((robot, robot2))
+}
+
+/// gps_sum returns the sum of the GPS coordinates of the boxes on the map. A box's GPS coordinate
+/// is 100 times its distance from the top edge plus its distance from the left edge.
+pub fn gps_sum(map: &Grid<char>) -> usize {
+    map.iter()
+        .filter(|(_, c)| **c == 'O' || **c == '[')
+        .map(|(p, _)| 100 * p.y + p.x)
+        .sum()
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day15 is the solution for day 15.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Robot, Robot2);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_input(r)
+    }
+
+    fn part1(&self, (robot, _): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let mut robot = robot.clone();
+        while robot.advance() {}
+        Ok(gps_sum(&robot.map))
+    }
+
+    fn part2(&self, (_, robot2): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut robot2 = robot2.clone();
+        while robot2.advance() {}
+        Ok(gps_sum(&robot2.map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<",
+        );
+
+        let (n, n2) = Day15.solve(input.reader())?;
+        assert_eq!(n, 2028);
+        assert_eq!(n2, 1751);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_large() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+    ",
+        );
+
+        let (n, n2) = Day15.solve(input.reader())?;
+        assert_eq!(n, 10092);
+        assert_eq!(n2, 9021);
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Direction, Error, Grid, Point, Solution};
+
+use crate::{Robot, Robot2};
+
+/// Reference is a slow but simple solution for day 15 that Day15 is checked against. Boxes are
+/// pushed recursively and the wide warehouse for part 2 is built from the part 1 warehouse.
+pub struct Reference;
+
+// can_push returns true if whatever is at p can move one step in the direction.
+fn can_push(map: &Grid<char>, p: Point, dir: Direction) -> bool {
+    let next = match map.step(p, dir, 1) {
+        Some(next) => next,
+        None => return false,
+    };
+    match (map[next], dir.is_vertical()) {
+        ('#', _) => false,
+        ('[', true) => {
+            can_push(map, next, dir) && can_push(map, Point::new(next.x + 1, next.y), dir)
+        }
+        (']', true) => {
+            can_push(map, next, dir) && can_push(map, Point::new(next.x - 1, next.y), dir)
+        }
+        ('O' | '[' | ']', _) => can_push(map, next, dir),
+        _ => true,
+    }
+}
+
+// push moves whatever is at p one step in the direction along with everything it pushes.
+// can_push must be true.
+fn push(map: &mut Grid<char>, p: Point, dir: Direction) {
+    let next = map.step(p, dir, 1).unwrap();
+    match (map[next], dir.is_vertical()) {
+        ('[', true) => {
+            push(map, Point::new(next.x + 1, next.y), dir);
+            push(map, next, dir);
+        }
+        (']', true) => {
+            push(map, Point::new(next.x - 1, next.y), dir);
+            push(map, next, dir);
+        }
+        ('O' | '[' | ']', _) => push(map, next, dir),
+        _ => {}
+    }
+    map[next] = map[p];
+    map[p] = '.';
+}
+
+// run follows the moves and returns the sum of the boxes' GPS coordinates.
+fn run(mut map: Grid<char>, mut pos: Point, moves: &[Direction]) -> usize {
+    for dir in moves {
+        if can_push(&map, pos, *dir) {
+            push(&mut map, pos, *dir);
+            pos = map.step(pos, *dir, 1).unwrap();
+        }
+    }
+    map.iter()
+        .filter(|(_, c)| **c == 'O' || **c == '[')
+        .map(|(p, _)| 100 * p.y + p.x)
+        .sum()
+}
+
+impl Solution for Reference {
+    type Input = (Robot, Robot2);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_input(r)
+    }
+
+    fn part1(&self, (robot, _): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let moves: Vec<_> = robot.moves.iter().copied().collect();
+        Ok(run(robot.map.clone(), robot.pos, &moves))
+    }
+
+    fn part2(&self, (robot, _): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let rows = robot
+            .map
+            .rows()
+            .take(robot.map.height())
+            .map(|row| {
+                row.iter()
+                    .flat_map(|c| match c {
+                        '#' => ['#', '#'],
+                        'O' => ['[', ']'],
+                        '@' => ['@', '.'],
+                        _ => ['.', '.'],
+                    })
+                    .collect()
+            })
+            .collect();
+        let map = Grid::from_rows(rows)?;
+        let pos = Point::new(robot.pos.x * 2, robot.pos.y);
+        let moves: Vec<_> = robot.moves.iter().copied().collect();
+        Ok(run(map, pos, &moves))
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+
+use aoc_common::{Color, Frame, Grid, Player, Point, Visualize};
+
+use crate::{gps_sum, Day15, Robot, Robot2};
+
+// frame returns a frame showing the warehouse with the boxes and the robot coloured in.
+fn frame(map: &Grid<char>, robot: Point) -> Frame {
+    let mut f = Frame::from_chars(map);
+    let walls: Vec<_> = map.points().filter(|p| map[*p] == '#').collect();
+    let boxes: Vec<_> = map
+        .points()
+        .filter(|p| matches!(map[*p], 'O' | '[' | ']'))
+        .collect();
+    f.color(walls, Color::Blue)
+        .color(boxes, Color::Yellow)
+        .actor(robot, '@', Color::Red);
+    f
+}
+
+// show shows a frame of the warehouse after the given number of moves.
+fn show(
+    player: &mut Player,
+    part: usize,
+    map: &Grid<char>,
+    robot: Point,
+    moves: (usize, usize),
+) -> Result<(), Box<dyn error::Error>> {
+    let mut f = frame(map, robot);
+    f.caption(format!("part {part}: move {} of {}", moves.0, moves.1))
+        .caption(format!("GPS sum {}", gps_sum(map)));
+    player.show(&f)?;
+    Ok(())
+}
+
+impl Visualize for Day15 {
+    // visualize shows the robot making each of its moves in the warehouse and then in the scaled
+    // up warehouse.
+    fn visualize(
+        &self,
+        (robot, robot2): &(Robot, Robot2),
+        player: &mut Player,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let mut robot = robot.clone();
+        let total = robot.moves().len();
+        for i in 0.. {
+            if player.stopped() {
+                return Ok(());
+            }
+            show(player, 1, robot.map(), robot.position(), (i, total))?;
+            if !robot.advance() {
+                break;
+            }
+        }
+
+        let mut robot2 = robot2.clone();
+        let total = robot2.moves().len();
+        for i in 0.. {
+            if player.stopped() {
+                return Ok(());
+            }
+            show(player, 2, robot2.map(), robot2.position(), (i, total))?;
+            if !robot2.advance() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
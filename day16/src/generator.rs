@@ -0,0 +1,55 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::generator::{maze, maze_path};
+use aoc_common::{Direction, Generated, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 141;
+
+/// generate returns a size by size maze, rounded down to an odd size of at least 5, with the start
+/// in the bottom left and the end in the top right. The maze has a single path from the start to
+/// the end so the best path is that path. Its score follows from its length and the number of
+/// turns starting from facing east, and every tile on it is on the best path.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = (size.max(5) - 1) / 2 * 2 + 1;
+    let mut maze = maze(rng, size);
+    let start = Point::new(1, size - 2);
+    let end = Point::new(size - 2, 1);
+    maze[start] = 'S';
+    maze[end] = 'E';
+
+    let path = maze_path(&maze, start, end);
+    let mut dir = Direction::Right;
+    let mut score = 0;
+    for w in path.windows(2) {
+        let next = Direction::CARDINAL
+            .into_iter()
+            .find(|d| w[0].step(*d, 1) == Some(w[1]))
+            .unwrap_or(dir);
+        score += 1 + match next {
+            d if d == dir => 0,
+            d if d == dir.opposite() => 2000,
+            _ => 1000,
+        };
+        dir = next;
+    }
+
+    Generated {
+        input: maze.to_string(),
+        part1: Some(score.to_string()),
+        part2: Some(path.len().to_string()),
+        ..Default::default()
+    }
+}
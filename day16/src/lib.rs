@@ -0,0 +1,287 @@
+// Copyright 2025 Ian Lewi
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::log::Level;
+use aoc_common::{event, Direction, Error, Grid, Point, Solution};
+
+/// read_map reads the maze and returns the start tile along with the maze. Walls are '#' and the
+/// end tile is 'E'.
+pub fn read_map(r: impl BufRead) -> Result<(Point, Grid<char>), Error> {
+    let map = Grid::read_charset(r, "#.SE")?;
+    let start = map
+        .find('S')
+        .ok_or_else(|| Error::semantic("no start tile found"))?;
+    Ok((start, map))
+}
+
+// Action is the next step taken along a path.
+#[derive(Clone, Hash, Eq, PartialEq)]
+enum Action {
+    Forward,
+    Left,
+    Right,
+}
+
+/// Visit is a step along a path through the maze.
+#[derive(Debug, Clone)]
+pub struct Visit {
+    /// pos is the reindeer's position.
+    pub pos: Point,
+
+    /// dir is the direction the reindeer faces.
+    pub dir: Direction,
+
+    /// score is the score of the path up to and including this step. Moving forward scores 1 and
+    /// turning scores 1000.
+    pub score: usize,
+}
+
+/// find_paths searches the maze for paths from start to the end tile. The reindeer starts facing
+/// east. Paths that are known to have a higher score than another path through the same location
+/// are abandoned early, so every path with the lowest score is returned but paths with higher
+/// scores may not be.
+pub fn find_paths(start: Point, map: &Grid<char>) -> Vec<Vec<Visit>> {
+    let mut actions = collections::VecDeque::new();
+    let mut paths = Vec::new();
+
+    let start = vec![Visit {
+        pos: start,
+        dir: Direction::Right,
+        score: 0,
+    }];
+    actions.push_back((start.clone(), Action::Forward));
+    actions.push_back((start.clone(), Action::Left));
+    actions.push_back((start.clone(), Action::Right));
+
+    // visited is a global visited cache that is used as an optimization to
+    // avoid going down low score paths.
+    let mut visited = collections::HashMap::new();
+    let (mut steps, mut max_stack) = (0usize, actions.len());
+    while !actions.is_empty() {
+        max_stack = max_stack.max(actions.len());
+        steps += 1;
+        if steps % 4096 == 0 {
+            event!(
+                Level::Trace,
+                "search",
+                steps = steps,
+                stack = actions.len(),
+                visited = visited.len(),
+                paths = paths.len()
+            );
+        }
+        let (mut path, action) = actions.pop_back().unwrap();
+
+        let last_visit = path.last().unwrap();
+        let cur_visit;
+        let next_actions;
+        match action {
+            Action::Forward => {
+                let pos = match map.step(last_visit.pos, last_visit.dir, 1) {
+                    Some(pos) => pos,
+                    None => continue,
+                };
+                cur_visit = Visit {
+                    pos,
+                    dir: last_visit.dir,
+                    score: last_visit.score + 1,
+                };
+                next_actions = vec![Action::Forward, Action::Left, Action::Right];
+            }
+            Action::Left => {
+                cur_visit = Visit {
+                    pos: last_visit.pos,
+                    dir: last_visit.dir.counter_clockwise(),
+                    score: last_visit.score + 1000,
+                };
+                next_actions = vec![Action::Forward];
+            }
+            Action::Right => {
+                cur_visit = Visit {
+                    pos: last_visit.pos,
+                    dir: last_visit.dir.clockwise(),
+                    score: last_visit.score + 1000,
+                };
+                next_actions = vec![Action::Forward];
+            }
+        }
+
+        if map[cur_visit.pos] == '#' {
+            // We are in a wall. Oops!
+            continue;
+        }
+
+        if let Some(s) = visited.get(&(cur_visit.pos, cur_visit.dir)) {
+            if *s < cur_visit.score {
+                continue;
+            }
+        }
+
+        let cur_pos = cur_visit.pos;
+        path.push(cur_visit.clone());
+        visited.insert((cur_visit.pos, cur_visit.dir), cur_visit.score);
+        if map[cur_pos] == 'E' {
+            // We found the end!
+            event!(
+                Level::Trace,
+                "path",
+                score = cur_visit.score,
+                length = path.len(),
+                stack = actions.len()
+            );
+            paths.push(path);
+            continue;
+        }
+
+        for next_action in &next_actions {
+            // Optimization: process forward actions first by pushing them to the back of the
+            // stack. Forward actions tend to result in a lower score so we don't have to
+            // backtrack over visited locations as much.
+            if action == Action::Forward {
+                actions.push_back((path.clone(), next_action.clone()));
+            } else {
+                actions.push_front((path.clone(), next_action.clone()));
+            }
+        }
+    }
+
+    event!(
+        Level::Debug,
+        "searched",
+        steps = steps,
+        max_stack = max_stack,
+        visited = visited.len(),
+        paths = paths.len()
+    );
+    paths
+}
+
+/// min_score returns the lowest score of all the paths. A NoSolution error is returned if there
+/// are no paths.
+pub fn min_score(paths: &[Vec<Visit>]) -> Result<usize, Error> {
+    paths
+        .iter()
+        .map(|path| path.last().unwrap().score)
+        .min()
+        .ok_or_else(|| Error::no_solution("there is no path from the start to the end"))
+}
+
+/// best_tiles returns the tiles that are on at least one of the paths with the lowest score.
+pub fn best_tiles(paths: &[Vec<Visit>]) -> Result<collections::HashSet<Point>, Error> {
+    let score_min = min_score(paths)?;
+
+    let mut unique_tiles = collections::HashSet::new();
+    for path in paths
+        .iter()
+        .filter(|p| p.last().unwrap().score == score_min)
+    {
+        for visit in path {
+            unique_tiles.insert(visit.pos);
+        }
+    }
+
+    Ok(unique_tiles)
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day16 is the solution for day 16.
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = (Point, Grid<char>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_map(r)
+    }
+
+    fn part1(&self, (start, map): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(min_score(&find_paths(*start, map))?)
+    }
+
+    fn part2(&self, (start, map): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(best_tiles(&find_paths(*start, map))?.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############",
+        );
+
+        let (n, n2) = Day16.solve(input.reader())?;
+        assert_eq!(n, 7036);
+        assert_eq!(n2, 45);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run2() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################",
+        );
+
+        let (n, n2) = Day16.solve(input.reader())?;
+        assert_eq!(n, 11048);
+        assert_eq!(n2, 64);
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Direction, Error, Grid, Point, Solution};
+
+/// Reference is a slow but simple solution for day 16 that Day16 is checked against. The lowest
+/// score to every position and direction is found by relaxing moves until nothing improves.
+pub struct Reference;
+
+// State is a position and the direction the reindeer faces there.
+type State = (Point, Direction);
+
+// Scores holds the lowest score found to each state.
+type Scores = collections::HashMap<State, usize>;
+
+// moves returns the states reachable from the state with one move and the cost of the move.
+fn moves(map: &Grid<char>, (p, dir): State) -> Vec<(State, usize)> {
+    let mut moves = vec![
+        ((p, dir.clockwise()), 1000),
+        ((p, dir.counter_clockwise()), 1000),
+    ];
+    if let Some(next) = map.step(p, dir, 1).filter(|next| map[*next] != '#') {
+        moves.push(((next, dir), 1));
+    }
+    moves
+}
+
+// scores returns the lowest score from any of the starting states to every reachable state.
+// Moves are followed backwards if reverse is true.
+fn scores(map: &Grid<char>, starts: &[State], reverse: bool) -> Scores {
+    let mut scores: Scores = starts.iter().map(|s| (*s, 0)).collect();
+    loop {
+        let mut changed = false;
+        for p in map.points().filter(|p| map[*p] != '#') {
+            for dir in Direction::CARDINAL {
+                for (next, cost) in moves(map, (p, dir)) {
+                    let (from, to) = if reverse {
+                        (next, (p, dir))
+                    } else {
+                        ((p, dir), next)
+                    };
+                    let score = match scores.get(&from) {
+                        Some(s) => s + cost,
+                        None => continue,
+                    };
+                    if scores.get(&to).is_none_or(|s| score < *s) {
+                        scores.insert(to, score);
+                        changed = true;
+                    }
+                }
+            }
+        }
+        if !changed {
+            return scores;
+        }
+    }
+}
+
+// best returns the lowest score from the start to the end and the states at the end.
+fn best(start: Point, map: &Grid<char>) -> Result<(Scores, Vec<State>, usize), Error> {
+    let from_start = scores(map, &[(start, Direction::Right)], false);
+    let ends: Vec<State> = map
+        .points()
+        .filter(|p| map[*p] == 'E')
+        .flat_map(|p| Direction::CARDINAL.map(|dir| (p, dir)))
+        .collect();
+    let score = ends
+        .iter()
+        .filter_map(|s| from_start.get(s))
+        .min()
+        .copied()
+        .ok_or_else(|| Error::no_solution("there is no path from the start to the end"))?;
+    Ok((from_start, ends, score))
+}
+
+impl Solution for Reference {
+    type Input = (Point, Grid<char>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_map(r)
+    }
+
+    fn part1(&self, (start, map): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(best(*start, map)?.2)
+    }
+
+    fn part2(&self, (start, map): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        // A tile is on a best path if the lowest scores to it from the start and from it to the
+        // end add up to the best score.
+        let (from_start, ends, score) = best(*start, map)?;
+        let to_end = scores(map, &ends, true);
+        let tiles: collections::HashSet<Point> = from_start
+            .iter()
+            .filter(|(s, n)| to_end.get(s).is_some_and(|m| *n + m == score))
+            .map(|((p, _), _)| *p)
+            .collect();
+        Ok(tiles.len())
+    }
+}
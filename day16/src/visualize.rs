@@ -0,0 +1,100 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+
+use aoc_common::{Color, Frame, Grid, Images, Player, Point, Rgb, Svg, Visualize};
+
+use crate::{best_tiles, find_paths, min_score, Day16};
+
+impl Visualize for Day16 {
+    // visualize shows each of the best paths through the maze in turn and then every tile that is
+    // on one of them.
+    fn visualize(
+        &self,
+        (start, map): &(Point, Grid<char>),
+        player: &mut Player,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let paths = find_paths(*start, map);
+        let score = min_score(&paths)?;
+        let best: Vec<_> = paths
+            .iter()
+            .filter(|path| path.last().is_some_and(|v| v.score == score))
+            .collect();
+
+        let mut base = Frame::from_chars(map);
+        let walls: Vec<_> = map.points().filter(|p| map[*p] == '#').collect();
+        base.color(walls, Color::Blue);
+
+        for (i, path) in best.iter().enumerate() {
+            if player.stopped() {
+                return Ok(());
+            }
+            let mut points: Vec<_> = path.iter().map(|v| v.pos).collect();
+            points.dedup();
+            let mut f = base.clone();
+            f.path(&points, Color::Yellow)
+                .actor(*start, 'S', Color::Red)
+                .caption(format!(
+                    "best path {} of {}: score {score}",
+                    i + 1,
+                    best.len()
+                ));
+            player.show(&f)?;
+        }
+
+        let tiles = best_tiles(&paths)?;
+        let mut f = base.clone();
+        for p in &tiles {
+            f.set(*p, 'O');
+        }
+        f.color(tiles.iter().copied(), Color::Yellow)
+            .actor(*start, 'S', Color::Red)
+            .caption(format!("{} tiles are on a best path", tiles.len()));
+        player.show(&f)?;
+        Ok(())
+    }
+
+    // export draws the tiles on a best path over the maze as a PPM image, and each best path in
+    // its own colour as an SVG image.
+    fn export(
+        &self,
+        (start, map): &(Point, Grid<char>),
+        images: &mut Images,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let paths = find_paths(*start, map);
+        let score = min_score(&paths)?;
+        let maze = map.map(|c| if *c == '#' { Rgb::GRAY } else { Rgb::WHITE });
+
+        let mut image = maze.clone();
+        for p in best_tiles(&paths)? {
+            image[p] = Color::Yellow.into();
+        }
+        image[*start] = Color::Red.into();
+        images.ppm("best-tiles", &image)?;
+
+        let mut svg = Svg::from_image(&maze, 10);
+        let best = paths
+            .iter()
+            .filter(|path| path.last().is_some_and(|v| v.score == score));
+        for (i, path) in best.enumerate() {
+            let mut points: Vec<_> = path.iter().map(|v| v.pos).collect();
+            points.dedup();
+            svg.path(&points, Color::PALETTE[i % Color::PALETTE.len()].into());
+        }
+        svg.circle(*start, Color::Red.into());
+        images.svg("best-paths", &svg)?;
+        Ok(())
+    }
+}
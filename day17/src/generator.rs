@@ -0,0 +1,45 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of octal digits in register A, and so the number of values the
+/// program outputs, in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 16;
+
+// MAX_DIGITS is the largest number of octal digits that fit in register A.
+const MAX_DIGITS: usize = 20;
+
+/// generate returns a program with the same shape as real puzzle inputs, which outputs a value
+/// computed from the low 3 bits of register A and shifts A right by 3 bits until it is zero:
+///
+///   B = A % 8; B ^= X; C = A >> B; B ^= Y; B ^= C; A >>= 3; out B; jump to start if A != 0
+///
+/// X and Y are random. Register A holds a random number of size octal digits, up to 20. The
+/// answers are not known, and part 2 has no answer for some choices of X and Y.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let digits = size.clamp(1, MAX_DIGITS) as u32;
+    let a = rng.range(8_i64.pow(digits - 1)..8_i64.pow(digits));
+    let (x, y) = (rng.below(8), rng.below(8));
+    let program = [2, 4, 1, x, 7, 5, 1, y, 4, rng.below(8), 0, 3, 5, 5, 3, 0];
+    let program: Vec<_> = program.iter().map(|n| n.to_string()).collect();
+
+    Generated {
+        input: format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            program.join(",")
+        ),
+        ..Default::default()
+    }
+}
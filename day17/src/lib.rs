@@ -0,0 +1,365 @@
+// Copyright 2025 Ian Lewis
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::log::Level;
+use aoc_common::{event, Error, Lines, Solution};
+
+/// Program is a list of the opcodes and their operands.
+pub struct Program {
+    instructions: Vec<usize>,
+}
+
+impl Program {
+    /// new returns a program with the given opcodes and operands. An error is returned if any of
+    /// them is not a 3-bit number.
+    pub fn new(instructions: Vec<usize>) -> Result<Self, Error> {
+        if let Some(n) = instructions.iter().find(|n| **n > 7) {
+            return Err(Error::semantic(format!("{n} is not a 3-bit number")));
+        }
+        Ok(Program { instructions })
+    }
+
+    /// instructions returns the program's opcodes and operands.
+    pub fn instructions(&self) -> &[usize] {
+        &self.instructions
+    }
+}
+
+// MAX_STEPS is the maximum number of instructions executed before a program is assumed to loop
+// forever.
+const MAX_STEPS: usize = 1_000_000;
+
+/// Interpreter is a program interpreter.
+#[derive(Clone)]
+pub struct Interpreter {
+    // pc is the instruction pointer.
+    pc: usize,
+
+    // a is the value of the a register
+    a: i64,
+    // b is the value of the b register
+    b: i64,
+    // c is the value of the c register
+    c: i64,
+}
+
+impl Interpreter {
+    /// new returns an interpreter with the given register values and the instruction pointer at
+    /// the start of the program.
+    pub fn new(a: i64, b: i64, c: i64) -> Self {
+        Interpreter { pc: 0, a, b, c }
+    }
+
+    /// registers returns the values of the a, b and c registers.
+    pub fn registers(&self) -> (i64, i64, i64) {
+        (self.a, self.b, self.c)
+    }
+
+    /// exec executes the given program from the current instruction until it halts and returns
+    /// all outputs of the program. An error is returned for an invalid instruction or if the
+    /// program runs for too long without halting.
+    pub fn exec(&mut self, p: &Program) -> Result<Vec<usize>, Box<dyn error::Error>> {
+        let mut out = Vec::new();
+
+        let mut steps = 0;
+        while self.pc < p.instructions.len() {
+            steps += 1;
+            if steps > MAX_STEPS {
+                return Err(format!("Program did not halt after {} steps", MAX_STEPS).into());
+            }
+
+            match p.instructions[self.pc] {
+                // adv
+                0 => self.a = self._read_div(p)?,
+                // bxl
+                1 => self.b ^= self._read_literal(p)?,
+                // bst
+                2 => self.b = self._read_combo(p)? % 8,
+                // jnz
+                3 => {
+                    if self.a != 0 {
+                        self.pc = self._read_literal(p)?.try_into()?;
+                        continue;
+                    }
+                }
+                // bxc
+                4 => self.b ^= self.c,
+                // out
+                5 => {
+                    let op: usize = self._read_combo(p)?.try_into()?;
+                    out.push(op % 8);
+                }
+                // bdv
+                6 => self.b = self._read_div(p)?,
+                // cdv
+                7 => self.c = self._read_div(p)?,
+                unknown_opcode => {
+                    return Err(format!("Invalid Opcode: {}", unknown_opcode).into());
+                }
+            }
+
+            self.pc += 2;
+        }
+
+        Ok(out)
+    }
+
+    fn _read_literal(&self, p: &Program) -> Result<i64, Box<dyn error::Error>> {
+        match p.instructions.get(self.pc + 1) {
+            Some(op) => Ok(i64::try_from(*op)?),
+            None => Err(format!("Missing operand at {}", self.pc + 1).into()),
+        }
+    }
+
+    // _read_div returns the value of the a register divided by two to the power of the combo
+    // operand.
+    fn _read_div(&self, p: &Program) -> Result<i64, Box<dyn error::Error>> {
+        let n: u32 = self._read_combo(p)?.try_into()?;
+        // The result is zero if the divisor is too large to represent.
+        Ok(2_i64.checked_pow(n).map_or(0, |d| self.a / d))
+    }
+
+    fn _read_combo(&self, p: &Program) -> Result<i64, Box<dyn error::Error>> {
+        let op = self._read_literal(p)?;
+        match op {
+            0..=3 => Ok(op),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            invalid_operand => Err(format!("Invalid operand: {}", invalid_operand).into()),
+        }
+    }
+}
+
+// read_register reads the value of the register with the given name.
+fn read_register(lines: &mut Lines<impl BufRead>, name: &str) -> Result<i64, Error> {
+    let line = lines.expect(&format!("register {name}"))?;
+    line.token()
+        .strip_prefix(&format!("Register {name}:"))?
+        .trim()
+        .parse::<i64>("integer")
+}
+
+/// read_input reads the initial register values and the program.
+pub fn read_input(r: impl BufRead) -> Result<(Interpreter, Program), Error> {
+    let mut p = Program {
+        instructions: vec![],
+    };
+    let mut i = Interpreter {
+        pc: 0,
+        a: 0,
+        b: 0,
+        c: 0,
+    };
+
+    let mut lines = Lines::new(r);
+    i.a = read_register(&mut lines, "A")?;
+    i.b = read_register(&mut lines, "B")?;
+    i.c = read_register(&mut lines, "C")?;
+
+    let empty = lines.expect("empty line")?;
+    if !empty.is_empty() {
+        return Err(empty.semantic("expected empty line"));
+    }
+
+    let p_line = lines.expect("program")?;
+    for num_str in p_line.token().strip_prefix("Program:")?.trim().split(",") {
+        let n = num_str.parse::<usize>("3-bit number")?;
+        if n > 7 {
+            return Err(num_str.malformed("3-bit number"));
+        }
+        p.instructions.push(n);
+    }
+
+    Ok((i, p))
+}
+
+// join returns the values separated by commas.
+fn join<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// find_a_reg returns the lowest value of the a register for which the program outputs a copy of
+/// itself, or None if there is none. It only works for programs that, like the puzzle inputs,
+/// output one value and divide a by 8 in each iteration of a loop that ends when a is 0.
+pub fn find_a_reg(m: Interpreter, p: &Program) -> Result<Option<i64>, Box<dyn error::Error>> {
+    //	find_a_reg relies on the structure of the specific input program given
+    // which has the structure:
+    //
+    // WHILE A != 0 {
+    //     B = ...
+    //     A /= 8
+    //     print(B)
+    // }
+    //
+    // Since A is managed in octal values one instruction is output per octal
+    // bit in A. We loop through through the possible values of A and process the
+    // smaller values first. At each bit we check if it matches the output of the
+    // program starting from the end. If we find a match we go to the next bit.
+    //
+    // Some bits are not 1:1 with output instructions so we need to keep a
+    //stack so we can go back and check values if the current one doesn't result in a
+    // match.
+
+    let mut stack: collections::VecDeque<(i64, i64, Vec<usize>)> = collections::VecDeque::new();
+
+    for i in (0..8).rev() {
+        stack.push_back((i, 0, vec![]));
+    }
+
+    while !stack.is_empty() {
+        let (n, a, instr) = stack.pop_back().unwrap();
+
+        let mut mc = m.clone();
+        let a_mul = match a.checked_mul(8) {
+            Some(a8) => a8 + n,
+            // A is too large. There is no solution on this branch.
+            None => continue,
+        };
+        mc.a = a_mul;
+        let out = mc.exec(p)?;
+
+        let expected = p
+            .instructions
+            .len()
+            .checked_sub(instr.len() + 1)
+            .and_then(|i| p.instructions.get(i));
+        if !out.is_empty() && out.first() == expected {
+            event!(
+                Level::Trace,
+                "candidate",
+                a = format!("{a_mul:o}"),
+                output = join(&out),
+                program = join(&p.instructions)
+            );
+            if out == p.instructions {
+                return Ok(Some(a_mul));
+            }
+            // Each octal digit added to A adds an output, so there is no point going deeper once
+            // the output is as long as the program or doesn't match its end.
+            if out.len() >= p.instructions.len() || !p.instructions.ends_with(&out) {
+                continue;
+            }
+            for next_n in (0..8).rev() {
+                stack.push_back((next_n, a_mul, out.clone()));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day17 is the solution for day 17.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (Interpreter, Program);
+    type Part1 = String;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_input(r)
+    }
+
+    fn part1(&self, (i, p): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(join(&i.clone().exec(p)?))
+    }
+
+    fn part2(&self, (i, p): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(find_a_reg(i.clone(), p)?.ok_or_else(|| {
+            Error::no_solution("no value of register A makes the program output itself")
+        })?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0",
+        );
+
+        let parsed = Day17.parse(input.reader())?;
+        assert_eq!(Day17.part1(&parsed)?, "4,6,3,5,6,3,5,2,1,0");
+        // No value of A makes this program output itself. 58 outputs 5,6,7,3,1,0, which is only
+        // as long as the program.
+        let err = Day17.part2(&parsed).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NoSolution { .. })
+        ));
+
+        let input = Bytes::from(
+            "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0",
+        );
+        let (n, n2) = Day17.solve(input.reader())?;
+        assert_eq!(n, "5,7,3,0");
+        assert_eq!(n2, 117440);
+        Ok(())
+    }
+
+    #[test]
+    fn test_exec() -> Result<(), Box<dyn error::Error>> {
+        // bst 4 sets b to a % 8, then out 5 outputs b.
+        let p = Program::new(vec![2, 4, 5, 5])?;
+        let mut i = Interpreter::new(13, 0, 0);
+        assert_eq!(i.exec(&p)?, [5]);
+        assert_eq!(i.registers(), (13, 5, 0));
+
+        assert!(Program::new(vec![2, 8]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0",
+        );
+
+        let (n, n2) = Day17.solve(input.reader())?;
+        assert_eq!(n, "5,7,3,0");
+        assert_eq!(n2, 117440);
+        Ok(())
+    }
+}
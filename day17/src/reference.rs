@@ -0,0 +1,102 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+use crate::{Interpreter, Program, MAX_STEPS};
+
+/// Reference is a slow but simple solution for day 17 that Day17 is checked against. Part 2 tries
+/// every value of register A in turn so it only finds small values.
+pub struct Reference;
+
+// SEARCH_LIMIT is the number of values of register A tried for part 2.
+const SEARCH_LIMIT: i64 = 1 << 18;
+
+// run runs the program with the given registers and returns its output. None is returned if the
+// program does not halt or is invalid.
+fn run(program: &[usize], [mut a, mut b, mut c]: [i64; 3]) -> Option<Vec<usize>> {
+    let mut out = Vec::new();
+    let mut pc = 0;
+    for _ in 0..=MAX_STEPS {
+        if pc >= program.len() {
+            return Some(out);
+        }
+
+        // Operands are only read by the instructions that use them.
+        let literal = || program.get(pc + 1).map(|op| *op as i64);
+        let combo = || match literal()? {
+            op @ 0..=3 => Some(op),
+            4 => Some(a),
+            5 => Some(b),
+            6 => Some(c),
+            _ => None,
+        };
+        let div = || {
+            let shift = u32::try_from(combo()?).ok()?;
+            Some(if shift >= 63 { 0 } else { a / (1 << shift) })
+        };
+
+        match program[pc] {
+            0 => a = div()?,
+            1 => b ^= literal()?,
+            2 => b = combo()? % 8,
+            3 if a != 0 => {
+                pc = literal()? as usize;
+                continue;
+            }
+            3 => {}
+            4 => b ^= c,
+            5 => out.push(usize::try_from(combo()?).ok()? % 8),
+            6 => b = div()?,
+            7 => c = div()?,
+            _ => return None,
+        }
+        pc += 2;
+    }
+    None
+}
+
+impl Solution for Reference {
+    type Input = (Interpreter, Program);
+    type Part1 = String;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_input(r)
+    }
+
+    fn part1(&self, (i, p): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let out = run(&p.instructions, [i.a, i.b, i.c]).ok_or("the program failed")?;
+        Ok(out
+            .iter()
+            .map(|o| o.to_string())
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+
+    fn part2(&self, (i, p): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        for a in 0..SEARCH_LIMIT {
+            if run(&p.instructions, [a, i.b, i.c]).as_ref() == Some(&p.instructions) {
+                return Ok(a);
+            }
+        }
+        Err(
+            format!("no value of register A below {SEARCH_LIMIT} makes the program output itself")
+                .into(),
+        )
+    }
+}
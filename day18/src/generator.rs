@@ -0,0 +1,103 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Grid, Params, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of the memory space in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 71;
+
+// staircase returns a path of right and down steps from the top left to the bottom right of a
+// width by height space that crosses row only at column. row must not be the first or last row.
+fn staircase(rng: &mut Rng, width: usize, height: usize, row: usize, column: usize) -> Vec<Point> {
+    // steps returns the steps between two points in a random order, with true for a step right.
+    let mut steps = |right: usize, down: usize| {
+        let mut steps: Vec<_> = (0..right)
+            .map(|_| true)
+            .chain((0..down).map(|_| false))
+            .collect();
+        rng.shuffle(&mut steps);
+        steps
+    };
+    let mut moves = steps(column, row - 1);
+    moves.extend([false, false]);
+    moves.extend(steps(width - 1 - column, height - 2 - row));
+
+    let mut p = Point::new(0, 0);
+    let mut path = vec![p];
+    for right in moves {
+        p = if right {
+            Point::new(p.x + 1, p.y)
+        } else {
+            Point::new(p.x, p.y + 1)
+        };
+        path.push(p);
+    }
+    path
+}
+
+/// generate returns the bytes falling into a size by size memory space, which is at least 3 by 3.
+/// The first bytes, which are the ones counted for part 1, fall on a fifth of the space but never
+/// on a staircase path from the start to the exit, so the shortest path is as long as the
+/// distance to the exit. Next, bytes fill a row that the staircase crosses once, except for the
+/// location where it crosses, and the byte that falls there is the first to block the exit.
+/// Bytes that fall after it may land anywhere.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(3);
+    let row = 1 + rng.below(size - 2);
+    let column = rng.below(size);
+    let path = staircase(rng, size, size, row, column);
+
+    // used marks the locations on the staircase and those that bytes have fallen on.
+    let mut used = Grid::new(size, size, false);
+    for p in &path {
+        used[*p] = true;
+    }
+    let mut free: Vec<_> = used.points().filter(|p| !used[*p]).collect();
+    rng.shuffle(&mut free);
+    let mut bytes: Vec<_> = free.into_iter().take(size * size / 5).collect();
+    let first = bytes.len();
+    for p in &bytes {
+        used[*p] = true;
+    }
+
+    // The staircase's only location on the row is already marked as used.
+    let mut wall: Vec<_> = (0..size)
+        .map(|x| Point::new(x, row))
+        .filter(|p| !used[*p])
+        .collect();
+    rng.shuffle(&mut wall);
+    let block = Point::new(column, row);
+    wall.push(block);
+    for p in &wall {
+        used[*p] = true;
+    }
+    bytes.extend(wall);
+
+    let mut rest: Vec<_> = used.points().filter(|p| !used[*p]).collect();
+    rng.shuffle(&mut rest);
+    rest.truncate(first / 2);
+    bytes.extend(rest);
+
+    let mut params = Params::new();
+    params.set("width", &size.to_string());
+    params.set("height", &size.to_string());
+    params.set("bytes", &first.to_string());
+
+    Generated {
+        input: bytes.iter().map(|p| format!("{p}\n")).collect(),
+        params,
+        part1: Some((2 * (size - 1)).to_string()),
+        part2: Some(block.to_string()),
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2025 Ian Lewis
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::log::Level;
+use aoc_common::{event, Error, Grid, Lines, Param, Params, Point, Solution};
+
+/// read_input reads the positions of the falling bytes in the order they fall. A Semantic error is
+/// returned for a byte outside the w by h memory space.
+pub fn read_input(r: impl BufRead, w: usize, h: usize) -> Result<Vec<Point>, Error> {
+    let mut memory = Vec::new();
+    for line in Lines::new(r) {
+        let line = line?;
+        let (x, y) = line.token().split_once(",")?;
+        let p = Point::new(x.parse::<usize>("integer")?, y.parse::<usize>("integer")?);
+        if p.x >= w || p.y >= h {
+            return Err(line.semantic(format!("byte is outside the {w}x{h} memory space")));
+        }
+        memory.push(p);
+    }
+
+    Ok(memory)
+}
+
+/// PARAMS declares the parameters of Day18 and their defaults.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "width",
+        default: "71",
+        help: "width of the memory space",
+    },
+    Param {
+        name: "height",
+        default: "71",
+        help: "height of the memory space",
+    },
+    Param {
+        name: "bytes",
+        default: "1024",
+        help: "number of bytes that have fallen for part 1",
+    },
+];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day18 is the solution for day 18.
+pub struct Day18 {
+    /// width is the width of the memory space.
+    pub width: usize,
+    /// height is the height of the memory space.
+    pub height: usize,
+    /// bytes is the number of bytes that have fallen for part 1.
+    pub bytes: usize,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Day18::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day18 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day18 {
+            width: params.value(PARAMS, "width")?,
+            height: params.value(PARAMS, "height")?,
+            bytes: params.value(PARAMS, "bytes")?,
+        })
+    }
+}
+
+impl Solution for Day18 {
+    type Input = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = Point;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        if self.width == 0 || self.height == 0 {
+            return Err(Error::semantic("the memory space must not be empty"));
+        }
+        read_input(r, self.width, self.height)
+    }
+
+    fn part1(&self, memory: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(min_path(memory, self.width, self.height, self.bytes)
+            .ok_or_else(|| Error::no_solution("the exit is unreachable"))?)
+    }
+
+    fn part2(&self, memory: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        first_block(memory, self.width, self.height)
+    }
+}
+
+/// first_block returns the position of the first byte that cuts off the exit at the bottom right
+/// corner of the w by h memory space from the top left corner. A NoSolution error is returned if
+/// no byte does, or if the exit is unreachable before any bytes fall.
+pub fn first_block(memory: &[Point], w: usize, h: usize) -> Result<Point, Box<dyn error::Error>> {
+    if path_exists(memory, w, h, memory.len()) {
+        return Err(Error::no_solution("no byte blocks the path to the exit").into());
+    }
+
+    // Go in reverse until we find a path exists. This is much faster than
+    // starting from the beginning with very sparse memory.
+    for (t, _c) in memory.iter().enumerate().rev() {
+        // Only check if the path exists. Don't bother checking all paths.
+        let exists = path_exists(memory, w, h, t);
+        event!(Level::Debug, "check", bytes = t, path = exists);
+        if exists {
+            return Ok(memory[t]);
+        }
+    }
+
+    Err(Error::no_solution("the path to the exit is blocked before any bytes fall").into())
+}
+
+/// corrupted returns a map of the memory space with the locations corrupted by the first t bytes
+/// set to true.
+pub fn corrupted(memory: &[Point], w: usize, h: usize, t: usize) -> Grid<bool> {
+    let mut map = Grid::new(w, h, false);
+    for p in memory.iter().take(t) {
+        if let Some(c) = map.get_mut(*p) {
+            *c = true;
+        }
+    }
+    map
+}
+
+/// path_exists returns true if the exit at the bottom right corner of the w by h memory space can
+/// be reached from the top left corner after t bytes have fallen.
+pub fn path_exists(memory: &[Point], w: usize, h: usize, t: usize) -> bool {
+    let map = corrupted(memory, w, h, t);
+    let exit = Point::new(w - 1, h - 1);
+
+    let mut stack = collections::VecDeque::new();
+
+    stack.push_back(Point::new(0, 0));
+
+    let mut visited = collections::HashSet::new();
+    while !stack.is_empty() {
+        let cur = stack.pop_front().unwrap();
+
+        if cur == exit {
+            // We found the exit.
+            return true;
+        }
+
+        if visited.contains(&cur) {
+            continue;
+        }
+
+        visited.insert(cur);
+        event!(Level::Trace, "visit", pos = cur, bytes = t);
+
+        for (_dir, next) in map.neighbors4(cur) {
+            if map[next] {
+                continue;
+            }
+
+            stack.push_back(next);
+        }
+    }
+
+    false
+}
+
+/// min_path returns the length of the shortest path from the top left corner to the exit at the
+/// bottom right corner of the w by h memory space after t bytes have fallen. None is returned if
+/// the exit is unreachable.
+pub fn min_path(memory: &[Point], w: usize, h: usize, t: usize) -> Option<usize> {
+    let map = corrupted(memory, w, h, t);
+    let exit = Point::new(w - 1, h - 1);
+
+    let mut paths = Vec::new();
+    let mut stack = collections::VecDeque::new();
+
+    stack.push_back((Point::new(0, 0), 0));
+
+    let mut visited = collections::HashSet::new();
+    while !stack.is_empty() {
+        let (cur, mut path_len) = stack.pop_front().unwrap();
+
+        if cur == exit {
+            // We found the exit.
+            paths.push(path_len);
+            continue;
+        }
+
+        path_len += 1;
+        if visited.contains(&cur) {
+            continue;
+        }
+
+        visited.insert(cur);
+        event!(Level::Trace, "visit", pos = cur, bytes = t);
+
+        for (_dir, next) in map.neighbors4(cur) {
+            if map[next] {
+                continue;
+            }
+
+            stack.push_back((next, path_len));
+        }
+    }
+
+    paths.into_iter().min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+",
+        );
+
+        let (n, n2) = Day18 {
+            width: 7,
+            height: 7,
+            bytes: 12,
+        }
+        .solve(input.reader())?;
+        assert_eq!(n, 22);
+        assert_eq!(n2, Point::new(6, 1));
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Point, Solution};
+
+use crate::Day18;
+
+/// Reference is a slow but simple solution for day 18 that Day18 is checked against. Part 2 drops
+/// the bytes one at a time and searches for a path after each.
+pub struct Reference(pub Day18);
+
+impl Reference {
+    // distance returns the length of the shortest path from the top left to the bottom right
+    // corner after the given bytes have fallen. None is returned if there is no path.
+    fn distance(&self, bytes: &[Point]) -> Option<usize> {
+        let mut corrupted = Grid::new(self.0.width, self.0.height, false);
+        for b in bytes {
+            corrupted[*b] = true;
+        }
+
+        let start = Point::new(0, 0);
+        let exit = Point::new(self.0.width - 1, self.0.height - 1);
+        let mut distances = collections::HashMap::from([(start, 0)]);
+        let mut queue = collections::VecDeque::from([start]);
+        while let Some(p) = queue.pop_front() {
+            for (_, next) in corrupted.neighbors4(p) {
+                if !corrupted[next] && !distances.contains_key(&next) {
+                    distances.insert(next, distances[&p] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances.get(&exit).copied()
+    }
+}
+
+impl Solution for Reference {
+    type Input = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = Point;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        self.0.parse(r)
+    }
+
+    fn part1(&self, memory: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let fallen = &memory[..self.0.bytes.min(memory.len())];
+        Ok(self
+            .distance(fallen)
+            .ok_or_else(|| Error::no_solution("the exit is unreachable"))?)
+    }
+
+    fn part2(&self, memory: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        for t in 1..=memory.len() {
+            if self.distance(&memory[..t]).is_none() {
+                return Ok(memory[t - 1]);
+            }
+        }
+        Err(Error::no_solution("no byte blocks the path to the exit").into())
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+
+use aoc_common::{Color, Frame, Grid, Images, Player, Point, Rgb, Svg, Visualize};
+
+use crate::{corrupted, first_block, Day18};
+
+// FRAMES is roughly the number of frames shown while the bytes fall.
+const FRAMES: usize = 64;
+
+// shortest_path returns the points on a shortest path from the top left corner of the map to the
+// exit at the bottom right corner, or None if the exit is unreachable.
+fn shortest_path(map: &Grid<bool>) -> Option<Vec<Point>> {
+    let start = Point::new(0, 0);
+    let exit = Point::new(map.width().checked_sub(1)?, map.height().checked_sub(1)?);
+    if map[start] || map[exit] {
+        return None;
+    }
+
+    let mut from = collections::HashMap::from([(start, start)]);
+    let mut queue = collections::VecDeque::from([start]);
+    while let Some(p) = queue.pop_front() {
+        if p == exit {
+            let mut path = vec![exit];
+            let mut p = exit;
+            while p != start {
+                p = from[&p];
+                path.push(p);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for (_, next) in map.neighbors4(p) {
+            if !map[next] && !from.contains_key(&next) {
+                from.insert(next, p);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+impl Visualize for Day18 {
+    // visualize shows the bytes falling along with the shortest path to the exit until the path
+    // is cut off.
+    fn visualize(
+        &self,
+        memory: &Vec<Point>,
+        player: &mut Player,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let (w, h) = (self.width, self.height);
+        let block = first_block(memory, w, h)
+            .ok()
+            .and_then(|b| memory.iter().position(|p| *p == b));
+        let end = block.map_or(memory.len(), |i| i + 1);
+
+        let mut times: Vec<_> = (0..end)
+            .step_by((end / FRAMES).max(1))
+            .chain([self.bytes.min(end), end])
+            .collect();
+        times.sort();
+        times.dedup();
+
+        for t in times {
+            if player.stopped() {
+                break;
+            }
+            let map = corrupted(memory, w, h, t);
+            let mut f = Frame::from_grid(&map, |c| if *c { '#' } else { '.' });
+            f.caption(format!("{t} bytes have fallen"));
+            match shortest_path(&map) {
+                Some(path) => {
+                    f.path(&path, Color::Yellow)
+                        .caption(format!("the shortest path takes {} steps", path.len() - 1));
+                }
+                None => {
+                    f.caption("the exit is cut off");
+                }
+            }
+            if t > 0 {
+                f.actor(memory[t - 1], '#', Color::Red);
+            }
+            if t == self.bytes {
+                f.caption("part 1");
+            }
+            player.show(&f)?;
+        }
+        Ok(())
+    }
+
+    // export draws the shortest path through the memory after part 1's bytes have fallen, and
+    // the last path before the byte that cuts off the exit, as PPM and SVG images.
+    fn export(
+        &self,
+        memory: &Vec<Point>,
+        images: &mut Images,
+    ) -> Result<(), Box<dyn error::Error>> {
+        let (w, h) = (self.width, self.height);
+        let block = first_block(memory, w, h)
+            .ok()
+            .and_then(|b| memory.iter().position(|p| *p == b));
+
+        let mut parts = vec![("part1", self.bytes.min(memory.len()), None)];
+        if let Some(i) = block {
+            parts.push(("part2", i, Some(memory[i])));
+        }
+        for (name, t, blocker) in parts {
+            let map = corrupted(memory, w, h, t);
+            let mut image = map.map(|c| if *c { Rgb::GRAY } else { Rgb::WHITE });
+            let mut svg = Svg::from_image(&image, 10);
+            if let Some(path) = shortest_path(&map) {
+                for p in &path {
+                    image[*p] = Color::Yellow.into();
+                }
+                svg.path(&path, Color::Yellow.into());
+            }
+            if let Some(p) = blocker {
+                image[p] = Color::Red.into();
+                svg.rect(p, Color::Red.into());
+            }
+            images.ppm(name, &image)?;
+            images.svg(name, &svg)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_path() {
+        let mut map = Grid::new(3, 3, false);
+        map[Point::new(1, 0)] = true;
+        map[Point::new(1, 1)] = true;
+        let path = shortest_path(&map).unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.last(), Some(&Point::new(2, 2)));
+
+        map[Point::new(1, 2)] = true;
+        assert_eq!(shortest_path(&map), None);
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of designs in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 400;
+
+// PATTERNS is the number of towel patterns.
+const PATTERNS: usize = 400;
+
+// pattern returns a random pattern of 1 to 8 stripes in which every run of green stripes has an
+// even length.
+fn pattern(rng: &mut Rng) -> String {
+    let len = 1 + rng.below(8);
+    let mut p = String::new();
+    while p.len() < len {
+        if rng.chance(1, 6) {
+            p.push_str("gg");
+        } else {
+            p.push(*rng.choose(&['w', 'u', 'b', 'r']));
+        }
+    }
+    p
+}
+
+/// generate returns an input with size designs. Every run of green stripes in a towel pattern has
+/// an even length, so the same is true of every design that can be made. Half of the designs are
+/// made by joining random patterns. The rest are made the same way with a single green stripe
+/// added between two other colors, which no arrangement of patterns can match. The answer to
+/// part 1 is the number of designs made from patterns. The answer to part 2 is not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut patterns: Vec<String> = (0..PATTERNS).map(|_| pattern(rng)).collect();
+    patterns.sort();
+    patterns.dedup();
+    rng.shuffle(&mut patterns);
+
+    let mut possible = 0;
+    let mut designs = Vec::new();
+    while designs.len() < size {
+        // NOTE: Designs are kept short enough that the number of arrangements fits in a u64.
+        let len = 20 + rng.below(31);
+        let mut design = String::new();
+        while design.len() < len {
+            design.push_str(rng.choose(&patterns).as_str());
+        }
+
+        if rng.chance(1, 2) {
+            possible += 1;
+        } else {
+            let b = design.as_bytes();
+            let gaps: Vec<_> = (0..=b.len())
+                .filter(|i| (*i == 0 || b[i - 1] != b'g') && b.get(*i) != Some(&b'g'))
+                .collect();
+            if gaps.is_empty() {
+                continue;
+            }
+            design.insert(*rng.choose(&gaps), 'g');
+        }
+        design.push('\n');
+        designs.push(design);
+    }
+
+    Generated {
+        input: format!("{}\n\n{}", patterns.join(", "), designs.concat()),
+        part1: Some(possible.to_string()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2025 Ian Lewis
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::cmp;
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::log::Level;
+use aoc_common::{event, Error, Lines, Solution, Token};
+
+// read_towels reads a comma separated list of towel patterns or a design made up of towel colors.
+fn read_towels<'a>(t: Token<'a>) -> Result<String, Error> {
+    for (c, ct) in t.chars() {
+        if !"wubrg".contains(c) {
+            return Err(ct.invalid_char("towel color"));
+        }
+    }
+    Ok(t.text.to_string())
+}
+
+/// read_input reads the towel patterns and, after a blank line, the designs. The patterns are
+/// returned longest first.
+pub fn read_input(r: impl BufRead) -> Result<(Vec<String>, Vec<String>), Error> {
+    let mut lines = Lines::new(r);
+
+    // Read and sort in reverse order by length so we test longer keys first. This reduces the
+    // number of comparisons and backtracking we need to do.
+    let patterns_line = lines.expect("towel patterns")?;
+    let mut towel_patterns = patterns_line
+        .split(",")
+        .map(|s| match s.trim() {
+            p if p.text.is_empty() => Err(p.malformed("towel pattern")),
+            p => read_towels(p),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    towel_patterns.sort_by_key(|p| cmp::Reverse(p.len()));
+
+    let empty = lines.expect("empty line")?;
+    if !empty.is_empty() {
+        return Err(empty.semantic("expected empty line"));
+    }
+
+    let mut designs = Vec::new();
+    for line in lines {
+        designs.push(read_towels(line?.token().trim())?);
+    }
+
+    Ok((towel_patterns, designs))
+}
+
+/// valid_design_count returns the number of ways the design can be made from the patterns. None is
+/// returned if the count overflows.
+pub fn valid_design_count(design: &str, patterns: &[String]) -> Option<u64> {
+    let mut visited = collections::HashMap::new();
+    let mut hits = 0;
+    let count = _valid_design_count(design.to_string(), patterns, &mut visited, &mut hits);
+    // Every miss adds an entry to the cache.
+    let misses = visited.len();
+    event!(
+        Level::Debug,
+        "cache",
+        design = design,
+        hits = hits,
+        misses = misses,
+        hit_rate = format!("{:.3}", hits as f64 / (hits + misses).max(1) as f64)
+    );
+    count
+}
+
+// _valid_design_count returns the number of ways the design can be made from the patterns using
+// visited to cache the counts for the ends of designs. hits counts the lookups found in the
+// cache.
+fn _valid_design_count(
+    design: String,
+    patterns: &[String],
+    visited: &mut collections::HashMap<String, Option<u64>>,
+    hits: &mut usize,
+) -> Option<u64> {
+    if design.is_empty() {
+        return Some(1);
+    }
+
+    // We keep a cache of visited values and the count of successful pattern
+    // combinations.
+    if let Some(c) = visited.get(&design) {
+        *hits += 1;
+        return *c;
+    }
+
+    let mut total = Some(0_u64);
+    for p in patterns {
+        if design.len() >= p.len() && design[..p.len()] == *p {
+            let count = _valid_design_count(design[p.len()..].to_string(), patterns, visited, hits);
+            total = total.zip(count).and_then(|(t, c)| t.checked_add(c));
+        }
+    }
+
+    visited.insert(design, total);
+
+    total
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day19 is the solution for day 19.
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<String>, Vec<String>);
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_input(r)
+    }
+
+    fn part1(
+        &self,
+        (patterns, designs): &Self::Input,
+    ) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(designs
+            .iter()
+            .filter(|d| valid_design_count(d, patterns) != Some(0))
+            .count())
+    }
+
+    fn part2(
+        &self,
+        (patterns, designs): &Self::Input,
+    ) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut total: u64 = 0;
+        for d in designs {
+            total = valid_design_count(d, patterns)
+                .and_then(|c| total.checked_add(c))
+                .ok_or("too many arrangements to count")?;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+",
+        );
+
+        let (n, n2) = Day19.solve(input.reader())?;
+        assert_eq!(n, 6);
+        assert_eq!(n2, 16);
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+/// Reference is a slow but simple solution for day 19 that Day19 is checked against. It counts the
+/// arrangements of every suffix of a design from the shortest suffix up.
+pub struct Reference;
+
+// arrangements returns the number of ways to make the design from the patterns. None is returned
+// if the count overflows.
+fn arrangements(design: &str, patterns: &[String]) -> Option<u64> {
+    // ways[i] is the number of ways to make design[i..].
+    let mut ways = vec![Some(0_u64); design.len() + 1];
+    ways[design.len()] = Some(1);
+    for i in (0..design.len()).rev() {
+        for p in patterns
+            .iter()
+            .filter(|p| design[i..].starts_with(p.as_str()))
+        {
+            ways[i] = ways[i]
+                .zip(ways[i + p.len()])
+                .and_then(|(w, n)| w.checked_add(n));
+        }
+    }
+    ways[0]
+}
+
+// possible returns true if the design can be made from the patterns.
+fn possible(design: &str, patterns: &[String]) -> bool {
+    // ok[i] is true if design[i..] can be made.
+    let mut ok = vec![false; design.len() + 1];
+    ok[design.len()] = true;
+    for i in (0..design.len()).rev() {
+        ok[i] = patterns
+            .iter()
+            .any(|p| design[i..].starts_with(p.as_str()) && ok[i + p.len()]);
+    }
+    ok[0]
+}
+
+impl Solution for Reference {
+    type Input = (Vec<String>, Vec<String>);
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_input(r)
+    }
+
+    fn part1(
+        &self,
+        (patterns, designs): &Self::Input,
+    ) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(designs.iter().filter(|d| possible(d, patterns)).count())
+    }
+
+    fn part2(
+        &self,
+        (patterns, designs): &Self::Input,
+    ) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut total: u64 = 0;
+        for d in designs {
+            total = arrangements(d, patterns)
+                .and_then(|n| total.checked_add(n))
+                .ok_or("too many arrangements to count")?;
+        }
+        Ok(total)
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of reports in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 1000;
+
+// safe_report returns a random report whose levels all increase or all decrease by 1 to 3.
+fn safe_report(rng: &mut Rng) -> Vec<i64> {
+    let len = 5 + rng.below(4);
+    let increasing = rng.chance(1, 2);
+    let mut level = if increasing {
+        rng.range(1..60)
+    } else {
+        rng.range(30..90)
+    };
+    let mut report = vec![level];
+    for _ in 1..len {
+        let step = rng.range(1..4);
+        level += if increasing { step } else { -step };
+        report.push(level);
+    }
+    report
+}
+
+/// generate returns an input with size reports. A third of the reports are safe. A third have a
+/// single bad level inserted into a safe report, either repeating its neighbor or jumping by more
+/// than 3, so that they are only safe with the bad level removed. The rest repeat two levels that
+/// are apart from each other, which no single removal can fix.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut safe = 0;
+    let mut dampened = 0;
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut report = safe_report(rng);
+        match rng.below(3) {
+            0 => safe += 1,
+            1 => {
+                let i = rng.below(report.len());
+                let bad = if rng.chance(1, 2) {
+                    report[i]
+                } else {
+                    // NOTE: Levels are kept positive.
+                    let up = report[i] < 10 || rng.chance(1, 2);
+                    report[i] + rng.range(4..10) * if up { 1 } else { -1 }
+                };
+                report.insert(i + 1, bad);
+                dampened += 1;
+            }
+            _ => {
+                let i = rng.below(report.len() - 2);
+                let j = i + 2 + rng.below(report.len() - i - 2);
+                report.insert(j + 1, report[j]);
+                report.insert(i + 1, report[i]);
+            }
+        }
+
+        let levels: Vec<_> = report.iter().map(|l| l.to_string()).collect();
+        input.push_str(&levels.join(" "));
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        part1: Some(safe.to_string()),
+        part2: Some((safe + dampened).to_string()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod policy;
+pub mod reference;
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Explain, Explanation, Lines, Param, Params, Solution, Table};
+
+pub use policy::{Directions, SafetyPolicy};
+
+/// is_safe returns true if the report's levels are within the policy's bounds and every level
+/// follows the one before it as the policy allows, all in the same direction.
+pub fn is_safe(report: &[i64], policy: &SafetyPolicy) -> bool {
+    report.iter().all(|l| policy.in_bounds(*l))
+        && policy
+            .directions
+            .signs()
+            .iter()
+            .any(|sign| report.windows(2).all(|w| policy.allows(*sign, w[0], w[1])))
+}
+
+// MaxTree holds a length for each of a number of slots and finds the largest length in a range
+// of slots in logarithmic time.
+struct MaxTree {
+    n: usize,
+    // nodes holds the slots in its second half. Each node in the first half holds the largest
+    // length of its two children.
+    nodes: Vec<usize>,
+}
+
+impl MaxTree {
+    fn new(n: usize) -> Self {
+        MaxTree {
+            n,
+            nodes: vec![0; 2 * n],
+        }
+    }
+
+    // raise sets the length of slot i to len if it is larger.
+    fn raise(&mut self, i: usize, len: usize) {
+        let mut i = i + self.n;
+        while i > 0 {
+            self.nodes[i] = self.nodes[i].max(len);
+            i /= 2;
+        }
+    }
+
+    // max returns the largest length in slots lo..hi.
+    fn max(&self, lo: usize, hi: usize) -> usize {
+        let (mut lo, mut hi) = (lo + self.n, hi + self.n);
+        let mut best = 0;
+        while lo < hi {
+            if lo % 2 == 1 {
+                best = best.max(self.nodes[lo]);
+                lo += 1;
+            }
+            if hi % 2 == 1 {
+                hi -= 1;
+                best = best.max(self.nodes[hi]);
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+        best
+    }
+}
+
+// longest_run returns the length of the longest subsequence of the report that the policy
+// allows in the direction given by sign. values holds the distinct levels of the report within
+// the policy's bounds in sorted order. The longest subsequence ending with each value seen so
+// far is kept in a MaxTree so that the values a level may follow are searched as a range
+// rather than one by one, taking O(n log n) time for any step sizes.
+fn longest_run(report: &[i64], values: &[i64], sign: i64, policy: &SafetyPolicy) -> usize {
+    let start = |v: i64| values.partition_point(|x| *x < v);
+    let end = |v: i64| values.partition_point(|x| *x <= v);
+    let mut longest = MaxTree::new(values.len());
+    let mut best = 0;
+    for &level in report.iter().filter(|l| policy.in_bounds(**l)) {
+        // level may follow the values that are min_step to max_step below it when increasing,
+        // or above it when decreasing.
+        let range = if sign > 0 {
+            level
+                .checked_sub(policy.min_step)
+                .map(|hi| (level.saturating_sub(policy.max_step), hi))
+        } else {
+            level
+                .checked_add(policy.min_step)
+                .map(|lo| (lo, level.saturating_add(policy.max_step)))
+        };
+        let mut prev = range.map_or(0, |(lo, hi)| longest.max(start(lo), end(hi)));
+        if policy.allow_equal {
+            prev = prev.max(longest.max(start(level), end(level)));
+        }
+        longest.raise(start(level), prev + 1);
+        best = best.max(prev + 1);
+    }
+    best
+}
+
+/// min_removals returns the fewest levels that must be removed from the report to make it safe
+/// under the policy. It is the number of levels that aren't part of the longest safe
+/// subsequence of the report, which is found in O(n log n) time.
+pub fn min_removals(report: &[i64], policy: &SafetyPolicy) -> usize {
+    let mut values: Vec<_> = report
+        .iter()
+        .copied()
+        .filter(|l| policy.in_bounds(*l))
+        .collect();
+    values.sort_unstable();
+    values.dedup();
+    let longest = policy
+        .directions
+        .signs()
+        .iter()
+        .map(|sign| longest_run(report, &values, *sign, policy))
+        .max()
+        .unwrap_or(0);
+    report.len() - longest
+}
+
+/// is_safe_dampened returns true if the report is safe under the policy after removing up to k of
+/// its levels.
+pub fn is_safe_dampened(report: &[i64], k: usize, policy: &SafetyPolicy) -> bool {
+    min_removals(report, policy) <= k
+}
+
+/// safe_counts returns the number of reports that are safe after removing up to k levels for
+/// each k from 0 to max_k under the policy. The fewest removals of each report are only found
+/// once. Every report is safe once all of its levels are removed, so max_k is limited to the
+/// length of the longest report.
+pub fn safe_counts(reports: &[Vec<i64>], max_k: usize, policy: &SafetyPolicy) -> Vec<i64> {
+    let max_k = max_k.min(reports.iter().map(Vec::len).max().unwrap_or(0));
+    let mut counts = vec![0; max_k + 1];
+    for report in reports {
+        let removals = min_removals(report, policy);
+        if removals <= max_k {
+            counts[removals] += 1;
+        }
+    }
+    for k in 1..counts.len() {
+        counts[k] += counts[k - 1];
+    }
+    counts
+}
+
+/// PARAMS declares the parameters of Day2 and their defaults. All but removals set the
+/// SafetyPolicy, and the defaults are the puzzle's rules.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "removals",
+        default: "1",
+        help: "number of levels the Problem Dampener may remove for part 2",
+    },
+    Param {
+        name: "min_step",
+        default: "1",
+        help: "smallest allowed difference between adjacent levels that aren't equal",
+    },
+    Param {
+        name: "max_step",
+        default: "3",
+        help: "largest allowed difference between adjacent levels",
+    },
+    Param {
+        name: "directions",
+        default: "both",
+        help: "directions the levels may change in: increasing, decreasing or both",
+    },
+    Param {
+        name: "allow_equal",
+        default: "false",
+        help: "whether adjacent levels may be equal",
+    },
+    Param {
+        name: "min_level",
+        default: "none",
+        help: "smallest allowed level",
+    },
+    Param {
+        name: "max_level",
+        default: "none",
+        help: "largest allowed level",
+    },
+];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day2 is the solution for day 2.
+pub struct Day2 {
+    /// removals is the number of levels the Problem Dampener may remove from a report.
+    pub removals: usize,
+
+    /// policy is the rules a report must follow to be safe.
+    pub policy: SafetyPolicy,
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Day2::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day2 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day2 {
+            removals: params.value(PARAMS, "removals")?,
+            policy: SafetyPolicy::from_params(params)?,
+        })
+    }
+}
+
+/// read_reports reads a report of levels from each line.
+pub fn read_reports(r: impl BufRead) -> Result<Vec<Vec<i64>>, Error> {
+    let mut reports = Vec::new();
+    for line in Lines::new(r) {
+        let line = line?;
+
+        let mut list = Vec::new();
+        for v in line.fields() {
+            list.push(v.parse::<i64>("level")?);
+        }
+
+        reports.push(list);
+    }
+
+    Ok(reports)
+}
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_reports(r)
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(reports.iter().filter(|r| is_safe(r, &self.policy)).count() as i64)
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(reports
+            .iter()
+            .filter(|r| is_safe_dampened(r, self.removals, &self.policy))
+            .count() as i64)
+    }
+}
+
+impl Explain for Day2 {
+    const EXPLANATIONS: &'static [Explanation] = &[Explanation {
+        name: "dampener",
+        help: "the number of safe reports when removing up to k levels, for k up to --removals",
+    }];
+
+    fn explain(
+        &self,
+        reports: &Self::Input,
+        name: &str,
+    ) -> Result<Vec<Table>, Box<dyn error::Error>> {
+        match name {
+            "dampener" => {
+                let mut table = Table::new(["removals", "safe"]);
+                for (k, safe) in safe_counts(reports, self.removals, &self.policy)
+                    .iter()
+                    .enumerate()
+                {
+                    table.push([k as i64, *safe]);
+                }
+                Ok(vec![table])
+            }
+            _ => Err(format!("unknown explanation: {name:?}").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::TableFormat;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+",
+        );
+
+        let (safe, semi_safe) = Day2::default().solve(input.reader())?;
+        assert_eq!(safe, 2);
+        assert_eq!(semi_safe, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_removals() {
+        let policy = SafetyPolicy::default();
+        assert_eq!(min_removals(&[7, 6, 4, 2, 1], &policy), 0);
+        assert_eq!(min_removals(&[1, 2, 7, 8, 9], &policy), 2);
+        assert_eq!(min_removals(&[9, 7, 6, 2, 1], &policy), 2);
+        assert_eq!(min_removals(&[1, 3, 2, 4, 5], &policy), 1);
+        assert_eq!(min_removals(&[8, 6, 4, 4, 1], &policy), 1);
+        assert_eq!(min_removals(&[1, 9, 2, 9, 3, 9, 4], &policy), 3);
+        assert_eq!(min_removals(&[5, 5, 5, 5], &policy), 3);
+        assert_eq!(min_removals(&[i64::MIN, i64::MAX], &policy), 1);
+        assert_eq!(min_removals(&[], &policy), 0);
+
+        let policy = SafetyPolicy {
+            max_step: 10,
+            directions: Directions::Increasing,
+            allow_equal: true,
+            min_level: Some(0),
+            ..policy
+        };
+        assert_eq!(min_removals(&[1, 9, 2, 9, 3, 9, 4], &policy), 3);
+        assert_eq!(min_removals(&[9, 7, 6, 2, 1], &policy), 4);
+        assert_eq!(min_removals(&[5, 5, 5, 5], &policy), 0);
+        assert_eq!(min_removals(&[-1, 0, 30, 40, -2], &policy), 3);
+        assert!(is_safe(&[5, 5, 15, 16], &policy));
+        assert!(!is_safe(&[-1, 0], &policy));
+        assert!(!is_safe(&[16, 15], &policy));
+    }
+
+    #[test]
+    fn test_safe_counts() -> Result<(), Box<dyn error::Error>> {
+        let input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+        let day2 = Day2 {
+            removals: 3,
+            ..Day2::default()
+        };
+        let reports = day2.parse(input.as_bytes())?;
+        assert_eq!(safe_counts(&reports, 3, &day2.policy), [2, 4, 6, 6]);
+        assert_eq!(
+            safe_counts(&reports, usize::MAX, &day2.policy),
+            [2, 4, 6, 6, 6, 6]
+        );
+        assert_eq!(day2.solve(input.as_bytes())?, (2, 6));
+
+        let mut text = Vec::new();
+        TableFormat::Text.write(&mut text, &day2.explain(&reports, "dampener")?)?;
+        assert_eq!(
+            String::from_utf8(text)?,
+            "removals  safe
+       0     2
+       1     4
+       2     6
+       3     6
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference() -> Result<(), Box<dyn error::Error>> {
+        let generated = generator::generate(&mut aoc_common::Rng::new(2), 300);
+        let input = generated.input.as_bytes();
+        let policies = [
+            SafetyPolicy::default(),
+            SafetyPolicy::read("max_step = 6\nallow_equal = true\n".as_bytes())?,
+            SafetyPolicy::read(
+                "min_step = 2\ndirections = decreasing\nmax_level = 60\n".as_bytes(),
+            )?,
+        ];
+        for policy in policies {
+            for removals in 0..=3 {
+                let day2 = || Day2 { removals, policy };
+                assert_eq!(
+                    day2().solve(input)?,
+                    reference::Reference(day2()).solve(input)?,
+                    "removals = {removals}, policy:\n{policy}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_params() -> Result<(), Box<dyn error::Error>> {
+        let mut params = Params::new();
+        params.set("removals", "2");
+        assert_eq!(Day2::from_params(&params)?.removals, 2);
+        params.set("max_level", "50");
+        assert_eq!(Day2::from_params(&params)?.policy.max_level, Some(50));
+        params.set("removals", "-1");
+        assert!(Day2::from_params(&params).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsafe() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "1 2 7 8 9
+9 7 6 2 1
+",
+        );
+
+        let (safe, semi_safe) = Day2::default().solve(input.reader())?;
+        assert_eq!(safe, 0);
+        assert_eq!(semi_safe, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_safe_first_val() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "5 0 1 2 3
+",
+        );
+
+        let (safe, semi_safe) = Day2::default().solve(input.reader())?;
+        assert_eq!(safe, 0);
+        assert_eq!(semi_safe, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_safe_last_val() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "1 2 3 4 0
+",
+        );
+
+        let (safe, semi_safe) = Day2::default().solve(input.reader())?;
+        assert_eq!(safe, 0);
+        assert_eq!(semi_safe, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_safe_direction_first_val() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "1 0 1 2 3
+",
+        );
+
+        let (safe, semi_safe) = Day2::default().solve(input.reader())?;
+        assert_eq!(safe, 0);
+        assert_eq!(semi_safe, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_safe_direction_last_val() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "0 1 2 3 2
+",
+        );
+
+        let (safe, semi_safe) = Day2::default().solve(input.reader())?;
+        assert_eq!(safe, 0);
+        assert_eq!(semi_safe, 1);
+        Ok(())
+    }
+}
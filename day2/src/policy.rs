@@ -0,0 +1,244 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use aoc_common::{Error, Params};
+
+use crate::PARAMS;
+
+// NAMES are the parameters of Day2 that set its SafetyPolicy.
+const NAMES: &[&str] = &[
+    "min_step",
+    "max_step",
+    "directions",
+    "allow_equal",
+    "min_level",
+    "max_level",
+];
+
+/// Directions are the directions the levels of a safe report may change in.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Directions {
+    /// Increasing reports have levels that only increase.
+    Increasing,
+
+    /// Decreasing reports have levels that only decrease.
+    Decreasing,
+
+    /// Both allows reports that only increase or only decrease.
+    Both,
+}
+
+impl Directions {
+    /// signs returns the sign of the difference between adjacent levels for each allowed
+    /// direction.
+    pub fn signs(&self) -> &'static [i64] {
+        match self {
+            Directions::Increasing => &[1],
+            Directions::Decreasing => &[-1],
+            Directions::Both => &[1, -1],
+        }
+    }
+}
+
+impl FromStr for Directions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "increasing" => Ok(Directions::Increasing),
+            "decreasing" => Ok(Directions::Decreasing),
+            "both" => Ok(Directions::Both),
+            _ => Err(format!("invalid directions: {s:?}")),
+        }
+    }
+}
+
+impl fmt::Display for Directions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Directions::Increasing => write!(f, "increasing"),
+            Directions::Decreasing => write!(f, "decreasing"),
+            Directions::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// SafetyPolicy holds the rules a report must follow to be safe. The default policy is the
+/// puzzle's: levels all increase or all decrease by 1 to 3.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct SafetyPolicy {
+    /// min_step is the smallest allowed difference between adjacent levels other than equal
+    /// levels. It is at least 1.
+    pub min_step: i64,
+
+    /// max_step is the largest allowed difference between adjacent levels.
+    pub max_step: i64,
+
+    /// directions are the directions the levels may change in.
+    pub directions: Directions,
+
+    /// allow_equal is true if adjacent levels may be equal.
+    pub allow_equal: bool,
+
+    /// min_level is the smallest allowed level, if any.
+    pub min_level: Option<i64>,
+
+    /// max_level is the largest allowed level, if any.
+    pub max_level: Option<i64>,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy::from_params(&Params::new()).expect("invalid default policy")
+    }
+}
+
+impl SafetyPolicy {
+    /// from_params returns the policy set by the parameters of Day2, with the defaults in
+    /// PARAMS for the rules that are not set. Other parameters are ignored. An error is
+    /// returned if a value is invalid or the rules contradict each other.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        let policy = SafetyPolicy {
+            min_step: params.value(PARAMS, "min_step")?,
+            max_step: params.value(PARAMS, "max_step")?,
+            directions: params.value(PARAMS, "directions")?,
+            allow_equal: params.value(PARAMS, "allow_equal")?,
+            min_level: level(params, "min_level")?,
+            max_level: level(params, "max_level")?,
+        };
+        if policy.min_step < 1 {
+            return Err(Error::semantic(
+                "min_step must be at least 1; set allow_equal to allow equal levels",
+            ));
+        }
+        if policy.max_step < policy.min_step {
+            return Err(Error::semantic("max_step is less than min_step"));
+        }
+        if let (Some(min), Some(max)) = (policy.min_level, policy.max_level) {
+            if max < min {
+                return Err(Error::semantic("max_level is less than min_level"));
+            }
+        }
+        Ok(policy)
+    }
+
+    /// read reads a policy from "name = value" lines naming the rules, such as "max_step = 5".
+    /// The rules that are not given are the defaults. An error is returned for unknown names.
+    pub fn read(r: impl BufRead) -> Result<Self, Error> {
+        let params = Params::read(r)?;
+        params.check_known(NAMES)?;
+        SafetyPolicy::from_params(&params)
+    }
+
+    /// in_bounds returns true if the level is within the policy's absolute bounds.
+    pub fn in_bounds(&self, level: i64) -> bool {
+        self.min_level.is_none_or(|min| level >= min)
+            && self.max_level.is_none_or(|max| level <= max)
+    }
+
+    /// allows returns true if the level may follow prev in a report changing in the direction
+    /// given by sign, 1 for increasing or -1 for decreasing.
+    pub fn allows(&self, sign: i64, prev: i64, level: i64) -> bool {
+        match level.checked_sub(prev) {
+            Some(0) => self.allow_equal,
+            Some(d) => d
+                .checked_mul(sign)
+                .is_some_and(|d| (self.min_step..=self.max_step).contains(&d)),
+            None => false,
+        }
+    }
+}
+
+// level returns the bound on levels set by the named parameter, or None if it is "none".
+fn level(params: &Params, name: &str) -> Result<Option<i64>, Error> {
+    let v: String = params.value(PARAMS, name)?;
+    match v.as_str() {
+        "none" => Ok(None),
+        _ => v
+            .parse()
+            .map(Some)
+            .map_err(|_| Error::semantic(format!("invalid value for parameter {name}: {v:?}"))),
+    }
+}
+
+impl fmt::Display for SafetyPolicy {
+    // fmt writes the policy as "name = value" lines that can be read back with read.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bound = |b: Option<i64>| b.map_or("none".to_string(), |b| b.to_string());
+        writeln!(f, "allow_equal = {}", self.allow_equal)?;
+        writeln!(f, "directions = {}", self.directions)?;
+        writeln!(f, "max_level = {}", bound(self.max_level))?;
+        writeln!(f, "max_step = {}", self.max_step)?;
+        writeln!(f, "min_level = {}", bound(self.min_level))?;
+        writeln!(f, "min_step = {}", self.min_step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() -> Result<(), Error> {
+        let input = "# Reactor 7 tolerates larger steps.
+max_step = 5
+directions = increasing
+min_level=0
+";
+        let policy = SafetyPolicy::read(input.as_bytes())?;
+        assert_eq!(
+            policy,
+            SafetyPolicy {
+                max_step: 5,
+                directions: Directions::Increasing,
+                min_level: Some(0),
+                ..SafetyPolicy::default()
+            }
+        );
+        assert_eq!(SafetyPolicy::read(policy.to_string().as_bytes())?, policy);
+
+        let err = SafetyPolicy::read("removals = 2\n".as_bytes()).unwrap_err();
+        assert!(err.message().starts_with("unknown parameter removals"));
+        assert!(SafetyPolicy::read("min_step = 0\n".as_bytes()).is_err());
+        assert!(SafetyPolicy::read("min_step = 4\n".as_bytes()).is_err());
+        assert!(SafetyPolicy::read("min_level = 5\nmax_level = 4\n".as_bytes()).is_err());
+        assert!(SafetyPolicy::read("directions = sideways\n".as_bytes()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_allows() {
+        let policy = SafetyPolicy::default();
+        assert!(policy.allows(1, 1, 4));
+        assert!(!policy.allows(1, 4, 1));
+        assert!(policy.allows(-1, 4, 1));
+        assert!(!policy.allows(1, 1, 1));
+        assert!(!policy.allows(1, 1, 5));
+        assert!(!policy.allows(-1, i64::MAX, i64::MIN));
+        assert!(policy.in_bounds(i64::MIN));
+
+        let policy = SafetyPolicy {
+            allow_equal: true,
+            max_level: Some(10),
+            ..policy
+        };
+        assert!(policy.allows(1, 1, 1));
+        assert!(policy.allows(-1, 1, 1));
+        assert!(!policy.in_bounds(11));
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+use crate::{Day2, Directions, SafetyPolicy};
+
+/// Reference is a slow but simple solution for day 2 that Day2 is checked against. It tries
+/// removing every combination of up to the given number of levels.
+pub struct Reference(pub Day2);
+
+// is_safe returns true if the levels are within the policy's bounds and all increase or all
+// decrease by steps the policy allows.
+fn is_safe(levels: &[i64], policy: &SafetyPolicy) -> bool {
+    let in_bounds = levels.iter().all(|l| {
+        policy.min_level.is_none_or(|min| *l >= min) && policy.max_level.is_none_or(|max| *l <= max)
+    });
+    let diffs: Vec<i64> = levels.windows(2).map(|w| w[1] - w[0]).collect();
+    let steps = policy.min_step..=policy.max_step;
+    let allowed =
+        |d: &i64, sign: i64| (*d == 0 && policy.allow_equal) || steps.contains(&(d * sign));
+    let increasing =
+        policy.directions != Directions::Decreasing && diffs.iter().all(|d| allowed(d, 1));
+    let decreasing =
+        policy.directions != Directions::Increasing && diffs.iter().all(|d| allowed(d, -1));
+    in_bounds && (increasing || decreasing)
+}
+
+// is_safe_dampened returns true if the levels are safe after removing up to k of them.
+fn is_safe_dampened(levels: &[i64], k: usize, policy: &SafetyPolicy) -> bool {
+    is_safe(levels, policy)
+        || (k > 0
+            && (0..levels.len()).any(|i| {
+                let mut dampened = levels.to_vec();
+                dampened.remove(i);
+                is_safe_dampened(&dampened, k - 1, policy)
+            }))
+}
+
+impl Solution for Reference {
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_reports(r)
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(reports
+            .iter()
+            .filter(|r| is_safe(r, &self.0.policy))
+            .count() as i64)
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let safe = reports
+            .iter()
+            .filter(|r| is_safe_dampened(r, self.0.removals, &self.0.policy));
+        Ok(safe.count() as i64)
+    }
+}
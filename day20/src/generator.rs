@@ -0,0 +1,59 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::generator::{maze, maze_path};
+use aoc_common::{Generated, Grid, Params, Point, Rng};
+
+use crate::Day20;
+
+/// DEFAULT_SIZE is the width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 141;
+
+/// generate returns a size by size racetrack, rounded down to an odd size of at least 5. The
+/// track is the path between two random locations of a random maze and every other location is a
+/// wall, so there is a single path from the start to the end. Tracks smaller than a real puzzle
+/// input have too few cheats that save 100 picoseconds, so the minimum saving is scaled down with
+/// the size. The answers are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = (size.max(5) - 1) / 2 * 2 + 1;
+    let maze = maze(rng, size);
+
+    // room returns a random location with odd coordinates, which is always open.
+    let mut room = || Point::new(1 + 2 * rng.below(size / 2), 1 + 2 * rng.below(size / 2));
+    let start = room();
+    let mut end = room();
+    while end == start {
+        end = room();
+    }
+
+    let mut track = Grid::new(size, size, '#');
+    for p in maze_path(&maze, start, end) {
+        track[p] = '.';
+    }
+    track[start] = 'S';
+    track[end] = 'E';
+
+    let mut params = Params::new();
+    let min_save = Day20::default().min_save * size / DEFAULT_SIZE;
+    if size < DEFAULT_SIZE {
+        params.set("min_save", &min_save.to_string());
+        params.set("min_save2", &min_save.to_string());
+    }
+
+    Generated {
+        input: track.to_string(),
+        params,
+        ..Default::default()
+    }
+}
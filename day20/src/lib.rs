@@ -0,0 +1,245 @@
+// Copyright 2025 Ian Lewis
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Param, Params, Point, Solution};
+
+/// Map is the racetrack along with its start and end tiles.
+pub struct Map {
+    start: Point,
+    end: Point,
+    map: Grid<char>,
+}
+
+impl Map {
+    /// start returns the position of the start tile.
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// end returns the position of the end tile.
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    /// grid returns the racetrack's grid. Walls are '#'.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.map
+    }
+}
+
+/// read_map reads the racetrack. A Semantic error is returned if it has no start or end tile.
+pub fn read_map(r: impl BufRead) -> Result<Map, Error> {
+    let map = Grid::read_charset(r, "#.SE")?;
+    Ok(Map {
+        start: map
+            .find('S')
+            .ok_or_else(|| Error::semantic("no start tile found"))?,
+        end: map
+            .find('E')
+            .ok_or_else(|| Error::semantic("no end tile found"))?,
+        map,
+    })
+}
+
+/// maze_path returns the points on the path from the start to the end. The racetrack is expected
+/// to have a single path. A NoSolution error is returned if the end cannot be reached.
+pub fn maze_path(Map { start, end, map }: &Map) -> Result<Vec<Point>, Error> {
+    let mut stack = collections::VecDeque::new();
+    let mut visited = collections::HashSet::new();
+
+    stack.push_back((*start, Vec::new()));
+
+    while !stack.is_empty() {
+        let (cur, mut path) = stack.pop_back().unwrap();
+
+        if cur == *end {
+            path.push(cur);
+            return Ok(path);
+        }
+
+        if map[cur] == '#' {
+            continue;
+        }
+
+        if visited.contains(&cur) {
+            continue;
+        }
+
+        path.push(cur);
+
+        for (_dir, next) in map.neighbors4(cur) {
+            stack.push_back((next, path.clone()));
+        }
+
+        visited.insert(cur);
+    }
+
+    Err(Error::no_solution(
+        "there is no path from the start to the end",
+    ))
+}
+
+/// find_cheats returns the number of cheats that save at least min_saved picoseconds by moving
+/// through walls for at most max_cheat_length picoseconds. A cheat is identified by its start and
+/// end positions, which are both on the path.
+pub fn find_cheats(path: &[Point], max_cheat_length: usize, min_saved: usize) -> usize {
+    let mut cheats = collections::HashSet::new();
+
+    // For each tile in the path to the exit, find another tile later in the path that is within
+    // the max_cheat_length number of tiles and at least max_saved tiles further down the path.
+
+    for (start_pos, start) in path.iter().enumerate() {
+        for (end_pos, end) in path.iter().enumerate().skip(start_pos + min_saved) {
+            let cheat_length = start.manhattan(end);
+            let picos_saved = (end_pos as isize) - (start_pos as isize) - (cheat_length as isize);
+            if cheat_length <= max_cheat_length && picos_saved >= (min_saved as isize) {
+                cheats.insert((start, end));
+            }
+        }
+    }
+
+    cheats.len()
+}
+
+/// PARAMS declares the parameters of Day20 and their defaults.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "max_cheat_length",
+        default: "2",
+        help: "maximum length of a cheat for part 1",
+    },
+    Param {
+        name: "min_save",
+        default: "100",
+        help: "minimum number of picoseconds a cheat must save for part 1",
+    },
+    Param {
+        name: "max_cheat_length2",
+        default: "20",
+        help: "maximum length of a cheat for part 2",
+    },
+    Param {
+        name: "min_save2",
+        default: "100",
+        help: "minimum number of picoseconds a cheat must save for part 2",
+    },
+];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day20 is the solution for day 20.
+pub struct Day20 {
+    /// max_cheat_length is the maximum length of a cheat for part 1.
+    pub max_cheat_length: usize,
+    /// min_save is the minimum number of picoseconds a cheat must save for part 1.
+    pub min_save: usize,
+    /// max_cheat_length2 is the maximum length of a cheat for part 2.
+    pub max_cheat_length2: usize,
+    /// min_save2 is the minimum number of picoseconds a cheat must save for part 2.
+    pub min_save2: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Day20::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day20 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day20 {
+            max_cheat_length: params.value(PARAMS, "max_cheat_length")?,
+            min_save: params.value(PARAMS, "min_save")?,
+            max_cheat_length2: params.value(PARAMS, "max_cheat_length2")?,
+            min_save2: params.value(PARAMS, "min_save2")?,
+        })
+    }
+}
+
+impl Solution for Day20 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_map(r)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(find_cheats(
+            &maze_path(map)?,
+            self.max_cheat_length,
+            self.min_save,
+        ))
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(find_cheats(
+            &maze_path(map)?,
+            self.max_cheat_length2,
+            self.min_save2,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+",
+        );
+
+        let (n, n2) = Day20 {
+            max_cheat_length: 2,
+            min_save: 1,
+            max_cheat_length2: 20,
+            min_save2: 50,
+        }
+        .solve(input.reader())?;
+        assert_eq!(n, 44);
+        assert_eq!(n2, 285);
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Point, Solution};
+
+use crate::{Day20, Map};
+
+/// Reference is a slow but simple solution for day 20 that Day20 is checked against. It tries a
+/// cheat between every pair of track locations.
+pub struct Reference(pub Day20);
+
+// distances returns the length of the shortest path from p to every reachable track location.
+fn distances(map: &Grid<char>, p: Point) -> collections::HashMap<Point, usize> {
+    let mut distances = collections::HashMap::from([(p, 0)]);
+    let mut queue = collections::VecDeque::from([p]);
+    while let Some(cur) = queue.pop_front() {
+        for (_, next) in map.neighbors4(cur) {
+            if map[next] != '#' && !distances.contains_key(&next) {
+                distances.insert(next, distances[&cur] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+// cheats returns the number of cheats of at most max_len picoseconds that save at least min_save
+// picoseconds.
+fn cheats(map: &Map, max_len: usize, min_save: usize) -> Result<usize, Error> {
+    let from_start = distances(&map.map, map.start);
+    let to_end = distances(&map.map, map.end);
+    let best = *from_start
+        .get(&map.end)
+        .ok_or_else(|| Error::no_solution("there is no path from the start to the end"))?;
+
+    let mut total = 0;
+    for (a, da) in &from_start {
+        for (b, db) in &to_end {
+            let len = a.manhattan(b);
+            if len <= max_len && da + len + db + min_save <= best {
+                total += 1;
+            }
+        }
+    }
+    Ok(total)
+}
+
+impl Solution for Reference {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        self.0.parse(r)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(cheats(map, self.0.max_cheat_length, self.0.min_save)?)
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(cheats(map, self.0.max_cheat_length2, self.0.min_save2)?)
+    }
+}
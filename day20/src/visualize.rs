@@ -0,0 +1,162 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+
+use aoc_common::{Color, Frame, Images, Player, Point, Rgb, Svg, Visualize};
+
+use crate::{maze_path, Day20, Map};
+
+// Cheats holds the cheats of at most a given length that save at least a given time.
+struct Cheats {
+    // starts holds the positions where cheats start.
+    starts: Vec<Point>,
+    // count is the number of cheats.
+    count: usize,
+    // best is the start and end of the cheat that saves the most time and the time it saves.
+    best: Option<(Point, Point, usize)>,
+}
+
+// cheats finds the cheats along the path like find_cheats but also keeps where they start and
+// which one is best.
+fn cheats(path: &[Point], max_cheat_length: usize, min_saved: usize) -> Cheats {
+    let mut cheats = Cheats {
+        starts: Vec::new(),
+        count: 0,
+        best: None,
+    };
+    for (i, start) in path.iter().enumerate() {
+        let mut starts_here = false;
+        for (j, end) in path.iter().enumerate().skip(i + min_saved) {
+            let length = start.manhattan(end);
+            let saved = (j - i).saturating_sub(length);
+            if length <= max_cheat_length && saved >= min_saved {
+                cheats.count += 1;
+                starts_here = true;
+                if cheats.best.is_none_or(|(_, _, s)| saved > s) {
+                    cheats.best = Some((*start, *end, saved));
+                }
+            }
+        }
+        if starts_here {
+            cheats.starts.push(*start);
+        }
+    }
+    cheats
+}
+
+impl Visualize for Day20 {
+    // visualize shows the path around the racetrack and then, for each part, where the cheats
+    // start and the cheat that saves the most time.
+    fn visualize(&self, map: &Map, player: &mut Player) -> Result<(), Box<dyn error::Error>> {
+        let path = maze_path(map)?;
+        let grid = map.grid();
+        let mut base = Frame::from_chars(grid);
+        let walls: Vec<_> = grid.points().filter(|p| grid[*p] == '#').collect();
+        base.color(walls, Color::Blue)
+            .actor(map.start(), 'S', Color::Red)
+            .actor(map.end(), 'E', Color::Red);
+
+        let mut f = base.clone();
+        f.path(&path, Color::Yellow)
+            .actor(map.start(), 'S', Color::Red)
+            .actor(map.end(), 'E', Color::Red)
+            .caption(format!("the race takes {} picoseconds", path.len() - 1));
+        player.show(&f)?;
+
+        let parts = [
+            (1, self.max_cheat_length, self.min_save),
+            (2, self.max_cheat_length2, self.min_save2),
+        ];
+        for (part, max_len, min_save) in parts {
+            if player.stopped() {
+                break;
+            }
+            let c = cheats(&path, max_len, min_save);
+            let mut f = base.clone();
+            f.highlight(c.starts.iter().copied(), Color::Magenta)
+                .caption(format!(
+                "part {part}: {} cheats of up to {max_len} picoseconds save at least {min_save}",
+                c.count
+            ));
+            if let Some((start, end, saved)) = c.best {
+                f.actor(start, '1', Color::Green)
+                    .actor(end, '2', Color::Green)
+                    .caption(format!(
+                        "the best cheat, from {start} to {end}, saves {saved}"
+                    ));
+            }
+            player.show(&f)?;
+        }
+        Ok(())
+    }
+
+    // export draws the path around the racetrack as PPM and SVG images and, for each part, the
+    // route taken with the best cheat as an SVG image.
+    fn export(&self, map: &Map, images: &mut Images) -> Result<(), Box<dyn error::Error>> {
+        let path = maze_path(map)?;
+        let grid = map.grid();
+        let track = grid.map(|c| if *c == '#' { Rgb::GRAY } else { Rgb::WHITE });
+
+        let mut image = track.clone();
+        for p in &path {
+            image[*p] = Color::Yellow.into();
+        }
+        image[map.start()] = Color::Red.into();
+        image[map.end()] = Color::Red.into();
+        images.ppm("race", &image)?;
+
+        let mut svg = Svg::from_image(&track, 10);
+        svg.path(&path, Color::Yellow.into())
+            .circle(map.start(), Color::Red.into())
+            .circle(map.end(), Color::Red.into());
+        images.svg("race", &svg)?;
+
+        let parts = [
+            (1, self.max_cheat_length, self.min_save),
+            (2, self.max_cheat_length2, self.min_save2),
+        ];
+        for (part, max_len, min_save) in parts {
+            let Some((start, end, _)) = cheats(&path, max_len, min_save).best else {
+                continue;
+            };
+            let i = path.iter().position(|p| *p == start).unwrap_or(0);
+            let j = path.iter().position(|p| *p == end).unwrap_or(path.len());
+            let mut svg = Svg::from_image(&track, 10);
+            svg.path(&path[..=i], Color::Yellow.into())
+                .path(&[start, end], Color::Green.into())
+                .path(&path[j..], Color::Yellow.into())
+                .circle(map.start(), Color::Red.into())
+                .circle(map.end(), Color::Red.into());
+            images.svg(&format!("cheat-{part}"), &svg)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_cheats;
+
+    #[test]
+    fn test_cheats() -> Result<(), Box<dyn error::Error>> {
+        let input = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/example.in.txt"))?;
+        let path = maze_path(&crate::read_map(&input[..])?)?;
+        let c = cheats(&path, 2, 20);
+        assert_eq!(c.count, find_cheats(&path, 2, 20));
+        assert_eq!(c.best.map(|(_, _, saved)| saved), Some(64));
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of codes in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 5;
+
+/// generate returns an input with size codes of three random digits followed by A. The answers
+/// are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    Generated {
+        input: (0..size)
+            .map(|_| format!("{:03}A\n", rng.below(1000)))
+            .collect(),
+        ..Default::default()
+    }
+}
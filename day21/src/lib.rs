@@ -0,0 +1,468 @@
+// Copyright 2025 Ian Lewis
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::log::Level;
+use aoc_common::{event, Direction, Error, Lines, Param, Params, Point, Solution};
+
+/// read_input reads a door code from each line.
+pub fn read_input(r: impl BufRead) -> Result<Vec<Vec<char>>, Error> {
+    let mut codes = Vec::new();
+    for line in Lines::new(r) {
+        let mut code = Vec::new();
+        for (c, t) in line?.chars() {
+            if !"0123456789A".contains(c) {
+                return Err(t.invalid_char("numeric keypad button"));
+            }
+            code.push(c);
+        }
+        codes.push(code);
+    }
+
+    Ok(codes)
+}
+
+/// Keypad is a keypad of buttons laid out on a grid. Paths between buttons are cached as they are
+/// found.
+#[derive(Clone)]
+pub struct Keypad {
+    // NOTE: We hold the map in a BTreeMap because it is hashable.
+    //       We use this property to use the map as part of a cache key later.
+    _map: collections::BTreeMap<char, Point>,
+    _pos_map: collections::HashMap<Point, char>,
+    _paths_cache: collections::HashMap<(char, char), Vec<Vec<char>>>,
+}
+
+impl Keypad {
+    /// new returns a keypad with each button at the given position.
+    pub fn new(map: collections::HashMap<char, Point>) -> Self {
+        Keypad {
+            _pos_map: map.iter().map(|(k, v)| (*v, *k)).collect(),
+            _map: map.into_iter().collect(),
+            _paths_cache: collections::HashMap::new(),
+        }
+    }
+
+    /// from_layout creates a keypad from rows of buttons. Gaps in the keypad are given as spaces.
+    pub fn from_layout(layout: &[&str]) -> Self {
+        let mut map = collections::HashMap::new();
+        for (y, row) in layout.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if c != ' ' {
+                    map.insert(c, Point::new(x, y));
+                }
+            }
+        }
+
+        Keypad::new(map)
+    }
+
+    /// new_numpad returns the numeric keypad on the door.
+    pub fn new_numpad() -> Self {
+        Keypad::from_layout(&["789", "456", "123", " 0A"])
+    }
+
+    /// new_dirpad returns a directional keypad used to control a robot arm.
+    pub fn new_dirpad() -> Self {
+        Keypad::from_layout(&[" ^A", "<v>"])
+    }
+
+    /// get_min_paths returns the paths of minimum length to get from button a to button b that
+    /// don't cross a gap. Each path is the directional buttons to press followed by 'A' to press
+    /// button b.
+    pub fn get_min_paths(&mut self, a: char, b: char) -> Vec<Vec<char>> {
+        if let Some(paths) = self._paths_cache.get(&(a, b)) {
+            return paths.to_vec();
+        }
+
+        let mut stack = collections::VecDeque::new();
+        stack.push_back((a, collections::HashSet::new(), Vec::new()));
+
+        let mut paths = Vec::new();
+        while !stack.is_empty() {
+            let (button, mut visited, mut path) = stack.pop_back().unwrap();
+
+            if let Some(pos) = self._map.get(&button) {
+                if button == b {
+                    path.push('A');
+                    paths.push(path);
+                    continue;
+                }
+
+                if visited.contains(&button) {
+                    continue;
+                }
+                visited.insert(button);
+
+                for dir in Direction::CARDINAL {
+                    let next_button = pos.step(dir, 1).and_then(|p| self._pos_map.get(&p));
+
+                    if let (Some(next_button), Some(dir_char)) = (next_button, dir.arrow()) {
+                        let mut new_path = path.clone();
+                        new_path.push(dir_char);
+                        stack.push_back((*next_button, visited.clone(), new_path.clone()));
+                    }
+                }
+            }
+        }
+
+        // Get minimum length of the found paths.
+        let min_len = paths.iter().map(|p| p.len()).min().unwrap_or(0);
+        // Get the paths of minimum length.
+        let filtered: Vec<_> = paths.into_iter().filter(|p| p.len() <= min_len).collect();
+        // Insert into the cache.
+        self._paths_cache.insert((a, b), filtered.clone());
+
+        // Return paths of minimum length.
+        filtered
+    }
+}
+
+/// RobotChain represents a chain of directional keypads that robots type into to a keypad.
+pub struct RobotChain {
+    _keypad: Keypad,
+    _dir_keypad: Keypad,
+    _chain_len: usize,
+    _cost_cache: collections::HashMap<(String, usize, collections::BTreeMap<char, Point>), usize>,
+    _cache_hits: usize,
+    _cache_misses: usize,
+}
+
+impl RobotChain {
+    /// new creates a new RobotChain with the given number of directional keypads (including the
+    /// human).
+    pub fn new(keypad: Keypad, num_dir_keypads: usize) -> Self {
+        RobotChain {
+            _keypad: keypad,
+            _chain_len: num_dir_keypads,
+            _dir_keypad: Keypad::new_dirpad(),
+            _cost_cache: collections::HashMap::new(),
+            _cache_hits: 0,
+            _cache_misses: 0,
+        }
+    }
+
+    /// calc_cost returns the total number of button presses required to enter the given code on the
+    /// given keypad at the human's position in the chain.
+    pub fn calc_cost(&mut self, code: &[char]) -> usize {
+        self._calc_cost(code, self._chain_len, self._keypad.clone())
+    }
+
+    // _calc_cost returns the number of button presses required to enter the code on the keypad at
+    // the given position in the chain, counted from the human's end.
+    fn _calc_cost(&mut self, code: &[char], chain_len: usize, mut keypad: Keypad) -> usize {
+        // Check the cost_cache in case we have processed this code before.
+        // This is necessary to allow this to run in a reasonable amount of time.
+        if let Some(cost) =
+            self._cost_cache
+                .get(&(String::from_iter(code), chain_len, keypad._map.clone()))
+        {
+            self._cache_hits += 1;
+            return *cost;
+        }
+        self._cache_misses += 1;
+
+        let mut total_cost = 0;
+
+        if chain_len == 0 {
+            return code.len();
+        }
+
+        // Get all button start and end values in a chain starting from A.
+        // e.g. ABCDE -> AB,BC,CD,DE
+        let button_paths = [&'A'].into_iter().chain(code).zip(code);
+
+        for (a, b) in button_paths {
+            let mut costs = Vec::new();
+
+            // Find the all combinations of presses of minimum length needed to enter the code in a
+            // directional keypad for the start and end buttons.
+            for path in keypad.get_min_paths(*a, *b) {
+                // Calculate the cost of the entering this key combination in further down the chain.
+                let path_cost = self._calc_cost(&path, chain_len - 1, self._dir_keypad.clone());
+                costs.push(path_cost)
+            }
+
+            // Add the minimum cost associated with this button path.
+            total_cost += costs.iter().min().unwrap_or(&0);
+        }
+
+        self._cost_cache.insert(
+            (String::from_iter(code), chain_len, keypad._map),
+            total_cost,
+        );
+
+        total_cost
+    }
+}
+
+/// complexity returns the sum of the complexities of the codes when typed through a chain of the
+/// given number of directional keypads, including the human's. A code's complexity is the number
+/// of button presses needed to type it multiplied by its numeric part.
+pub fn complexity(codes: &[Vec<char>], robots: usize) -> Result<usize, Box<dyn error::Error>> {
+    let mut total: usize = 0;
+    let mut robot = RobotChain::new(Keypad::new_numpad(), robots);
+
+    for code in codes {
+        let cost = robot.calc_cost(code);
+
+        let num = String::from_iter(code.iter().filter(|c| c.is_numeric())).parse::<usize>()?;
+        total = cost
+            .checked_mul(num)
+            .and_then(|c| total.checked_add(c))
+            .ok_or("complexity overflow")?;
+    }
+
+    let (hits, misses) = (robot._cache_hits, robot._cache_misses);
+    event!(
+        Level::Debug,
+        "cache",
+        robots = robots,
+        hits = hits,
+        misses = misses,
+        hit_rate = format!("{:.3}", hits as f64 / (hits + misses).max(1) as f64)
+    );
+    Ok(total)
+}
+
+/// PARAMS declares the parameters of Day21 and their defaults.
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "robots",
+        default: "3",
+        help: "number of directional keypads in the chain for part 1",
+    },
+    Param {
+        name: "robots2",
+        default: "26",
+        help: "number of directional keypads in the chain for part 2",
+    },
+];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day21 is the solution for day 21.
+pub struct Day21 {
+    /// robots is the number of directional keypads in the chain for part 1.
+    pub robots: usize,
+    /// robots2 is the number of directional keypads in the chain for part 2.
+    pub robots2: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Day21::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day21 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day21 {
+            robots: params.value(PARAMS, "robots")?,
+            robots2: params.value(PARAMS, "robots2")?,
+        })
+    }
+}
+
+impl Solution for Day21 {
+    type Input = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_input(r)
+    }
+
+    fn part1(&self, codes: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        complexity(codes, self.robots)
+    }
+
+    fn part2(&self, codes: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        complexity(codes, self.robots2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+    #[test]
+    fn test_keypad_get_min_paths() -> Result<(), Box<dyn error::Error>> {
+        let mut keypad = Keypad::new_dirpad();
+        let mut paths = keypad.get_min_paths('A', '<');
+
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            vec![vec!['<', 'v', '<', 'A'], vec!['v', '<', '<', 'A']]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_numpad_get_min_paths() -> Result<(), Box<dyn error::Error>> {
+        let mut keypad = Keypad::new_numpad();
+        let mut paths = keypad.get_min_paths('1', '6');
+
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            vec![
+                vec!['>', '>', '^', 'A'],
+                vec!['>', '^', '>', 'A'],
+                vec!['^', '>', '>', 'A'],
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_robotchain_calc_cost_shallow_12() -> Result<(), Box<dyn error::Error>> {
+        let mut chain = RobotChain::new(Keypad::new_numpad(), 1);
+        let cost = chain.calc_cost(&"12".chars().collect::<Vec<char>>());
+
+        // <^<A: A -> 1
+        // >A: 1 -> 2
+
+        assert_eq!(cost, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_robotchain_calc_cost_shallow_539() -> Result<(), Box<dyn error::Error>> {
+        let mut chain = RobotChain::new(Keypad::new_numpad(), 1);
+        let cost2 = chain.calc_cost(&"593".chars().collect::<Vec<char>>());
+
+        // <^^A: A -> 5
+        // ^>A: 5 -> 9
+        // vvA: 9 -> 3
+
+        assert_eq!(cost2, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_robotchain_calc_cost_three_gen() -> Result<(), Box<dyn error::Error>> {
+        // Numerical keypad
+        let code = "029A";
+
+        let mut chain0 = RobotChain::new(Keypad::new_numpad(), 0);
+        let cost0 = chain0.calc_cost(&code.chars().collect::<Vec<char>>());
+        assert_eq!(cost0, 4);
+
+        // First directional keypad
+        // <A^A>^^AvvvA
+        //
+        // <A: 0
+        // ^A: 2
+        // >^^A: 9
+        // vvvA: A
+
+        let mut chain1 = RobotChain::new(Keypad::new_numpad(), 1);
+        let cost1 = chain1.calc_cost(&code.chars().collect::<Vec<char>>());
+        assert_eq!(cost1, 12);
+
+        // Second directional keypad
+        // v<<A>>^A<A>AvA<^AA>A<vAAA>^A
+        //
+        // v<<A: <
+        // >>^A: A
+        //
+        // <A: ^
+        // >A: A
+        //
+        // vA: >
+        // <^AA: ^^
+        // >A: A
+        //
+        // <vAAA: vvv
+        // >^A: A
+
+        let mut chain2 = RobotChain::new(Keypad::new_numpad(), 2);
+        let cost2 = chain2.calc_cost(&code.chars().collect::<Vec<char>>());
+        assert_eq!(cost2, 28);
+
+        // Third directional keypad
+        // <vA<AA>>^AvAA<^A>A<v<A>>^AvA^A<vA>^A<v<A>^A>AAvA^A<v<A>A>^AAAvA<^A>A
+        //
+        // <vA: v
+        // <AA: <<
+        // >>^A: A
+        //
+        // vAA: >>
+        // <^A: ^
+        // >A: A
+        //
+        // <v<A: <
+        // >>^A: A
+        //
+        // vA: >
+        // ^A: A
+        //
+        // <vA: v
+        // >^A: A
+        //
+        // <v<A: <
+        // >^A: ^
+        // >AA: AA
+        //
+        // vA: >
+        // ^A: A
+        //
+        // <v<A: <
+        // >A: v
+        // >^AAA: AAA
+        //
+        // vA: >
+        // <^A: ^
+        // >A: A
+
+        let mut chain3 = RobotChain::new(Keypad::new_numpad(), 3);
+        let cost3 = chain3.calc_cost(&code.chars().collect::<Vec<char>>());
+        assert_eq!(cost3, 68);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "029A
+980A
+179A
+456A
+379A
+",
+        );
+
+        let (n, n2) = Day21::default().solve(input.reader())?;
+        assert_eq!(n, 126384);
+        assert_eq!(n2, 154115708116294);
+        Ok(())
+    }
+}
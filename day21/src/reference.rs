@@ -0,0 +1,144 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+use crate::Day21;
+
+/// Reference is a slow but simple solution for day 21 that Day21 is checked against. Rather than
+/// choosing among the shortest paths between buttons, it searches every sequence of presses on
+/// each keypad for the cheapest one given the cost of presses on the keypad that controls it.
+pub struct Reference(pub Day21);
+
+// NUMERIC is the numeric keypad. Gaps are spaces.
+const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+
+// DIRECTIONAL is the directional keypad. Gaps are spaces.
+const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+// Costs holds the number of presses by the human needed to press a button on a keypad after
+// another one, starting with the arm on the first button.
+type Costs = collections::HashMap<(char, char), usize>;
+
+// buttons returns the buttons of a keypad and their positions.
+fn buttons(layout: &[&str]) -> collections::HashMap<char, (i64, i64)> {
+    let mut buttons = collections::HashMap::new();
+    for (y, row) in layout.iter().enumerate() {
+        for (x, c) in row.chars().enumerate().filter(|(_, c)| *c != ' ') {
+            buttons.insert(c, (x as i64, y as i64));
+        }
+    }
+    buttons
+}
+
+// costs returns the cost of pressing each button of the keypad after each other button when it is
+// controlled through a directional keypad with the given costs. The controlling arm starts and
+// ends on its A button.
+fn costs(layout: &[&str], controller: &Costs) -> Costs {
+    let buttons = buttons(layout);
+    let at: collections::HashMap<(i64, i64), char> =
+        buttons.iter().map(|(c, p)| (*p, *c)).collect();
+    let moves = [('^', (0, -1)), ('v', (0, 1)), ('<', (-1, 0)), ('>', (1, 0))];
+
+    let mut costs = Costs::new();
+    for from in buttons.keys() {
+        // Find the cheapest way to reach each state of this keypad's button and the controlling
+        // arm's button by relaxing until nothing improves.
+        let mut best = collections::HashMap::from([((*from, 'A'), 0)]);
+        loop {
+            let mut changed = false;
+            for ((button, arm), cost) in best.clone() {
+                for (dir, (dx, dy)) in moves {
+                    let (x, y) = buttons[&button];
+                    let Some(next) = at.get(&(x + dx, y + dy)) else {
+                        continue;
+                    };
+                    let cost = cost + controller[&(arm, dir)];
+                    if best.get(&(*next, dir)).is_none_or(|c| cost < *c) {
+                        best.insert((*next, dir), cost);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        // Press the button by pressing A on the controlling keypad.
+        for ((button, arm), cost) in best {
+            let cost = cost + controller[&(arm, 'A')];
+            let entry = costs.entry((*from, button)).or_insert(cost);
+            *entry = cost.min(*entry);
+        }
+    }
+    costs
+}
+
+// complexity returns the sum of the complexities of the codes typed through the given number of
+// directional keypads, including the human's.
+fn complexity(codes: &[Vec<char>], keypads: usize) -> Result<usize, Box<dyn error::Error>> {
+    // The human presses each button directly.
+    let human: Vec<char> = buttons(&DIRECTIONAL).into_keys().collect();
+    let mut controller: Costs = human
+        .iter()
+        .flat_map(|a| human.iter().map(|b| ((*a, *b), 1)))
+        .collect();
+    for _ in 1..keypads {
+        controller = costs(&DIRECTIONAL, &controller);
+    }
+    let numeric = costs(&NUMERIC, &controller);
+
+    let mut total: usize = 0;
+    for code in codes {
+        let presses: usize = ['A']
+            .iter()
+            .chain(code)
+            .zip(code)
+            .map(|(a, b)| numeric[&(*a, *b)])
+            .sum();
+        let num: usize = code
+            .iter()
+            .filter(|c| c.is_ascii_digit())
+            .collect::<String>()
+            .parse()?;
+        total = presses
+            .checked_mul(num)
+            .and_then(|c| total.checked_add(c))
+            .ok_or("complexity overflow")?;
+    }
+    Ok(total)
+}
+
+impl Solution for Reference {
+    type Input = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_input(r)
+    }
+
+    fn part1(&self, codes: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        complexity(codes, self.0.robots)
+    }
+
+    fn part2(&self, codes: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        complexity(codes, self.0.robots2)
+    }
+}
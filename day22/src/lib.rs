@@ -0,0 +1,332 @@
+// Copyright 2025 Ian Lewis
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::collections;
+use std::collections::vec_deque;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Lines, Param, Params, Solution};
+
+/// BoundedVecDeque is a queue that holds at most a fixed number of elements. The oldest elements
+/// are dropped to make room for new ones.
+pub struct BoundedVecDeque<T> {
+    _deque: collections::VecDeque<T>,
+    _limit: usize,
+}
+
+impl<T> BoundedVecDeque<T> {
+    /// new returns an empty queue that holds at most limit elements.
+    pub fn new(limit: usize) -> Self {
+        BoundedVecDeque {
+            _deque: collections::VecDeque::new(),
+            _limit: limit,
+        }
+    }
+
+    /// push_back adds an element to the back of the queue, dropping elements from the front if
+    /// the queue is over its limit.
+    pub fn push_back(&mut self, elem: T) {
+        self._deque.push_back(elem);
+        while self._deque.len() > self._limit {
+            self._deque.pop_front();
+        }
+    }
+
+    /// iter returns an iterator over the elements from front to back.
+    pub fn iter(&self) -> vec_deque::Iter<'_, T> {
+        self._deque.iter()
+    }
+
+    /// len returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self._deque.len()
+    }
+
+    /// is_empty returns true if the queue has no elements.
+    pub fn is_empty(&self) -> bool {
+        self._deque.is_empty()
+    }
+}
+
+/// read_input reads each buyer's initial secret number.
+pub fn read_input(r: impl BufRead) -> Result<Vec<usize>, Error> {
+    let mut secret_numbers = Vec::new();
+    for line in Lines::new(r) {
+        secret_numbers.push(line?.token().parse::<usize>("secret number")?);
+    }
+    Ok(secret_numbers)
+}
+
+/// evolve returns the next secret number in the sequence after secret_num.
+pub fn evolve(secret_num: usize) -> usize {
+    // Calculate the result of multiplying the secret number by 64. Then, mix this result into the
+    // secret number. Finally, prune the secret number.
+    //
+    // NOTE: 64 = 2^6
+    //       This shifts the secret number 6 bits to the left, XORs the value
+    //       against the original secret number, and mods by 2^24.
+    //       This does not change the least significant digit.
+    //       Only the low 24 bits survive pruning so a wrapping multiply gives the same result
+    //       for secret numbers too large to multiply.
+    let mut new_num = prune(mix(secret_num, secret_num.wrapping_mul(64)));
+
+    // Calculate the result of dividing the secret number by 32. Round the result down to the
+    // nearest integer. Then, mix this result into the secret number. Finally, prune the secret
+    // number.
+    //
+    // NOTE: 32 = 2^5
+    //       This shifts the secret number 5 bits to the right, XORs the value
+    //       against the original secret number, and mods by 2^24.
+    //
+    new_num = prune(mix(new_num, new_num / 32));
+
+    // Calculate the result of multiplying the secret number by 2048. Then, mix this result into
+    // the secret number. Finally, prune the secret number.
+    //
+    // NOTE: 2048 = 2^11
+    prune(mix(new_num, new_num * 2048))
+}
+
+/// mix returns the value mixed into the secret number.
+pub fn mix(secret_num: usize, value: usize) -> usize {
+    // To mix a value into the secret number, calculate the bitwise XOR of the
+    // given value and the secret number. Then, the secret number becomes the
+    // result of that operation. (If the secret number is 42 and you were to mix 15
+    // into the secret number, the secret number would become 37.)
+    secret_num ^ value
+}
+
+/// prune returns the secret number after pruning.
+pub fn prune(secret_num: usize) -> usize {
+    // To prune the secret number, calculate the value of the secret number modulo
+    // 16777216. Then, the secret number becomes the result of that operation. (If the
+    // secret number is 100000000 and you were to prune the secret number, the secret
+    // number would become 16113920.)
+    //
+    // NOTE: 16777216 = 4096^2 = 2^24
+    // 	     prune effectively strips the bits higher than the 24th bit.
+    secret_num % 16777216
+}
+
+/// price_change returns the next secret number after secret_num along with the change in price
+/// from one to the other. A buyer's price is the ones digit of their secret number.
+pub fn price_change(secret_num: usize) -> (usize, isize) {
+    let next_num = evolve(secret_num);
+    (
+        next_num,
+        (next_num as isize % 10) - (secret_num as isize % 10),
+    )
+}
+
+/// secret_sum returns the sum of each buyer's secret number after it has evolved n times.
+pub fn secret_sum(secret_numbers: &[usize], n: usize) -> usize {
+    let mut final_numbers = Vec::new();
+    for num in secret_numbers {
+        let mut secret_num = *num;
+        for _i in 0..n {
+            secret_num = evolve(secret_num);
+        }
+        final_numbers.push(secret_num);
+    }
+
+    final_numbers.iter().sum()
+}
+
+/// most_bananas returns the most bananas that can be bought by picking the sequence of four
+/// price changes after which the monkey sells to each buyer. Each buyer's price changes n times
+/// and they sell at the first occurrence of the sequence, if any.
+pub fn most_bananas(secret_numbers: &[usize], n: usize) -> usize {
+    let mut sequences = collections::HashMap::new();
+    for num in secret_numbers {
+        let mut seen = collections::HashSet::new();
+        let mut changes = BoundedVecDeque::new(4);
+
+        let mut secret_num = *num;
+        let mut diff;
+        for _i in 0..n {
+            (secret_num, diff) = price_change(secret_num);
+            changes.push_back(diff);
+            if changes.len() == 4 {
+                let c = changes.iter().cloned().collect::<Vec<_>>();
+                if !seen.contains(&c) {
+                    seen.insert(c.clone());
+                    sequences.insert(c.clone(), sequences.get(&c).unwrap_or(&0) + secret_num % 10);
+                }
+            }
+        }
+    }
+
+    *sequences
+        .iter()
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .unwrap_or((&Vec::new(), &0))
+        .1
+}
+
+/// PARAMS declares the parameters of Day22 and their defaults.
+pub const PARAMS: &[Param] = &[Param {
+    name: "iterations",
+    default: "2000",
+    help: "number of secret numbers each buyer generates",
+}];
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day22 is the solution for day 22.
+pub struct Day22 {
+    /// iterations is the number of new secret numbers each buyer generates.
+    pub iterations: usize,
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Day22::from_params(&Params::new()).expect("invalid default parameters")
+    }
+}
+
+impl Day22 {
+    /// from_params returns the solution with the given parameters overriding the defaults in
+    /// PARAMS.
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.check(PARAMS)?;
+        Ok(Day22 {
+            iterations: params.value(PARAMS, "iterations")?,
+        })
+    }
+}
+
+impl Solution for Day22 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_input(r)
+    }
+
+    fn part1(&self, secret_numbers: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(secret_sum(secret_numbers, self.iterations))
+    }
+
+    fn part2(&self, secret_numbers: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(most_bananas(secret_numbers, self.iterations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_mix() -> Result<(), Box<dyn error::Error>> {
+        assert_eq!(mix(42, 15), 37);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bounded_vec_deque() {
+        let mut deque = BoundedVecDeque::new(2);
+        assert!(deque.is_empty());
+        for n in 1..=3 {
+            deque.push_back(n);
+        }
+        assert_eq!(deque.len(), 2);
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [2, 3]);
+    }
+
+    #[test]
+    fn test_prune() -> Result<(), Box<dyn error::Error>> {
+        assert_eq!(prune(100000000), 16113920);
+        Ok(())
+    }
+
+    #[test]
+    fn test_evolve() -> Result<(), Box<dyn error::Error>> {
+        let secret_numbers = [
+            123, 15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484,
+            7753432, 5908254,
+        ];
+        let price_changes: Vec<_> = secret_numbers[1..]
+            .iter()
+            .cloned()
+            .zip([-3, 6, -1, -1, 0, 2, -2, 0, -2, 2])
+            .collect();
+
+        for (i, num) in secret_numbers[..secret_numbers.len() - 1]
+            .iter()
+            .enumerate()
+        {
+            assert_eq!(evolve(*num), secret_numbers[i + 1]);
+            assert_eq!(price_change(*num), price_changes[i]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_evolve_2000() -> Result<(), Box<dyn error::Error>> {
+        let secret_numbers = [
+            (1, 8685429),
+            (10, 4700978),
+            (100, 15273692),
+            (2024, 8667524),
+        ];
+        for (mut num, expected) in secret_numbers {
+            for _i in 0..2000 {
+                num = evolve(num);
+            }
+            assert_eq!(num, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "1
+10
+100
+2024
+",
+        );
+
+        let (n, n2) = Day22::default().solve(input.reader())?;
+        assert_eq!(n, 37327623);
+        assert_eq!(n2, 24);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run2() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "1
+2
+3
+2024
+",
+        );
+
+        let (n, n2) = Day22::default().solve(input.reader())?;
+        assert_eq!(n, 37990510);
+        assert_eq!(n2, 23);
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+use crate::Day22;
+
+/// Reference is a slow but simple solution for day 22 that Day22 is checked against. It lists
+/// every buyer's prices before looking for the best sequence of changes.
+pub struct Reference(pub Day22);
+
+// next returns the secret number that follows n.
+fn next(n: usize) -> usize {
+    let n = (n ^ n.wrapping_shl(6)) & 0xffffff;
+    let n = (n ^ (n >> 5)) & 0xffffff;
+    (n ^ (n << 11)) & 0xffffff
+}
+
+// prices returns the buyer's price for its first secret number and the given number that
+// follow.
+fn prices(secret: usize, iterations: usize) -> Vec<i64> {
+    let mut n = secret;
+    let mut prices = vec![(n % 10) as i64];
+    for _ in 0..iterations {
+        n = next(n);
+        prices.push((n % 10) as i64);
+    }
+    prices
+}
+
+impl Solution for Reference {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_input(r)
+    }
+
+    fn part1(&self, secrets: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(secrets
+            .iter()
+            .map(|s| (0..self.0.iterations).fold(*s, |n, _| next(n)))
+            .sum())
+    }
+
+    fn part2(&self, secrets: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        // The monkey sells at the first occurrence of the sequence for each buyer.
+        let mut bananas: collections::HashMap<[i64; 4], usize> = collections::HashMap::new();
+        for s in secrets {
+            let prices = prices(*s, self.0.iterations);
+            let mut sold = collections::HashSet::new();
+            for i in 4..prices.len() {
+                let changes = [
+                    prices[i - 3] - prices[i - 4],
+                    prices[i - 2] - prices[i - 3],
+                    prices[i - 1] - prices[i - 2],
+                    prices[i] - prices[i - 1],
+                ];
+                if sold.insert(changes) {
+                    *bananas.entry(changes).or_default() += prices[i] as usize;
+                }
+            }
+        }
+        Ok(bananas.values().max().copied().unwrap_or(0))
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of computers in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 520;
+
+// PARTY is the size of the planted LAN party.
+const PARTY: usize = 13;
+
+/// generate returns the connections between size computers, at least 2 and up to 676, with
+/// two-letter names. A LAN party of up to 13 computers is planted and every other computer is
+/// connected to at most 11 others. A clique of 13 computers needs computers with 12 connections,
+/// so the planted party is the largest and its password is the answer to part 2. The answer to
+/// part 1 is not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut names: Vec<String> = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| String::from_utf8_lossy(&[a, b]).to_string()))
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.max(2));
+
+    let party_size = PARTY.min(names.len());
+    let mut party = names[..party_size].to_vec();
+    let mut edges = collections::HashSet::new();
+    for i in 0..party_size {
+        for j in i + 1..party_size {
+            edges.insert((i, j));
+        }
+    }
+
+    // Party members may have one connection outside the party.
+    let mut degree = vec![0; names.len()];
+    let limit = |i: usize| if i < party_size { 1 } else { PARTY - 2 };
+    for _ in 0..names.len() * PARTY / 2 {
+        let (a, b) = (rng.below(names.len()), rng.below(names.len()));
+        let (a, b) = (a.min(b), a.max(b));
+        if a == b || b < party_size || degree[a] >= limit(a) || degree[b] >= limit(b) {
+            continue;
+        }
+        if edges.insert((a, b)) {
+            degree[a] += 1;
+            degree[b] += 1;
+        }
+    }
+
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort();
+    rng.shuffle(&mut edges);
+    let input = edges
+        .iter()
+        .map(|(a, b)| {
+            let (a, b) = if rng.chance(1, 2) { (a, b) } else { (b, a) };
+            format!("{}-{}\n", names[*a], names[*b])
+        })
+        .collect();
+
+    party.sort();
+    Generated {
+        input,
+        part2: Some(party.join(",")),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,288 @@
+// Copyright 2025 Ian Lewis
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::log::Level;
+use aoc_common::{event, Error, Lines, Solution};
+
+/// NetworkData holds the names of the computers and the computers connected to each of them.
+pub type NetworkData = (
+    collections::HashSet<String>,
+    collections::HashMap<String, Vec<String>>,
+);
+
+/// read_network reads a connection between two computers from each line.
+pub fn read_network(r: impl BufRead) -> Result<NetworkData, Error> {
+    let mut computers = collections::HashSet::new();
+    let mut network = collections::HashMap::new();
+    for line_o in Lines::new(r) {
+        let line = line_o?;
+        let (a, b) = line.token().split_once("-")?;
+        let parts = [a.text, b.text];
+        computers.insert(parts[0].to_string());
+        network
+            .entry(parts[0].to_string())
+            .and_modify(|c: &mut Vec<String>| c.push(parts[1].to_string()))
+            .or_insert(vec![parts[1].to_string()]);
+
+        computers.insert(parts[1].to_string());
+        network
+            .entry(parts[1].to_string())
+            .and_modify(|c: &mut Vec<String>| c.push(parts[0].to_string()))
+            .or_insert(vec![parts[0].to_string()]);
+    }
+
+    Ok((computers, network))
+}
+
+/// get_three_groups returns the sets of three computers that are all connected to each other and
+/// include at least one computer whose name starts with 't'. Each set is sorted by name.
+pub fn get_three_groups(
+    computers: &collections::HashSet<String>,
+    network: &collections::HashMap<String, Vec<String>>,
+) -> Vec<Vec<String>> {
+    let mut stack = collections::VecDeque::new();
+
+    for name in computers {
+        if name.starts_with('t') {
+            let group = vec![name.to_string()];
+            stack.push_back(group);
+        }
+    }
+
+    let mut groups = Vec::new();
+    let mut seen = Vec::new();
+    let (mut steps, mut max_stack) = (0usize, stack.len());
+    while !stack.is_empty() {
+        max_stack = max_stack.max(stack.len());
+        steps += 1;
+        let group = stack.pop_back().unwrap();
+
+        if group.len() == 3 {
+            groups.push(group);
+            continue;
+        }
+
+        for name in computers {
+            if group.contains(name) {
+                continue;
+            }
+
+            let tmp = Vec::new();
+            let connected = network.get(name).unwrap_or(&tmp);
+            let all_connected = 'blk: {
+                for g in &group {
+                    if !connected.contains(g) {
+                        break 'blk false;
+                    }
+                }
+                true
+            };
+
+            if all_connected {
+                let mut new_group = group.clone();
+                new_group.push(name.to_string());
+                new_group.sort();
+                if !seen.contains(&new_group) {
+                    stack.push_back(new_group.clone());
+                    seen.push(new_group);
+                }
+            }
+        }
+    }
+
+    event!(
+        Level::Debug,
+        "three_groups",
+        steps = steps,
+        max_stack = max_stack,
+        groups = groups.len()
+    );
+    groups
+}
+
+/// get_lan_party returns the names of the computers in the largest set of computers that are all
+/// connected to each other, sorted by name.
+pub fn get_lan_party(
+    computers: &collections::HashSet<String>,
+    network: &collections::HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    let mut stack = collections::VecDeque::new();
+
+    stack.push_back((Vec::new(), computers.clone()));
+
+    let mut groups = Vec::new();
+    let mut seen = collections::HashSet::new();
+    let (mut steps, mut max_stack) = (0usize, stack.len());
+    while !stack.is_empty() {
+        max_stack = max_stack.max(stack.len());
+        steps += 1;
+        if steps % 4096 == 0 {
+            event!(
+                Level::Trace,
+                "search",
+                steps = steps,
+                stack = stack.len(),
+                seen = seen.len(),
+                cliques = groups.len()
+            );
+        }
+        let (group, rest) = stack.pop_back().unwrap();
+
+        if seen.contains(&group) {
+            continue;
+        }
+
+        let mut connected = collections::HashSet::new();
+        for name in rest.clone() {
+            let all_connected = 'blk: {
+                for name_g in &group {
+                    if !network.get(name_g).unwrap().contains(&name) {
+                        break 'blk false;
+                    }
+                }
+                true
+            };
+
+            if all_connected {
+                connected.insert(name);
+            }
+        }
+
+        seen.insert(group.clone());
+
+        if connected.is_empty() {
+            if !groups.contains(&group) {
+                event!(
+                    Level::Trace,
+                    "clique",
+                    size = group.len(),
+                    stack = stack.len()
+                );
+                groups.push(group);
+            }
+            continue;
+        }
+
+        for name in &connected {
+            let mut new_group = group.clone();
+            new_group.push(name.to_string());
+            new_group.sort();
+
+            let mut new_rest = connected.clone();
+            new_rest.remove(name);
+
+            stack.push_back((new_group, new_rest));
+        }
+    }
+
+    event!(
+        Level::Debug,
+        "lan_party",
+        steps = steps,
+        max_stack = max_stack,
+        seen = seen.len(),
+        cliques = groups.len()
+    );
+    groups
+        .into_iter()
+        .max_by(|a, b| a.len().cmp(&b.len()))
+        .unwrap_or(Vec::new())
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day23 is the solution for day 23.
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = NetworkData;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_network(r)
+    }
+
+    fn part1(
+        &self,
+        (computers, network): &Self::Input,
+    ) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(get_three_groups(computers, network).len())
+    }
+
+    fn part2(
+        &self,
+        (computers, network): &Self::Input,
+    ) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(get_lan_party(computers, network).join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+",
+        );
+
+        let (n, n2) = Day23.solve(input.reader())?;
+        assert_eq!(n, 7);
+        assert_eq!(n2, "co,de,ka,ta");
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+/// Reference is a slow but simple solution for day 23 that Day23 is checked against. It lists every
+/// set of connected computers. If there is more than one largest set, the password is the first in
+/// sorted order.
+pub struct Reference;
+
+// Network holds the sorted names of the computers and the connected pairs.
+type Network = (Vec<String>, collections::HashSet<(String, String)>);
+
+// extend calls f with every set of connected computers that starts with group and continues
+// with computers after the last one in group.
+fn extend(network: &Network, group: &mut Vec<usize>, f: &mut impl FnMut(&[usize])) {
+    let (names, links) = network;
+    f(group);
+    let start = group.last().map_or(0, |i| i + 1);
+    for i in start..names.len() {
+        if group
+            .iter()
+            .all(|g| links.contains(&(names[*g].clone(), names[i].clone())))
+        {
+            group.push(i);
+            extend(network, group, f);
+            group.pop();
+        }
+    }
+}
+
+impl Solution for Reference {
+    type Input = Network;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        let (computers, network) = super::read_network(r)?;
+        let mut names: Vec<String> = computers.into_iter().collect();
+        names.sort();
+        let mut links = collections::HashSet::new();
+        for (a, connected) in network {
+            for b in connected {
+                links.insert((a.clone(), b));
+            }
+        }
+        Ok((names, links))
+    }
+
+    fn part1(&self, network: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let mut total = 0;
+        extend(network, &mut Vec::new(), &mut |group| {
+            if group.len() == 3 && group.iter().any(|i| network.0[*i].starts_with('t')) {
+                total += 1;
+            }
+        });
+        Ok(total)
+    }
+
+    fn part2(&self, network: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut largest: Vec<usize> = Vec::new();
+        extend(network, &mut Vec::new(), &mut |group| {
+            if group.len() > largest.len() {
+                largest = group.to_vec();
+            }
+        });
+        Ok(largest
+            .iter()
+            .map(|i| network.0[*i].as_str())
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is roughly the number of instructions in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 1200;
+
+// NOISE holds the characters written between instructions. None of them can continue or start
+// an instruction.
+const NOISE: &[u8] = b"!@#$%^&*[]{}<>?;:+-/'~ ";
+
+// CORRUPTED holds templates of corrupted mul instructions. Each '#' is replaced by a number. Every
+// template contains a character that stops the instruction from being read, so the text that
+// follows cannot complete it.
+const CORRUPTED: &[&str] = &[
+    "mul(#*",
+    "mul[#,#]",
+    "mul ( # , # )",
+    "mul(#,#]",
+    "mul(#,#!",
+    "mul(1234,#)",
+    "?(#,#)",
+    "mul(,#)",
+];
+
+// OTHER holds instructions that are neither mul, do nor don't.
+const OTHER: &[&str] = &["what()", "from()", "select()", "how()", "where()", "when()"];
+
+/// generate returns an input with size instructions separated by noise. Instructions are valid
+/// mul instructions, corrupted mul instructions, do(), don't() or other instructions, and the
+/// answers are the sums of the valid mul instructions and of those enabled when they appear.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input = String::new();
+    let mut total = 0;
+    let mut enabled_total = 0;
+    let mut enabled = true;
+    let line_len = (size / 6).max(1);
+    for i in 0..size {
+        match rng.below(20) {
+            0..=9 => {
+                let (a, b) = (rng.range(1..1000), rng.range(1..1000));
+                input.push_str(&format!("mul({a},{b})"));
+                total += a * b;
+                if enabled {
+                    enabled_total += a * b;
+                }
+            }
+            10..=13 => {
+                for c in rng.choose(CORRUPTED).chars() {
+                    match c {
+                        '#' => input.push_str(&rng.range(1..1000).to_string()),
+                        _ => input.push(c),
+                    }
+                }
+            }
+            14..=15 => {
+                input.push_str("do()");
+                enabled = true;
+            }
+            16..=17 => {
+                input.push_str("don't()");
+                enabled = false;
+            }
+            _ => input.push_str(rng.choose::<&str>(OTHER)),
+        }
+
+        for _ in 0..rng.below(4) {
+            input.push(*rng.choose(NOISE) as char);
+        }
+        if (i + 1) % line_len == 0 {
+            input.push('\n');
+        }
+    }
+    if !input.ends_with('\n') {
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        part1: Some(total.to_string()),
+        part2: Some(enabled_total.to_string()),
+        ..Default::default()
+    }
+}
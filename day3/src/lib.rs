@@ -0,0 +1,346 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::error;
+use std::io;
+use std::io::{BufRead, Read};
+
+use aoc_common::{Error, Normalize, Solution};
+
+/// Lexer reads tokens from a program. Tokens are matched against the bytes returned by the
+/// reader's fill_buf, so a token split between two reads of the underlying reader is not found.
+/// The reader should hold the whole program in memory, like io::Cursor, so that fill_buf always
+/// returns the rest of the program.
+pub struct Lexer<R: io::BufRead> {
+    reader: R,
+}
+
+// TODO: Support utf-8 properly.
+//       Current code uses String.len() which returns the number of bytes and not utf-8 characters.
+//       Getting proper utf-8 substrings is also non-trivial in Rust it seems.
+
+impl<R: io::BufRead> Lexer<R> {
+    /// new returns a new Lexer reading the program from r.
+    pub fn new(r: R) -> Self {
+        Lexer { reader: r }
+    }
+
+    // peek reads n bytes from the current reader without advancing the reader's position.
+    fn peek(&mut self, n: usize) -> Result<String, Box<dyn error::Error>> {
+        // TODO: Do not call fill_buf every call to peek.
+        //       The buffer returned from fill_buf should be fully consumed before calling fill_buf
+        //       again because we convert it to a String on every call to peek.
+        // NOTE: The program is corrupted so it may not be valid utf-8. Invalid bytes are replaced
+        //       since they can never be part of a token.
+        let buf = self.reader.fill_buf()?;
+        Ok(String::from_utf8_lossy(&buf[..n.min(buf.len())]).to_string())
+    }
+
+    /// read_until reads from the reader until it encounters one of the given tokens. If one is
+    /// found then it is returned. If the reader is fully read without encountering a token then
+    /// None is returned.
+    pub fn read_until(&mut self, tokens: &[&str]) -> Result<Option<String>, Box<dyn error::Error>> {
+        // Get maximum length of given tokens.
+        let length = tokens.iter().map(|tok| tok.len()).max().unwrap_or(0);
+        loop {
+            let buf = self.peek(length)?;
+            if buf.is_empty() {
+                // EOF
+                return Ok(None);
+            }
+
+            let mut found_tok: Option<String> = None;
+            for tok in tokens.iter() {
+                if buf.starts_with(tok) {
+                    found_tok = Some(tok.to_string());
+                    break;
+                }
+            }
+
+            if let Some(tok) = &found_tok {
+                self.reader.consume(tok.len());
+                return Ok(found_tok);
+            } else {
+                self.reader.consume(1);
+            }
+        }
+    }
+
+    /// read_tok reads an expected token from the reader. Returns whether the token was read or
+    /// not.
+    pub fn read_tok(&mut self, tok: &str) -> Result<bool, Box<dyn error::Error>> {
+        let buf = self.peek(tok.len())?;
+        if buf == tok {
+            self.reader.consume(buf.len());
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// read_num reads an expected number (up to 3 digits) from the reader and returns it. If a
+    /// number was not present at the current location an error is returned.
+    pub fn read_num(&mut self) -> Result<i64, Box<dyn error::Error>> {
+        let buf = self.peek(3)?;
+        let mut digits = 0;
+        for c in buf.chars() {
+            if c.is_ascii_digit() {
+                digits += 1;
+            } else {
+                break;
+            }
+        }
+        let n = buf[..digits].parse::<i64>()?;
+        self.reader.consume(digits);
+        Ok(n)
+    }
+}
+
+/// sum_mul returns the sum of all mul() operations in the program.
+pub fn sum_mul(r: impl io::BufRead) -> Result<i64, Box<dyn error::Error>> {
+    let mut total = 0;
+    let mut lex = Lexer::new(r);
+    loop {
+        let found_tok = lex.read_until(&["mul"])?;
+        if found_tok.is_none() {
+            break;
+        }
+
+        if !lex.read_tok("(")? {
+            continue;
+        }
+
+        let left_result = lex.read_num();
+        // TODO: check for parse error
+        if left_result.is_err() {
+            continue;
+        }
+
+        if !lex.read_tok(",")? {
+            continue;
+        }
+
+        let right_result = lex.read_num();
+        // TODO: check for parse error
+        if right_result.is_err() {
+            continue;
+        }
+
+        if !lex.read_tok(")")? {
+            continue;
+        }
+        total += left_result.unwrap() * right_result.unwrap();
+    }
+
+    Ok(total)
+}
+
+/// sum_mul_do returns the sum of all mul() operations in the program that are enabled by do() and
+/// don't().
+pub fn sum_mul_do(r: impl io::BufRead) -> Result<i64, Box<dyn error::Error>> {
+    let mut total = 0;
+    let mut lex = Lexer::new(r);
+    let mut enabled = true;
+    loop {
+        if !enabled {
+            let found_tok = lex.read_until(&["do()"])?;
+            if found_tok.is_none() {
+                break;
+            }
+            enabled = true;
+            continue;
+        }
+
+        let found_tok = lex.read_until(&["mul", "don't()"])?;
+        if found_tok.is_none() {
+            break;
+        }
+
+        let tok = found_tok.unwrap();
+        if tok == "don't()" {
+            enabled = false;
+            continue;
+        }
+
+        if !lex.read_tok("(")? {
+            continue;
+        }
+
+        let left_result = lex.read_num();
+        // TODO: check for parse error
+        if left_result.is_err() {
+            continue;
+        }
+
+        if !lex.read_tok(",")? {
+            continue;
+        }
+
+        let right_result = lex.read_num();
+        // TODO: check for parse error
+        if right_result.is_err() {
+            continue;
+        }
+
+        if !lex.read_tok(")")? {
+            continue;
+        }
+        total += left_result.unwrap() * right_result.unwrap();
+    }
+
+    Ok(total)
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day3 is the solution for day 3.
+pub struct Day3;
+
+impl Solution for Day3 {
+    // The program is read twice so it is read into a buffer.
+    type Input = Vec<u8>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        let mut buf: Vec<u8> = Vec::new();
+        Normalize::new(r).read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn part1(&self, buf: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        sum_mul(io::Cursor::new(buf))
+    }
+
+    fn part2(&self, buf: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        sum_mul_do(io::Cursor::new(buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input =
+            Bytes::from("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
+        let result = sum_mul(input.reader())?;
+        assert_eq!(result, 161);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_do_nothing() -> Result<(), Box<dyn error::Error>> {
+        let inputs: [&str; 4] = ["mul(4*", "mul(6,9!", "?(12,34)", "mul ( 2 , 4 )"];
+
+        for input in inputs {
+            let b = Bytes::from(input);
+            let result = sum_mul(b.reader())?;
+            assert_eq!(result, 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_mul_paren_mul() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("mul(mul(2,4)");
+        let result = sum_mul(input.reader())?;
+        assert_eq!(result, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_mul_paren_num_mul() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("mul(2mul(2,4)");
+        let result = sum_mul(input.reader())?;
+        assert_eq!(result, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_mul_paren_num_comma_mul() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("mul(2,mul(2,4)");
+        let result = sum_mul(input.reader())?;
+        assert_eq!(result, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_mul_paren_num_comma_num_mul() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("mul(2,12mul(2,4)");
+        let result = sum_mul(input.reader())?;
+        assert_eq!(result, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_do() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        );
+        let result = sum_mul_do(input.reader())?;
+        assert_eq!(result, 48);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_large() -> Result<(), Box<dyn error::Error>> {
+        // The instructions straddle the 8 KiB boundary of a default sized buffer.
+        let mut program = "x".repeat(8190);
+        program.push_str("mul(2,4)don't()mul(5,5)");
+        let input = Bytes::from(program);
+
+        let (total, enabled) = Day3.solve(input.reader())?;
+        assert_eq!(total, 33);
+        assert_eq!(enabled, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lexer_read_until() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("foobar");
+        let mut lex = Lexer::new(input.reader());
+        let found = lex.read_until(&["bar"]).unwrap();
+
+        assert_eq!(found, Some("bar".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lexer_read_tok() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(",");
+        let mut lex = Lexer::new(input.reader());
+        let found = lex.read_tok(",").unwrap();
+
+        assert!(found);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lexer_read_num() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("123");
+        let mut lex = Lexer::new(input.reader());
+        let n = lex.read_num().unwrap();
+
+        assert_eq!(n, 123i64);
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+/// Reference is a slow but simple solution for day 3 that Day3 is checked against. It looks for
+/// an instruction at every offset of the program.
+pub struct Reference;
+
+// number returns the number of one to three digits at the start of b and its length.
+fn number(b: &[u8]) -> Option<(i64, usize)> {
+    let len = b.iter().take(3).take_while(|c| c.is_ascii_digit()).count();
+    if len == 0 {
+        return None;
+    }
+    let n = b[..len].iter().fold(0, |n, c| n * 10 + i64::from(c - b'0'));
+    Some((n, len))
+}
+
+// mul returns the product of the mul instruction at the start of b if there is a valid one.
+fn mul(b: &[u8]) -> Option<i64> {
+    let b = b.strip_prefix(b"mul(")?;
+    let (x, len) = number(b)?;
+    let b = b[len..].strip_prefix(b",")?;
+    let (y, len) = number(b)?;
+    b[len..].strip_prefix(b")")?;
+    Some(x * y)
+}
+
+// run returns the sum of the mul instructions in the program. do() and don't() instructions are
+// followed if conditionals is true.
+fn run(program: &[u8], conditionals: bool) -> i64 {
+    let mut enabled = true;
+    let mut total = 0;
+    for i in 0..program.len() {
+        let rest = &program[i..];
+        if conditionals && rest.starts_with(b"do()") {
+            enabled = true;
+        } else if conditionals && rest.starts_with(b"don't()") {
+            enabled = false;
+        } else if let Some(product) = mul(rest).filter(|_| enabled) {
+            total += product;
+        }
+    }
+    total
+}
+
+impl Solution for Reference {
+    type Input = Vec<u8>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, mut r: impl BufRead) -> Result<Self::Input, Error> {
+        let mut program = Vec::new();
+        r.read_to_end(&mut program)?;
+        Ok(program)
+    }
+
+    fn part1(&self, program: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(run(program, false))
+    }
+
+    fn part2(&self, program: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(run(program, true))
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Direction, Generated, Grid, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 140;
+
+// place writes the letters to the grid if every location is inside the grid and no location or
+// any of its neighbors is already used. Keeping every placed word apart from the others means
+// that no word can be read across two of them. It returns true if the letters were placed.
+fn place(grid: &mut Grid<char>, letters: &[(Option<Point>, char)]) -> bool {
+    let free = letters.iter().all(|(p, _)| match p {
+        Some(p) if grid.contains(*p) => {
+            grid[*p] == '.' && grid.neighbors8(*p).all(|(_, n)| grid[n] == '.')
+        }
+        _ => false,
+    });
+    if free {
+        for (p, c) in letters {
+            grid[p.unwrap()] = *c;
+        }
+    }
+    free
+}
+
+/// generate returns a size by size grid of '.' with XMAS words written in every direction and
+/// X-MAS crosses in every orientation placed apart from each other, so the answers are the number
+/// of each that were placed.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut grid = Grid::new(size, size, '.');
+    let mut words = 0;
+    let mut crosses = 0;
+
+    for _ in 0..size * size / 8 {
+        let p = Point::new(rng.below(size), rng.below(size));
+        if rng.chance(1, 2) {
+            let dir = *rng.choose(&Direction::ALL);
+            let letters: Vec<_> = "XMAS"
+                .chars()
+                .enumerate()
+                .map(|(i, c)| (p.step(dir, i), c))
+                .collect();
+            if place(&mut grid, &letters) {
+                words += 1;
+            }
+        } else {
+            // Each diagonal through the A reads MAS in a random direction.
+            let mut letters = vec![(Some(p), 'A')];
+            for dir in [Direction::UpLeft, Direction::UpRight] {
+                let (m, s) = if rng.chance(1, 2) {
+                    ('M', 'S')
+                } else {
+                    ('S', 'M')
+                };
+                letters.push((p.step(dir, 1), m));
+                letters.push((p.step(dir.opposite(), 1), s));
+            }
+            if place(&mut grid, &letters) {
+                crosses += 1;
+            }
+        }
+    }
+
+    Generated {
+        input: grid.to_string(),
+        part1: Some(words.to_string()),
+        part2: Some(crosses.to_string()),
+        ..Default::default()
+    }
+}
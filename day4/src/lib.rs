@@ -0,0 +1,124 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Direction, Error, Grid, Solution};
+
+/// find_xmas returns the number of times XMAS appears in the grid horizontally, vertically or
+/// diagonally, forwards or backwards.
+pub fn find_xmas(grid: &Grid<char>) -> i64 {
+    let mut total = 0;
+    for (p, c) in grid.iter() {
+        if *c != 'X' {
+            continue;
+        }
+
+        for dir in Direction::ALL {
+            let found = "MAS".chars().enumerate().all(|(i, expected)| {
+                grid.step(p, dir, i + 1).map(|next| grid[next]) == Some(expected)
+            });
+            if found {
+                total += 1;
+            }
+        }
+    }
+
+    total
+}
+
+/// find_x_mas returns the number of times two MAS cross in the shape of an X in the grid. Each
+/// MAS may be written forwards or backwards.
+pub fn find_x_mas(grid: &Grid<char>) -> i64 {
+    let is_mas = |a: Option<char>, b: Option<char>| {
+        matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+    };
+
+    let mut total = 0;
+    for (p, c) in grid.iter() {
+        // The center must be an 'A'
+        if *c != 'A' {
+            continue;
+        }
+
+        let corner = |dir| grid.step(p, dir, 1).map(|next| grid[next]);
+
+        // Both diagonals through the center must read MAS in either direction.
+        if is_mas(corner(Direction::UpLeft), corner(Direction::DownRight))
+            && is_mas(corner(Direction::UpRight), corner(Direction::DownLeft))
+        {
+            total += 1;
+        }
+    }
+
+    total
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day4 is the solution for day 4.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        // Read the full grid.
+        Grid::read(r)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(find_xmas(grid))
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(find_x_mas(grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+",
+        );
+
+        let (n, n2) = Day4.solve(input.reader())?;
+        assert_eq!(n, 18);
+        assert_eq!(n2, 9);
+        Ok(())
+    }
+}
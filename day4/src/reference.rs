@@ -0,0 +1,77 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Solution};
+
+/// Reference is a slow but simple solution for day 4 that Day4 is checked against.
+pub struct Reference;
+
+// read returns the word of length n starting at (x, y) and going in the direction (dx, dy).
+// None is returned if the word would leave the grid.
+fn read(
+    grid: &Grid<char>,
+    (x, y): (isize, isize),
+    (dx, dy): (isize, isize),
+    n: isize,
+) -> Option<String> {
+    (0..n)
+        .map(|i| {
+            let x = usize::try_from(x + dx * i).ok()?;
+            let y = usize::try_from(y + dy * i).ok()?;
+            grid.get((x, y).into()).copied()
+        })
+        .collect()
+}
+
+impl Solution for Reference {
+    type Input = Grid<char>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        Grid::read(r)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let mut total = 0;
+        for p in grid.points() {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let start = (p.x as isize, p.y as isize);
+                    if read(grid, start, (dx, dy), 4).as_deref() == Some("XMAS") {
+                        total += 1;
+                    }
+                }
+            }
+        }
+        Ok(total)
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let is_mas = |w: Option<String>| matches!(w.as_deref(), Some("MAS") | Some("SAM"));
+
+        // Check the diagonals of every 3x3 square.
+        let mut total = 0;
+        for p in grid.points() {
+            let (x, y) = (p.x as isize, p.y as isize);
+            if is_mas(read(grid, (x, y), (1, 1), 3)) && is_mas(read(grid, (x + 2, y), (-1, 1), 3)) {
+                total += 1;
+            }
+        }
+        Ok(total)
+    }
+}
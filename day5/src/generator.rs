@@ -0,0 +1,77 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of updates in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 200;
+
+// PAGES is the number of distinct page numbers. It is odd so that every pair of pages has a rule.
+const PAGES: usize = 49;
+
+// ARC is the number of consecutive pages around the circle that are totally ordered.
+const ARC: usize = PAGES / 2 + 1;
+
+/// generate returns an input with size updates. The pages are placed around a circle and each
+/// page must come before the PAGES/2 pages that follow it, so the rules as a whole are cyclic.
+/// Every update only uses pages from an arc of the circle, where the rules order the pages
+/// consistently, so the corrected order of an update is the order of its pages along the arc.
+/// About half of the updates are already in the correct order.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut numbers: Vec<usize> = (10..100).collect();
+    rng.shuffle(&mut numbers);
+    let pages = &numbers[..PAGES];
+
+    let mut rules = Vec::new();
+    for (i, before) in pages.iter().enumerate() {
+        for d in 1..ARC {
+            rules.push(format!("{before}|{}\n", pages[(i + d) % PAGES]));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut valid = 0;
+    let mut corrected = 0;
+    let mut updates = String::new();
+    for _ in 0..size {
+        let start = rng.below(PAGES);
+        let len = 5 + 2 * rng.below((ARC - 5) / 2 + 1);
+        let mut offsets: Vec<_> = (0..ARC).collect();
+        rng.shuffle(&mut offsets);
+        let mut offsets = offsets[..len].to_vec();
+        offsets.sort();
+        let ordered: Vec<_> = offsets.iter().map(|o| pages[(start + o) % PAGES]).collect();
+
+        let mut update = ordered.clone();
+        if rng.chance(1, 2) {
+            valid += ordered[len / 2];
+        } else {
+            while update == ordered {
+                rng.shuffle(&mut update);
+            }
+            corrected += ordered[len / 2];
+        }
+
+        let update: Vec<_> = update.iter().map(|p| p.to_string()).collect();
+        updates.push_str(&update.join(","));
+        updates.push('\n');
+    }
+
+    Generated {
+        input: format!("{}\n{updates}", rules.concat()),
+        part1: Some(valid.to_string()),
+        part2: Some(corrected.to_string()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Lines, Solution};
+
+/// Rules holds the page ordering rules. A rule (x, y) means that page x must be printed before
+/// page y if both are in an update.
+pub type Rules = Vec<(i64, i64)>;
+
+/// Updates holds the pages of each update in the order they are printed.
+pub type Updates = Vec<Vec<i64>>;
+
+/// read_rules_and_updates reads the page ordering rules and, after a blank line, the updates.
+pub fn read_rules_and_updates(r: impl BufRead) -> Result<(Rules, Updates), Error> {
+    let mut rules: Vec<(i64, i64)> = Vec::new();
+    let mut updates: Vec<Vec<i64>> = Vec::new();
+
+    let mut reading_rules = true;
+    for line in Lines::new(r) {
+        let line = line?;
+        if line.is_empty() {
+            reading_rules = false;
+            continue;
+        }
+
+        // We are either reading rules or updates.
+        let trimmed_line = line.token().trim();
+        if reading_rules {
+            let (left, right) = trimmed_line.split_once("|")?;
+
+            rules.push((left.parse("page number")?, right.parse("page number")?));
+        } else {
+            let mut pages: Vec<i64> = Vec::new();
+            for page_str in trimmed_line.split(",") {
+                pages.push(page_str.parse("page number")?);
+            }
+
+            updates.push(pages);
+        }
+    }
+
+    Ok((rules, updates))
+}
+
+/// is_valid returns true if the update's pages are in an order allowed by the rules.
+pub fn is_valid(rules: &[(i64, i64)], update: &[i64]) -> bool {
+    // Keep a list of pages that cannot come after pages we have seen.
+    let mut invalid_pages: Vec<i64> = Vec::new();
+    for page in update.iter() {
+        if invalid_pages.contains(page) {
+            return false;
+        }
+
+        // Add pages that are invalid moving forward.
+        for (x, y) in rules.iter() {
+            if y == page {
+                invalid_pages.push(*x);
+            }
+        }
+    }
+
+    true
+}
+
+/// correct_update returns the update's pages put in an order allowed by the rules. A Semantic
+/// error is returned if the rules are inconsistent.
+pub fn correct_update(rules: &[(i64, i64)], update: &[i64]) -> Result<Vec<i64>, Error> {
+    let mut corrected: Vec<i64> = Vec::new();
+
+    // Build up a new list of pages where the numbers are inserted at the correct position.
+    for n in update.iter() {
+        let mut after: Vec<i64> = Vec::new();
+        let mut before: Vec<i64> = Vec::new();
+        for (x, y) in rules.iter() {
+            if n == x {
+                before.push(*y);
+            }
+            if n == y {
+                after.push(*x);
+            }
+        }
+
+        let mut inserted = false;
+        for (i, c) in corrected.clone().iter().enumerate() {
+            if !inserted && before.contains(c) {
+                corrected.insert(i, *n);
+                inserted = true;
+            }
+
+            // NOTE: We add a consistency check for good measure.
+            if inserted && after.contains(c) {
+                return Err(Error::semantic("inconsistent rules"));
+            }
+        }
+        if !inserted {
+            corrected.push(*n);
+        }
+    }
+
+    Ok(corrected)
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day5 is the solution for day 5.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Rules, Updates);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_rules_and_updates(r)
+    }
+
+    fn part1(&self, (rules, updates): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        // Filter the valid updates and sum the middle page numbers.
+        Ok(updates
+            .iter()
+            .filter(|u| is_valid(rules, u))
+            .fold(0, |acc, u| acc + u[u.len() / 2]))
+    }
+
+    fn part2(&self, (rules, updates): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        // Filter the invalid updates, correct them, and sum the middle page numbers.
+        let invalid_updates = updates.iter().filter(|u| !is_valid(rules, u));
+        let mut invalid_update_sum = 0;
+        for u in invalid_updates {
+            let corrected = correct_update(rules, u)?;
+            invalid_update_sum += corrected[corrected.len() / 2];
+        }
+
+        Ok(invalid_update_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+",
+        );
+
+        let (n, n2) = Day5.solve(input.reader())?;
+        assert_eq!(n, 143);
+        assert_eq!(n2, 123);
+        Ok(())
+    }
+
+    #[test]
+    fn test_inconsistent_rules() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "1|2
+             2|3
+             3|1
+
+1,2,3
+",
+        );
+
+        match Day5.solve(input.reader()) {
+            Ok((n, n2)) => {
+                println!("{} {}", n, n2);
+                Err("Expected error".to_string().into())
+            }
+            Err(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+/// Reference is a slow but simple solution for day 5 that Day5 is checked against.
+pub struct Reference;
+
+// in_order returns true if no rule requires a page to come before a page printed earlier.
+fn in_order(rules: &[(i64, i64)], update: &[i64]) -> bool {
+    for i in 0..update.len() {
+        for j in i + 1..update.len() {
+            if rules.contains(&(update[j], update[i])) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// sort returns the pages of the update in the only order allowed by the rules. An error is
+// returned if the rules allow more than one order or none at all.
+fn sort(rules: &[(i64, i64)], update: &[i64]) -> Result<Vec<i64>, Box<dyn error::Error>> {
+    let mut rest = update.to_vec();
+    let mut sorted = Vec::new();
+    while !rest.is_empty() {
+        // The next page is the one that no remaining page must come before.
+        let first: Vec<usize> = (0..rest.len())
+            .filter(|i| !rest.iter().any(|p| rules.contains(&(*p, rest[*i]))))
+            .collect();
+        match first[..] {
+            [i] => sorted.push(rest.remove(i)),
+            _ => return Err("the rules do not give a single order for the update".into()),
+        }
+    }
+    Ok(sorted)
+}
+
+impl Solution for Reference {
+    type Input = (Vec<(i64, i64)>, Vec<Vec<i64>>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_rules_and_updates(r)
+    }
+
+    fn part1(&self, (rules, updates): &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(updates
+            .iter()
+            .filter(|u| in_order(rules, u))
+            .map(|u| u[u.len() / 2])
+            .sum())
+    }
+
+    fn part2(&self, (rules, updates): &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut total = 0;
+        for u in updates.iter().filter(|u| !in_order(rules, u)) {
+            let sorted = sort(rules, u)?;
+            total += sorted[sorted.len() / 2];
+        }
+        Ok(total)
+    }
+}
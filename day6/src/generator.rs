@@ -0,0 +1,57 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+
+use aoc_common::{Direction, Generated, Grid, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 130;
+
+// leaves returns true if the guard starting at start and facing up walks off the map.
+fn leaves(map: &Grid<char>, start: Point) -> bool {
+    let mut seen = collections::HashSet::new();
+    let (mut pos, mut dir) = (start, Direction::Up);
+    while seen.insert((pos, dir)) {
+        match map.step(pos, dir, 1) {
+            None => return true,
+            Some(next) if map[next] == '#' => dir = dir.clockwise(),
+            Some(next) => pos = next,
+        }
+    }
+    false
+}
+
+/// generate returns a size by size map with obstructions on about one in ten locations and the
+/// guard facing up. Maps where the guard walks in a loop are discarded. The answers are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    loop {
+        let mut map = Grid::new(size, size, '.');
+        for p in map.points().collect::<Vec<_>>() {
+            if rng.chance(1, 10) {
+                map[p] = '#';
+            }
+        }
+        let start = Point::new(rng.below(size), rng.below(size));
+        map[start] = '.';
+        if leaves(&map, start) {
+            map[start] = '^';
+            return Generated {
+                input: map.to_string(),
+                ..Default::default()
+            };
+        }
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::log::Level;
+use aoc_common::{event, Direction, Error, Grid, Point, Solution};
+
+/// Map is the lab map along with the guard's position and the positions they have visited.
+#[derive(Clone)]
+pub struct Map {
+    map: Grid<char>,
+    guard: Point,
+    guard_dir: Direction,
+
+    // visited_pos is a HashMap that maps visited positions to the directions the guard was facing
+    // when it visited them.
+    visited_pos: collections::HashMap<Point, Vec<Direction>>,
+}
+
+impl Map {
+    /// new returns a new Map for the grid. The guard is the first arrow character in the grid,
+    /// facing the way the arrow points. A Semantic error is returned if there is no guard.
+    pub fn new(map: Grid<char>) -> Result<Self, Error> {
+        let guard = map
+            .position(|c| Direction::from_arrow(*c).is_some())
+            .ok_or_else(|| Error::semantic("no guard found"))?;
+        let guard_dir =
+            Direction::from_arrow(map[guard]).ok_or_else(|| Error::semantic("no guard found"))?;
+
+        let mut visited_pos = collections::HashMap::new();
+        visited_pos.insert(guard, vec![guard_dir]);
+
+        Ok(Map {
+            map,
+            guard,
+            guard_dir,
+
+            visited_pos,
+        })
+    }
+
+    /// grid returns the map's grid. Obstructions are '#'.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.map
+    }
+
+    /// guard returns the guard's position and the direction they face.
+    pub fn guard(&self) -> (Point, Direction) {
+        (self.guard, self.guard_dir)
+    }
+
+    /// visited returns the positions the guard has visited, including the current one.
+    pub fn visited(&self) -> impl Iterator<Item = Point> + '_ {
+        self.visited_pos.keys().copied()
+    }
+
+    /// next_pos returns the position in front of the guard or None if it is off the map.
+    pub fn next_pos(&self) -> Option<Point> {
+        self.map.step(self.guard, self.guard_dir, 1)
+    }
+
+    /// advance turns the guard right if there is an obstruction in front of them and otherwise
+    /// moves them forward. The guard's position is returned, or None if they left the map. An
+    /// error is returned if the guard moves onto a position in a direction they have moved before
+    /// since they are then walking in a loop.
+    pub fn advance(&mut self) -> Result<Option<Point>, String> {
+        let next_pos = self.next_pos();
+        if next_pos.is_none() {
+            return Ok(None);
+        }
+
+        let new_guard = next_pos.unwrap();
+
+        // Check for obstructions
+        if self.map[new_guard] == '#' {
+            // - If there is something directly in front of you, turn right 90 degrees.
+            self.guard_dir = self.guard_dir.clockwise();
+        } else {
+            // - Otherwise, take a step forward.
+
+            // First check if we have entered a loop.
+            if let Some(p) = self.visited_pos.get(&new_guard) {
+                if p.contains(&self.guard_dir) {
+                    return Err("loop".to_string());
+                };
+            }
+
+            self.guard = new_guard;
+
+            // Insert our visited position.
+            self.visited_pos
+                .entry(self.guard)
+                .and_modify(|p| p.push(self.guard_dir))
+                .or_insert(vec![self.guard_dir]);
+        }
+
+        Ok(Some(self.guard))
+    }
+}
+
+// never_leaves returns the error for a map where the guard walks in a loop and never leaves.
+fn never_leaves() -> Error {
+    Error::no_solution("the guard never leaves the map")
+}
+
+/// visited_count returns the number of distinct positions the guard visits before leaving the
+/// map. A NoSolution error is returned if the guard never leaves.
+pub fn visited_count(map: &Map) -> Result<usize, Error> {
+    let mut map = map.clone();
+
+    // Advance the guard until they leave the map.
+    while map.advance().map_err(|_| never_leaves())?.is_some() {}
+
+    Ok(map.visited_pos.len())
+}
+
+/// obstruction_count returns the number of positions where a new obstruction would make the guard
+/// walk in a loop. A NoSolution error is returned if the guard never leaves the original map.
+pub fn obstruction_count(map: &Map) -> Result<usize, Error> {
+    let mut map = map.clone();
+
+    let mut obstruction_positions = collections::HashSet::new();
+    let mut tried = 0;
+
+    // Advance the guard until they leave the map.
+    loop {
+        match map.advance() {
+            Ok(Some(_p)) => {}
+            Ok(None) => break,
+            Err(_e) => return Err(never_leaves()),
+        }
+
+        // Insert an obstruction in front of the guard and see if it goes into a loop.
+        let mut new_map = map.clone();
+        let o = match new_map.next_pos() {
+            Some(o) => {
+                // Don't count if there is already an obstruction there, or if we have visited this location already.
+                if new_map.map[o] == '#' || new_map.visited_pos.contains_key(&o) {
+                    continue;
+                }
+                new_map.map[o] = '#';
+                o
+            }
+            None => continue,
+        };
+
+        if !obstruction_positions.contains(&o) {
+            tried += 1;
+            let mut steps = 0;
+            loop {
+                match new_map.advance() {
+                    Ok(Some(_p)) => steps += 1,
+                    Ok(None) => break,
+                    Err(_e) => {
+                        obstruction_positions.insert(o);
+                        event!(
+                            Level::Debug,
+                            "loop",
+                            obstruction = o,
+                            at = new_map.guard,
+                            steps = steps,
+                            found = obstruction_positions.len()
+                        );
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    event!(
+        Level::Info,
+        "obstructions",
+        tried = tried,
+        loops = obstruction_positions.len()
+    );
+    Ok(obstruction_positions.len())
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day6 is the solution for day 6.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        Map::new(Grid::read_charset(r, ".#^v<>")?)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(visited_count(map)?)
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(obstruction_count(map)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        );
+
+        let (n, n2) = Day6.solve(input.reader())?;
+        assert_eq!(n, 41);
+        assert_eq!(n2, 6);
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Point, Solution};
+
+use crate::{never_leaves, Map};
+
+/// Reference is a slow but simple solution for day 6 that Day6 is checked against. Part 2 tries
+/// an obstruction at every open location.
+pub struct Reference;
+
+// walk returns the locations the guard visits before leaving the map, or None if the guard walks
+// in a loop.
+fn walk(map: &Map, grid: &Grid<char>) -> Option<collections::HashSet<Point>> {
+    let mut pos = map.guard;
+    let mut dir = map.guard_dir;
+    let mut seen = collections::HashSet::new();
+    loop {
+        if !seen.insert((pos, dir)) {
+            return None;
+        }
+        match grid.step(pos, dir, 1) {
+            None => break,
+            Some(next) if grid[next] == '#' => dir = dir.clockwise(),
+            Some(next) => pos = next,
+        }
+    }
+    Some(seen.into_iter().map(|(p, _)| p).collect())
+}
+
+impl Solution for Reference {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        Map::new(Grid::read_charset(r, ".#^v<>")?)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(walk(map, &map.map).ok_or_else(never_leaves)?.len())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        walk(map, &map.map).ok_or_else(never_leaves)?;
+
+        let mut total = 0;
+        for p in map.map.points() {
+            if p == map.guard || map.map[p] == '#' {
+                continue;
+            }
+            let mut grid = map.map.clone();
+            grid[p] = '#';
+            if walk(map, &grid).is_none() {
+                total += 1;
+            }
+        }
+        Ok(total)
+    }
+}
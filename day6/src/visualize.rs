@@ -0,0 +1,127 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+
+use aoc_common::{Color, Frame, Player, Point, Visualize};
+
+use crate::{never_leaves, Day6, Map};
+
+// frame returns a frame showing the map with the positions the guard has visited and the guard.
+fn frame(map: &Map) -> Frame {
+    let (guard, dir) = map.guard();
+    let mut frame = Frame::from_grid(map.grid(), |c| if *c == '#' { '#' } else { '.' });
+    for p in map.visited() {
+        frame.set(p, 'X');
+    }
+    frame
+        .color(map.visited(), Color::Yellow)
+        .actor(guard, dir.arrow().unwrap_or('?'), Color::Red);
+    frame
+}
+
+// traps returns true if adding an obstruction at p makes the guard walk in a loop.
+fn traps(map: &Map, p: Point) -> Result<bool, Box<dyn error::Error>> {
+    let mut grid = map.grid().clone();
+    grid[p] = '#';
+    let mut map = Map::new(grid)?;
+    loop {
+        match map.advance() {
+            Ok(Some(_)) => {}
+            Ok(None) => return Ok(false),
+            Err(_) => return Ok(true),
+        }
+    }
+}
+
+impl Visualize for Day6 {
+    // visualize shows the guard walking their route one step at a time and then the positions
+    // where a new obstruction would make them walk in a loop.
+    fn visualize(&self, map: &Map, player: &mut Player) -> Result<(), Box<dyn error::Error>> {
+        let mut walk = map.clone();
+        let mut steps = 0;
+        while !player.stopped() {
+            let mut f = frame(&walk);
+            f.caption(format!(
+                "step {steps}: {} positions visited",
+                walk.visited().count()
+            ));
+            player.show(&f)?;
+            if walk.advance().map_err(|_| never_leaves())?.is_none() {
+                break;
+            }
+            steps += 1;
+        }
+        if player.stopped() {
+            return Ok(());
+        }
+
+        // Only positions on the guard's route can change it.
+        let (start, _) = map.guard();
+        let mut obstructions = Vec::new();
+        for p in walk.visited().filter(|p| *p != start) {
+            if traps(map, p)? {
+                obstructions.push(p);
+            }
+        }
+        let mut f = frame(&walk);
+        for p in &obstructions {
+            f.actor(*p, 'O', Color::Magenta);
+        }
+        f.caption(format!(
+            "{} positions for a new obstruction",
+            obstructions.len()
+        ));
+        player.show(&f)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Playback, Solution};
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_visualize() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        );
+        let map = Day6.parse(input.reader())?;
+        let mut out = Vec::new();
+        let mut player = Player::new(&mut out, Playback::Step, false);
+        Day6.visualize(&map, &mut player)?;
+        drop(player);
+        let out = String::from_utf8(out)?;
+        assert!(out.contains(
+            ".#..^.....\n........#.\n#.........\n......#...\nstep 0: 1 positions visited\n"
+        ));
+        assert!(out.contains("step 1: 2 positions visited\n"));
+        assert!(out.ends_with(
+            ".#XOXXXXX.\n.XXXXXOO#.\n#OXOXXXX..\n......#O..\n6 positions for a new obstruction\n"
+        ));
+        Ok(())
+    }
+}
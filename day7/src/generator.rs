@@ -0,0 +1,102 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of equations in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 850;
+
+// MAX_TARGET bounds the test values so that their sum does not overflow.
+const MAX_TARGET: i64 = 1_000_000_000_000_000;
+
+// concat returns the digits of a followed by the digits of b.
+fn concat(a: i64, b: i64) -> Option<i64> {
+    a.checked_mul(10_i64.pow(b.to_string().len() as u32))?
+        .checked_add(b)
+}
+
+// evaluate returns the result of applying the operators, chosen by ops from +, * and ||, to the
+// numbers from left to right. None is returned if the result exceeds MAX_TARGET.
+fn evaluate(numbers: &[i64], ops: &[usize]) -> Option<i64> {
+    let mut result = numbers[0];
+    for (n, op) in numbers[1..].iter().zip(ops) {
+        result = match op {
+            0 => result.checked_add(*n)?,
+            1 => result.checked_mul(*n)?,
+            _ => concat(result, *n)?,
+        };
+    }
+    Some(result).filter(|r| *r <= MAX_TARGET)
+}
+
+/// generate returns an input with size equations of 2 to 12 numbers. Each equation is one of:
+///
+///   - made with + and * only, so it is true for both parts.
+///   - made with at least one || and a test value larger than the product of the numbers, with
+///     numbers below 2 counted as 2, which is the largest value + and * can reach. It is only
+///     true for part 2.
+///   - given a test value larger than all the numbers concatenated, which is the largest value
+///     any operators can reach. It is never true.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut add_mul = 0;
+    let mut concat_total = 0;
+    let mut input = String::new();
+    let mut i = 0;
+    while i < size {
+        let len = 2 + rng.below(11);
+        let numbers: Vec<i64> = (0..len)
+            .map(|_| match rng.below(4) {
+                0 => rng.range(1..10),
+                1 | 2 => rng.range(10..100),
+                _ => rng.range(100..1000),
+            })
+            .collect();
+
+        let target = match rng.below(3) {
+            0 => {
+                let ops: Vec<_> = (1..len).map(|_| rng.below(2)).collect();
+                evaluate(&numbers, &ops).inspect(|t| add_mul += t)
+            }
+            1 => {
+                let mut ops: Vec<_> = (1..len).map(|_| rng.below(3)).collect();
+                ops[rng.below(len - 1)] = 2;
+                let bound = numbers
+                    .iter()
+                    .fold(1_i64, |b, n| b.saturating_mul((*n).max(2)));
+                evaluate(&numbers, &ops)
+                    .filter(|t| *t > bound)
+                    .inspect(|t| concat_total += t)
+            }
+            _ => evaluate(&numbers, &vec![2; len - 1])
+                .map(|max| max + rng.range(1..1000))
+                .filter(|t| *t <= MAX_TARGET),
+        };
+
+        // Equations whose test value is too large or cannot be made as intended are replaced.
+        let target = match target {
+            Some(t) => t,
+            None => continue,
+        };
+        let numbers: Vec<_> = numbers.iter().map(|n| n.to_string()).collect();
+        input.push_str(&format!("{target}: {}\n", numbers.join(" ")));
+        i += 1;
+    }
+
+    Generated {
+        input,
+        part1: Some(add_mul.to_string()),
+        part2: Some((add_mul + concat_total).to_string()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Lines, Solution};
+
+/// Calibrations holds the test value and the numbers of each calibration equation.
+pub type Calibrations = Vec<(i64, Vec<i64>)>;
+
+/// read_calibrations reads a calibration equation from each line.
+pub fn read_calibrations(r: impl BufRead) -> Result<Calibrations, Error> {
+    let mut numbers = Vec::new();
+    for line in Lines::new(r) {
+        let line = line?;
+        let (target_str, nums_str) = line.token().split_once(":")?;
+
+        let mut numvec = Vec::new();
+        let target = target_str.parse::<i64>("test value")?;
+        for n_str in nums_str.fields() {
+            numvec.push(n_str.parse::<i64>("number")?);
+        }
+        if numvec.is_empty() {
+            return Err(nums_str.malformed("numbers"));
+        }
+        numbers.push((target, numvec));
+    }
+
+    Ok(numbers)
+}
+
+/// Operator applies an operator to two numbers. None is returned if the result overflows.
+pub type Operator = fn(i64, i64) -> Option<i64>;
+
+/// test_num returns true if the numbers can be combined into the target by placing one of the
+/// operators between each pair of numbers. Operators are evaluated left to right.
+pub fn test_num(target: i64, numbers: &[i64], opers: &[Operator]) -> bool {
+    if numbers.is_empty() {
+        return false;
+    }
+    if numbers.len() == 1 {
+        return target == numbers[0];
+    }
+
+    for oper in opers {
+        // An overflowing result can never reach the target.
+        let n = match oper(numbers[0], numbers[1]) {
+            Some(n) => n,
+            None => continue,
+        };
+        let mut new_numbers = vec![n];
+        new_numbers.extend_from_slice(&numbers[2..]);
+
+        if test_num(target, &new_numbers, opers) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day7 is the solution for day 7.
+pub struct Day7;
+
+/// ADD_MUL holds the addition and multiplication operators.
+pub const ADD_MUL: [Operator; 2] = [|l, r| l.checked_add(r), |l, r| l.checked_mul(r)];
+
+/// ADD_MUL_CONCAT holds the addition, multiplication, and concatenation operators.
+pub const ADD_MUL_CONCAT: [Operator; 3] = [
+    |l, r| l.checked_add(r),
+    |l, r| l.checked_mul(r),
+    // Concatenates numbers together.
+    // NOTE: Number of digits in a number n is log_10(n) + 1. Integer logarithms are used since
+    // floating point ones are inexact for some powers of 10 (e.g. 1000).
+    |l, r| {
+        l.checked_mul(10_i64.checked_pow(r.checked_ilog10().unwrap_or(0) + 1)?)?
+            .checked_add(r)
+    },
+];
+
+/// sum_targets returns the sum of the targets of the calibrations. An error is returned if the sum
+/// overflows.
+pub fn sum_targets<'a>(
+    mut calibrations: impl Iterator<Item = &'a (i64, Vec<i64>)>,
+) -> Result<i64, Box<dyn error::Error>> {
+    Ok(calibrations
+        .try_fold(0_i64, |sum, (target, _)| sum.checked_add(*target))
+        .ok_or("calibration result overflow")?)
+}
+
+impl Solution for Day7 {
+    type Input = Calibrations;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_calibrations(r)
+    }
+
+    fn part1(&self, calibrations: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        sum_targets(
+            calibrations
+                .iter()
+                .filter(|(target, numbers)| test_num(*target, numbers, &ADD_MUL)),
+        )
+    }
+
+    fn part2(&self, calibrations: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        // Calibrations that can be made with addition and multiplication only are checked first
+        // because it is much faster.
+        sum_targets(calibrations.iter().filter(|(target, numbers)| {
+            test_num(*target, numbers, &ADD_MUL) || test_num(*target, numbers, &ADD_MUL_CONCAT)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+",
+        );
+
+        let (n, n2) = Day7.solve(input.reader())?;
+        assert_eq!(n, 3749);
+        assert_eq!(n2, 11387);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_powers_of_ten() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("100001000: 10000 1000\n");
+
+        let (n, n2) = Day7.solve(input.reader())?;
+        assert_eq!(n, 0);
+        assert_eq!(n2, 100001000);
+        Ok(())
+    }
+}
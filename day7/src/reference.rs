@@ -0,0 +1,79 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+/// Reference is a slow but simple solution for day 7 that Day7 is checked against. It tries every
+/// combination of operators in turn.
+pub struct Reference;
+
+// apply applies operator op to two numbers. 0 is addition, 1 multiplication and 2
+// concatenation. None is returned if the result overflows.
+fn apply(op: usize, l: i64, r: i64) -> Option<i64> {
+    match op {
+        0 => l.checked_add(r),
+        1 => l.checked_mul(r),
+        _ => format!("{l}{r}").parse().ok(),
+    }
+}
+
+// solvable returns true if some combination of the first num_ops operators, evaluated left to
+// right, makes the numbers equal the target.
+fn solvable(target: i64, numbers: &[i64], num_ops: usize) -> bool {
+    let Some((first, rest)) = numbers.split_first() else {
+        return false;
+    };
+
+    // Each combination is a number in base num_ops with a digit for each operator.
+    let combinations = (num_ops as u64).pow(rest.len() as u32);
+    (0..combinations).any(|mut c| {
+        let mut total = Some(*first);
+        for n in rest {
+            total = total.and_then(|t| apply((c % num_ops as u64) as usize, t, *n));
+            c /= num_ops as u64;
+        }
+        total == Some(target)
+    })
+}
+
+// sum returns the sum of the targets that can be made with the first num_ops operators.
+fn sum(calibrations: &[(i64, Vec<i64>)], num_ops: usize) -> Result<i64, Box<dyn error::Error>> {
+    let total: i128 = calibrations
+        .iter()
+        .filter(|(target, numbers)| solvable(*target, numbers, num_ops))
+        .map(|(target, _)| i128::from(*target))
+        .sum();
+    Ok(i64::try_from(total).map_err(|_| "calibration result overflow")?)
+}
+
+impl Solution for Reference {
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_calibrations(r)
+    }
+
+    fn part1(&self, calibrations: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        sum(calibrations, 2)
+    }
+
+    fn part2(&self, calibrations: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        sum(calibrations, 3)
+    }
+}
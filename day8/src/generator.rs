@@ -0,0 +1,43 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Grid, Point, Rng};
+
+/// DEFAULT_SIZE is the width and height of a real puzzle input.
+pub const DEFAULT_SIZE: usize = 50;
+
+// FREQUENCIES holds the characters used for antenna frequencies.
+const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// generate returns a size by size map with about one antenna frequency for every two rows and
+/// three or four antennas of each frequency. The answers are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut map = Grid::new(size, size, '.');
+    let mut frequencies: Vec<_> = FREQUENCIES.chars().collect();
+    rng.shuffle(&mut frequencies);
+
+    for f in frequencies.iter().take(size / 2) {
+        for _ in 0..3 + rng.below(2) {
+            let p = Point::new(rng.below(size), rng.below(size));
+            if map[p] == '.' {
+                map[p] = *f;
+            }
+        }
+    }
+
+    Generated {
+        input: map.to_string(),
+        ..Default::default()
+    }
+}
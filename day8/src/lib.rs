@@ -0,0 +1,210 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+mod visualize;
+
+use std::collections;
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Point, Solution};
+
+/// Map is the map of the antennas.
+pub struct Map {
+    map: Grid<char>,
+
+    antennas: collections::HashMap<char, Vec<Point>>,
+}
+
+impl Map {
+    /// new returns a new Map for the grid. Every letter or digit in the grid is an antenna with
+    /// that character as its frequency.
+    pub fn new(map: Grid<char>) -> Self {
+        let mut antennas: collections::HashMap<char, Vec<Point>> = collections::HashMap::new();
+        for (p, c) in map.iter() {
+            if c.is_alphanumeric() {
+                antennas
+                    .entry(*c)
+                    .and_modify(|vec| vec.push(p))
+                    .or_insert(vec![p]);
+            }
+        }
+
+        Map { map, antennas }
+    }
+
+    /// grid returns the map's grid.
+    pub fn grid(&self) -> &Grid<char> {
+        &self.map
+    }
+
+    /// antennas returns the positions of the antennas of each frequency in row-major order.
+    pub fn antennas(&self) -> &collections::HashMap<char, Vec<Point>> {
+        &self.antennas
+    }
+}
+
+// cartesian_product returns every pair of distinct elements of vec.
+fn cartesian_product<R: Copy>(vec: &[R]) -> Vec<(R, R)> {
+    let mut product: Vec<(R, R)> = Vec::new();
+
+    for i in 0..vec.len() {
+        for j in (i + 1)..vec.len() {
+            product.push((vec[i], vec[j]));
+        }
+    }
+
+    product
+}
+
+// offset returns the signed distance from r to l.
+fn offset(l: Point, r: Point) -> (isize, isize) {
+    (l.x as isize - r.x as isize, l.y as isize - r.y as isize)
+}
+
+/// calc_first_antinodes returns the antinodes of the antennas at l and r on the map that are
+/// twice as far from one antenna as from the other.
+pub fn calc_first_antinodes(map: &Grid<char>, l: Point, r: Point) -> collections::HashSet<Point> {
+    let mut antinodes = collections::HashSet::new();
+    if let Some(p) = l.checked_add_signed(offset(l, r)) {
+        if map.contains(p) {
+            antinodes.insert(p);
+        }
+    }
+    if let Some(p) = r.checked_add_signed(offset(r, l)) {
+        if map.contains(p) {
+            antinodes.insert(p);
+        }
+    }
+
+    antinodes
+}
+
+/// calc_antinodes returns the antinodes of the antennas at l and r on the map at any multiple of
+/// the distance between them, not counting the antennas themselves.
+pub fn calc_antinodes(map: &Grid<char>, l: Point, r: Point) -> collections::HashSet<Point> {
+    let mut antinodes = collections::HashSet::new();
+
+    // Calculate antinodes on the left and right.
+    for (start, (dx, dy)) in [(l, offset(l, r)), (r, offset(r, l))] {
+        let mut cur = start;
+        while let Some(p) = cur
+            .checked_add_signed((dx, dy))
+            .filter(|p| map.contains(*p))
+        {
+            antinodes.insert(p);
+            cur = p;
+        }
+    }
+
+    antinodes
+}
+
+/// antinodes returns the positions of the antinodes of every pair of antennas with the same
+/// frequency.
+pub fn antinodes(map: &Map) -> collections::HashSet<Point> {
+    let mut antinodes = collections::HashSet::new();
+    for antennas in map.antennas.values() {
+        for (l, r) in cartesian_product(antennas) {
+            antinodes.extend(calc_first_antinodes(&map.map, l, r));
+        }
+    }
+    antinodes
+}
+
+/// resonant_antinodes returns the positions of the antinodes of every pair of antennas with the
+/// same frequency when resonant harmonics are taken into account. Every antenna in line with
+/// another of the same frequency is also an antinode.
+pub fn resonant_antinodes(map: &Map) -> collections::HashSet<Point> {
+    let mut antinodes = collections::HashSet::new();
+    for antennas in map.antennas.values() {
+        for (l, r) in cartesian_product(antennas) {
+            antinodes.extend(calc_antinodes(&map.map, l, r));
+        }
+
+        // Add all antennas that are in line with at least one other antenna of the same
+        // frequency.
+        if antennas.len() >= 2 {
+            antinodes.extend(antennas);
+        }
+    }
+    antinodes
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day8 is the solution for day 8.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        Ok(Map::new(Grid::read(r)?))
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        Ok(antinodes(map).len())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(resonant_antinodes(map).len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from(
+            "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+",
+        );
+
+        let (n, n2) = Day8.solve(input.reader())?;
+        assert_eq!(n, 14);
+        assert_eq!(n2, 34);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lone_antenna() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("a..\n...\n");
+
+        let (n, n2) = Day8.solve(input.reader())?;
+        assert_eq!(n, 0);
+        assert_eq!(n2, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Grid, Point, Solution};
+
+use crate::Map;
+
+/// Reference is a slow but simple solution for day 8 that Day8 is checked against. It checks
+/// every location of the map against every pair of antennas.
+pub struct Reference;
+
+// multiple returns the k for which p = a + k * (b - a), if there is an integer one.
+fn multiple(p: Point, a: Point, b: Point) -> Option<i64> {
+    let (dx, dy) = (b.x as i64 - a.x as i64, b.y as i64 - a.y as i64);
+    let (px, py) = (p.x as i64 - a.x as i64, p.y as i64 - a.y as i64);
+    let k = if dx != 0 { px / dx } else { py / dy };
+    (px == k * dx && py == k * dy).then_some(k)
+}
+
+// count returns the number of locations that are an antinode of some pair of antennas. A location
+// is an antinode if it is a multiple of the pair's offset from the first antenna for which
+// is_antinode returns true.
+fn count(map: &Map, is_antinode: impl Fn(i64) -> bool) -> usize {
+    let antennas: Vec<(Point, char)> = map
+        .map
+        .iter()
+        .filter(|(_, c)| c.is_alphanumeric())
+        .map(|(p, c)| (p, *c))
+        .collect();
+
+    map.map
+        .points()
+        .filter(|p| {
+            antennas.iter().any(|(a, fa)| {
+                antennas.iter().any(|(b, fb)| {
+                    a != b && fa == fb && multiple(*p, *a, *b).is_some_and(&is_antinode)
+                })
+            })
+        })
+        .count()
+}
+
+impl Solution for Reference {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        Ok(Map::new(Grid::read(r)?))
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        // The antinodes are one offset beyond either antenna.
+        Ok(count(map, |k| k == -1 || k == 2))
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        Ok(count(map, |_| true))
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections;
+use std::error;
+
+use aoc_common::{Color, Frame, Images, Player, Point, Rgb, Svg, Visualize};
+
+use crate::{antinodes, resonant_antinodes, Day8, Map};
+
+// Find finds the antinodes of the antennas on a map.
+type Find = fn(&Map) -> collections::HashSet<Point>;
+
+// PARTS pairs the name of each part's antinodes with the function that finds them.
+const PARTS: [(&str, Find); 2] = [
+    ("antinodes", antinodes),
+    ("resonant antinodes", resonant_antinodes),
+];
+
+// ANTINODE is the colour of the cells holding an antinode in exported images.
+const ANTINODE: Rgb = Rgb(96, 32, 96);
+
+impl Visualize for Day8 {
+    // visualize shows the antennas coloured by frequency and then the antinodes of each part.
+    fn visualize(&self, map: &Map, player: &mut Player) -> Result<(), Box<dyn error::Error>> {
+        let mut antennas = Frame::from_chars(map.grid());
+        let mut frequencies: Vec<_> = map.antennas().keys().copied().collect();
+        frequencies.sort();
+        for (i, c) in frequencies.iter().enumerate() {
+            antennas.color(
+                map.antennas()[c].iter().copied(),
+                Color::PALETTE[i % Color::PALETTE.len()],
+            );
+        }
+        let mut f = antennas.clone();
+        f.caption(format!("{} frequencies", frequencies.len()));
+        player.show(&f)?;
+
+        for (name, find) in PARTS {
+            if player.stopped() {
+                break;
+            }
+            let found = find(map);
+            let mut f = antennas.clone();
+            for p in &found {
+                if map.grid()[*p] == '.' {
+                    f.set(*p, '#');
+                }
+            }
+            f.highlight(found.iter().copied(), Color::Magenta)
+                .caption(format!("{} {name}", found.len()));
+            player.show(&f)?;
+        }
+        Ok(())
+    }
+
+    // export draws the antinodes of each part with the antennas coloured by frequency, as PPM
+    // and SVG images.
+    fn export(&self, map: &Map, images: &mut Images) -> Result<(), Box<dyn error::Error>> {
+        for (part, (_, find)) in PARTS.iter().enumerate() {
+            let found = find(map);
+            let mut image = map.grid().map(|_| Rgb::BLACK);
+            for p in &found {
+                image[*p] = ANTINODE;
+            }
+            let mut svg = Svg::from_image(&image, 10);
+            for (c, points) in map.antennas() {
+                for p in points {
+                    image[*p] = Rgb::for_key(*c);
+                    svg.circle(*p, Rgb::for_key(*c))
+                        .text(*p, &c.to_string(), Rgb::BLACK);
+                }
+            }
+            let name = format!("antinodes-{}", part + 1);
+            images.ppm(&name, &image)?;
+            images.svg(&name, &svg)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Playback, Solution};
+
+    #[test]
+    fn test_visualize() -> Result<(), Box<dyn error::Error>> {
+        let map = Day8.parse("....\n.a..\n..a.\n....\n".as_bytes())?;
+        let mut out = Vec::new();
+        let mut player = Player::new(&mut out, Playback::Step, false);
+        Day8.visualize(&map, &mut player)?;
+        drop(player);
+        assert_eq!(
+            String::from_utf8_lossy(&out),
+            "....\n.a..\n..a.\n....\n1 frequencies\n
+#...\n.a..\n..a.\n...#\n2 antinodes\n
+#...\n.a..\n..a.\n...#\n4 resonant antinodes\n"
+        );
+        Ok(())
+    }
+}
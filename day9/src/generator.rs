@@ -0,0 +1,42 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aoc_common::{Generated, Rng};
+
+/// DEFAULT_SIZE is the number of digits in a real puzzle input.
+pub const DEFAULT_SIZE: usize = 19999;
+
+/// generate returns a disk map of size digits that alternate between files of 1 to 9 blocks and
+/// free space of 0 to 9 blocks. The map always ends with a file. The answers are not known.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut input: String = (0..size)
+        .map(|i| {
+            let d = if i % 2 == 0 {
+                1 + rng.below(9)
+            } else {
+                rng.below(10)
+            };
+            char::from(b'0' + d as u8)
+        })
+        .collect();
+    if size.is_multiple_of(2) {
+        input.pop();
+    }
+    input.push('\n');
+
+    Generated {
+        input,
+        ..Default::default()
+    }
+}
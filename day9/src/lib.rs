@@ -0,0 +1,211 @@
+// Copyright 2025 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod generator;
+pub mod reference;
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Lines, Solution};
+
+/// read_disk_map_to_blocks reads the disk map and returns a vector of blocks containing their file
+/// ID. The block is None if empty.
+pub fn read_disk_map_to_blocks(r: impl BufRead) -> Result<Vec<Option<i64>>, Error> {
+    let mut blocks = Vec::new();
+    let mut is_file = true;
+    let mut file_id = 0;
+    for line in Lines::new(r) {
+        let line = line?;
+        for (c, t) in line.chars() {
+            let n = c.to_digit(10).ok_or_else(|| t.invalid_char("digit"))?;
+
+            if is_file {
+                for _i in 0..n {
+                    blocks.push(Some(file_id));
+                }
+                file_id += 1;
+            } else {
+                for _i in 0..n {
+                    blocks.push(None);
+                }
+            }
+
+            is_file = !is_file;
+        }
+    }
+
+    Ok(blocks)
+}
+
+/// compact moves file blocks one at a time from the end of the disk to the leftmost free block
+/// until there are no gaps between file blocks.
+pub fn compact(blocks: &mut [Option<i64>]) {
+    let mut i = 0;
+    let mut j = blocks.len().saturating_sub(1);
+    while i < j {
+        if blocks[i].is_some() {
+            i += 1;
+            continue;
+        }
+        if blocks[j].is_none() {
+            j -= 1;
+            continue;
+        }
+        blocks[i] = Some(blocks[j].unwrap());
+        blocks[j] = None;
+    }
+}
+
+// find_space returns the index of the first run of at least size free blocks.
+fn find_space(blocks: &[Option<i64>], size: usize) -> Option<usize> {
+    let mut i = 0;
+    'outer: while i < blocks.len() {
+        // Find a free space.
+        while i < blocks.len() && blocks[i].is_some() {
+            i += 1;
+            continue;
+        }
+
+        // Check if the free space is the appropriate length.
+        if i + size > blocks.len() {
+            break;
+        }
+        for (j, b) in blocks.iter().skip(i).take(size).enumerate() {
+            if b.is_some() {
+                i += j;
+                continue 'outer;
+            }
+        }
+        return Some(i);
+    }
+
+    None
+}
+
+/// defrag moves whole files, in order of decreasing file ID, to the leftmost run of free blocks
+/// before them that is large enough to hold them. Files that don't fit anywhere are not moved.
+pub fn defrag(blocks: &mut [Option<i64>]) {
+    // i marks the beginning of where to look for open space.
+    let mut i = 0;
+
+    // j marks the end of where to look for open space and the beginning of file data.
+    let mut j = blocks.len().saturating_sub(1);
+
+    loop {
+        while i < j && blocks[i].is_some() {
+            i += 1;
+        }
+        while i < j && blocks[j].is_none() {
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+
+        // Find the file start (j) and end (k) locations.
+        let file_id = blocks[j].unwrap();
+        let k = j;
+        while let Some(b) = blocks[j - 1] {
+            if b == file_id {
+                j -= 1;
+            } else {
+                break;
+            }
+        }
+        if i >= j {
+            break;
+        }
+
+        // The file is now at blocks[j..=k]
+        let file_len = k - j + 1;
+        if let Some(s) = find_space(&blocks[i..j], file_len) {
+            // We found some open space. Move the file.
+            let s_index = i + s;
+            for index in 0..file_len {
+                blocks[s_index + index] = blocks[j + index];
+                blocks[j + index] = None;
+            }
+        }
+
+        j -= 1;
+    }
+}
+
+/// calc_checksum returns the sum of each file block's position multiplied by its file ID.
+pub fn calc_checksum(blocks: &[Option<i64>]) -> i64 {
+    let mut checksum = 0;
+    for (i, n) in blocks.iter().enumerate() {
+        if n.is_some() {
+            checksum += i as i64 * n.unwrap();
+        }
+    }
+
+    checksum
+}
+
+/// VERSION identifies the code of the crate. It is set by the build script from the crate's
+/// version and a digest of its sources.
+pub const VERSION: &str = env!("AOC_VERSION");
+
+/// Day9 is the solution for day 9.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Option<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        read_disk_map_to_blocks(r)
+    }
+
+    fn part1(&self, blocks: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        let mut blocks = blocks.clone();
+        compact(&mut blocks);
+        Ok(calc_checksum(&blocks))
+    }
+
+    fn part2(&self, blocks: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut blocks = blocks.clone();
+        defrag(&mut blocks);
+        Ok(calc_checksum(&blocks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, Bytes};
+
+    #[test]
+    fn test_run() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("12345\n");
+
+        let (n, n2) = Day9.solve(input.reader())?;
+        assert_eq!(n, 60);
+        assert_eq!(n2, 132);
+        Ok(())
+    }
+
+    #[test]
+    fn test_example() -> Result<(), Box<dyn error::Error>> {
+        let input = Bytes::from("2333133121414131402\n");
+
+        let (n, n2) = Day9.solve(input.reader())?;
+        assert_eq!(n, 1928);
+        assert_eq!(n2, 2858);
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2024 Ian Lewis
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::io::BufRead;
+
+use aoc_common::{Error, Solution};
+
+/// Reference is a slow but simple solution for day 9 that Day9 is checked against.
+pub struct Reference;
+
+// checksum returns the sum of each block's position multiplied by its file ID.
+fn checksum(blocks: &[Option<i64>]) -> i64 {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, b)| i as i64 * b.unwrap_or(0))
+        .sum()
+}
+
+impl Solution for Reference {
+    type Input = Vec<Option<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, r: impl BufRead) -> Result<Self::Input, Error> {
+        super::read_disk_map_to_blocks(r)
+    }
+
+    fn part1(&self, blocks: &Self::Input) -> Result<Self::Part1, Box<dyn error::Error>> {
+        // After compacting, the disk starts with one block for every file block. The free
+        // blocks among them are filled with the file blocks from the end of the disk.
+        let files = blocks.iter().filter(|b| b.is_some()).count();
+        let mut from_end = blocks.iter().rev().flatten();
+        let compacted: Vec<Option<i64>> = blocks[..files]
+            .iter()
+            .map(|b| b.or_else(|| from_end.next().copied()))
+            .collect();
+        Ok(checksum(&compacted))
+    }
+
+    fn part2(&self, blocks: &Self::Input) -> Result<Self::Part2, Box<dyn error::Error>> {
+        let mut blocks = blocks.clone();
+        let max_id = blocks.iter().flatten().max().copied().unwrap_or(-1);
+        for id in (0..=max_id).rev() {
+            let start = match blocks.iter().position(|b| *b == Some(id)) {
+                Some(start) => start,
+                None => continue,
+            };
+            let len = blocks[start..]
+                .iter()
+                .take_while(|b| **b == Some(id))
+                .count();
+
+            // Move the file to the leftmost run of free blocks before it that is long enough.
+            let free = (0..start).find(|i| blocks[*i..i + len].iter().all(|b| b.is_none()));
+            if let Some(free) = free {
+                for i in 0..len {
+                    blocks.swap(free + i, start + i);
+                }
+            }
+        }
+        Ok(checksum(&blocks))
+    }
+}